@@ -108,6 +108,170 @@ impl<const N: u32> BitVec<N> {
     }
 }
 
+/// The error returned by [`BitVec::from_hex`] on malformed input.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum HexError {
+    /// The string does not have exactly one digit per 4 bits of the bit vector.
+    InvalidLength { expected: usize, found: usize },
+    /// The string contains a character that is not a hexadecimal digit.
+    InvalidDigit(char),
+    /// The most significant digit sets bits beyond the length of the bit vector.
+    Overflow,
+}
+
+impl std::fmt::Display for HexError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HexError::InvalidLength { expected, found } => {
+                write!(f, "expected {expected} hexadecimal digits, found {found}")
+            }
+            HexError::InvalidDigit(c) => write!(f, "invalid hexadecimal digit {c:?}"),
+            HexError::Overflow => write!(f, "the value does not fit in the bit vector"),
+        }
+    }
+}
+
+impl std::error::Error for HexError {}
+
+impl<const N: u32> BitVec<N> {
+    /// Parses a bit vector written as a hexadecimal number, most significant
+    /// digit first, the way Intel's documentation writes vectors: the highest
+    /// lane comes first, and each lane is big-endian. A `0x` prefix and `_`
+    /// separators (e.g. between lanes) are allowed, and are ignored.
+    ///
+    /// ```rust
+    /// use testable_simd_models::abstractions::bitvec::{BitVec, HexError};
+    ///
+    /// let bv = BitVec::<64>::from_hex("0x00000002_00000001").unwrap();
+    /// assert_eq!(bv.to_vec::<u32>(), vec![1, 2]);
+    /// assert_eq!(bv.to_hex(), "0000000200000001");
+    /// assert_eq!(BitVec::<64>::from_hex(&bv.to_hex()), Ok(bv));
+    ///
+    /// assert_eq!(
+    ///     BitVec::<64>::from_hex("123"),
+    ///     Err(HexError::InvalidLength { expected: 16, found: 3 })
+    /// );
+    /// assert_eq!(
+    ///     BitVec::<8>::from_hex("0g"),
+    ///     Err(HexError::InvalidDigit('g'))
+    /// );
+    /// ```
+    pub fn from_hex(s: &str) -> Result<Self, HexError> {
+        let s = s.strip_prefix("0x").unwrap_or(s);
+        let digits = s
+            .chars()
+            .filter(|&c| c != '_')
+            .rev()
+            .map(|c| c.to_digit(16).ok_or(HexError::InvalidDigit(c)))
+            .collect::<Result<Vec<u32>, _>>()?;
+        let expected = N.div_ceil(4) as usize;
+        if digits.len() != expected {
+            return Err(HexError::InvalidLength {
+                expected,
+                found: digits.len(),
+            });
+        }
+        if !N.is_multiple_of(4) && digits[expected - 1] >> (N % 4) != 0 {
+            return Err(HexError::Overflow);
+        }
+        Ok(Self::from_fn(|i| {
+            Bit::nth_bit::<u32>(digits[(i / 4) as usize], (i % 4) as usize)
+        }))
+    }
+
+    /// Prints the bit vector as a hexadecimal number, most significant digit
+    /// first, without any prefix or separator. This is the inverse of
+    /// [`BitVec::from_hex`].
+    pub fn to_hex(&self) -> String {
+        let bits = self.0.as_vec();
+        bits.chunks(4)
+            .rev()
+            .map(|digit| {
+                let digit = u128_int_from_bit_slice(digit) as u32;
+                char::from_digit(digit, 16).unwrap()
+            })
+            .collect()
+    }
+}
+
+impl<const N: u32> BitVec<N> {
+    /// Concatenates two bit vectors, `self` making up the low `N` bits of the
+    /// result and `other` its high `M` bits. The length `R` of the result must
+    /// be `N + M`, which is checked at compile time.
+    ///
+    /// ```rust
+    /// use testable_simd_models::abstractions::bitvec::BitVec;
+    ///
+    /// let lo = BitVec::<128>::from_int(0x0123_4567_89ab_cdef_u128);
+    /// let hi = BitVec::<128>::from_int(u128::MAX);
+    /// let v: BitVec<256> = lo.concat(hi);
+    /// assert_eq!(v.to_vec::<u128>(), vec![0x0123_4567_89ab_cdef, u128::MAX]);
+    ///
+    /// let (lo2, hi2): (BitVec<128>, BitVec<128>) = v.split_at();
+    /// assert_eq!((lo2, hi2), (lo, hi));
+    /// ```
+    pub fn concat<const M: u32, const R: u32>(self, other: BitVec<M>) -> BitVec<R> {
+        const { assert!(N + M == R, "expected a result of N + M bits") };
+        BitVec::from_fn(|i| if i < N { self[i] } else { other[i - N] })
+    }
+
+    /// Splits a bit vector into its low `K` bits and its high `R` bits. `K + R`
+    /// must be `N`, which is checked at compile time. This is the inverse of
+    /// [`BitVec::concat`].
+    pub fn split_at<const K: u32, const R: u32>(self) -> (BitVec<K>, BitVec<R>) {
+        const { assert!(K + R == N, "expected halves of K + R = N bits") };
+        (
+            BitVec::from_fn(|i| self[i]),
+            BitVec::from_fn(|i| self[K + i]),
+        )
+    }
+}
+
+impl<const N: u32> BitVec<N> {
+    /// Returns bit `i`, bit 0 being the least significant bit of the first
+    /// element given to [`BitVec::from_slice`]. Panics if `i` is not below `N`.
+    ///
+    /// ```rust
+    /// use testable_simd_models::abstractions::bitvec::BitVec;
+    ///
+    /// let mut v = BitVec::<32>::from_slice(&[0x01u8, 0x80, 0x00, 0x00], 8);
+    /// assert!(v.bit(0) && v.bit(15) && !v.bit(16));
+    ///
+    /// v.set_bit(16, true);
+    /// assert_eq!(v.to_vec::<u8>(), vec![0x01, 0x80, 0x01, 0x00]);
+    ///
+    /// let field: BitVec<12> = v.bits(8..20);
+    /// assert_eq!(field.to_int::<u16>(), 0x180);
+    /// ```
+    pub fn bit(&self, i: u32) -> bool {
+        assert!(i < N, "bit index {i} out of range for a {N}-bit vector");
+        self[i].into()
+    }
+
+    /// Sets bit `i` to `value`. Panics if `i` is not below `N`.
+    pub fn set_bit(&mut self, i: u32, value: bool) {
+        assert!(i < N, "bit index {i} out of range for a {N}-bit vector");
+        let bits = *self;
+        *self = Self::from_fn(|j| if j == i { value.into() } else { bits[j] });
+    }
+
+    /// Extracts the bits in `range` as a bit vector of `M` bits, the first bit
+    /// of the range becoming bit 0. Panics if the range does not fit within `N`
+    /// bits, or if it is not exactly `M` bits long.
+    pub fn bits<const M: u32>(&self, range: core::ops::Range<u32>) -> BitVec<M> {
+        assert!(
+            range.start <= range.end && range.end <= N,
+            "bit range {range:?} out of range for a {N}-bit vector"
+        );
+        assert_eq!(
+            range.end - range.start,
+            M,
+            "bit range {range:?} does not have {M} bits"
+        );
+        BitVec::from_fn(|i| self[range.start + i])
+    }
+}
+
 impl<const N: u32> BitVec<N> {
     pub fn chunked_shift<const CHUNK: u32, const SHIFTS: u32>(
         self,
@@ -146,3 +310,63 @@ impl<const N: u32> BitVec<N> {
         self.0.fold(init, f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::test::HasRandom;
+
+    /// Bit `i` of a vector built by `from_slice` is bit `i % 16` of its element
+    /// `i / 16`, and extracting an element's bits gives back what `to_vec` does.
+    #[test]
+    fn bits_match_from_slice() {
+        for _ in 0..100 {
+            let lanes: [u16; 8] = core::array::from_fn(|_| u16::random());
+            let v = BitVec::<128>::from_slice(&lanes, 16);
+            for i in 0..128 {
+                assert_eq!(v.bit(i), (lanes[(i / 16) as usize] >> (i % 16)) & 1 == 1);
+            }
+            for (j, lane) in v.to_vec::<u16>().into_iter().enumerate() {
+                let j = j as u32;
+                assert_eq!(v.bits::<16>(16 * j..16 * j + 16).to_int::<u16>(), lane);
+            }
+        }
+    }
+
+    #[test]
+    fn set_bit_changes_a_single_bit() {
+        for _ in 0..100 {
+            let v = BitVec::<64>::random();
+            let i = u32::random() % 64;
+            let value = bool::from(BitVec::<1>::random()[0]);
+            let mut w = v;
+            w.set_bit(i, value);
+            let expected = (v.to_int::<u64>() & !(1 << i)) | ((value as u64) << i);
+            assert_eq!(w.to_int::<u64>(), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "bit index 32 out of range")]
+    fn bit_out_of_range() {
+        BitVec::<32>::ZERO().bit(32);
+    }
+
+    #[test]
+    #[should_panic(expected = "bit index 8 out of range")]
+    fn set_bit_out_of_range() {
+        BitVec::<8>::ZERO().set_bit(8, true);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range for a 16-bit vector")]
+    fn bits_out_of_range() {
+        BitVec::<16>::ZERO().bits::<8>(12..20);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not have 4 bits")]
+    fn bits_wrong_length() {
+        BitVec::<16>::ZERO().bits::<4>(0..8);
+    }
+}
@@ -1,7 +1,9 @@
 //! This module implements a fixed-size array wrapper with functional semantics
 //! which are used in formulating abstractions.
 
-use crate::abstractions::bit::MachineNumeric;
+use crate::abstractions::bit::{MachineInteger, MachineNumeric};
+use rand::distr::{Distribution, StandardUniform};
+use rand::Rng;
 
 /// `FunArray<N, T>` represents an array of `T` values of length `N`, where `N` is a compile-time constant.
 /// Internally, it uses a fixed-length array of `Option<T>` with a maximum capacity of 512 elements.
@@ -32,11 +34,59 @@ impl<const N: u32, T> FunArray<N, T> {
     where
         T: Clone,
     {
-        self.0[0..(N as usize)]
-            .iter()
-            .cloned()
-            .map(|x| x.unwrap())
-            .collect()
+        self.iter().cloned().collect()
+    }
+
+    /// Returns an iterator over the elements of the array, in lane order.
+    ///
+    /// ```rust
+    /// use testable_simd_models::abstractions::funarr::FunArray;
+    ///
+    /// let arr = FunArray::<4, u32>::from_fn(|i| 10 * i);
+    /// assert_eq!(arr.iter().copied().collect::<Vec<_>>(), vec![0, 10, 20, 30]);
+    /// assert!(arr.enumerate().all(|(i, x)| *x == arr[i]));
+    ///
+    /// let reversed = FunArray::<4, u32>::from_fn(|i| arr.into_iter().rev().nth(i as usize).unwrap());
+    /// assert_eq!(reversed, FunArray::<4, u32>::new(30, 20, 10, 0));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.0[0..(N as usize)].iter().map(unwrap_ref)
+    }
+
+    /// Returns an iterator over the elements of the array along with their
+    /// index, in lane order.
+    pub fn enumerate(&self) -> impl DoubleEndedIterator<Item = (u32, &T)> {
+        (0..N).zip(self.iter())
+    }
+
+    /// Applies `f` to every element of the array.
+    ///
+    /// ```rust
+    /// use testable_simd_models::abstractions::funarr::FunArray;
+    ///
+    /// let a = FunArray::<4, i32>::from_fn(|i| i as i32);
+    /// let b = FunArray::<4, i32>::new(10, 20, 30, 40);
+    /// assert_eq!(a.map(|x| x * 2), FunArray::<4, i32>::new(0, 2, 4, 6));
+    /// assert_eq!(
+    ///     a.zip_with(b, |x, y| x + y),
+    ///     FunArray::<4, i32>::new(10, 21, 32, 43)
+    /// );
+    /// assert_eq!(a.map(|x| x as u8 == 1), FunArray::<4, bool>::from_fn(|i| i == 1));
+    /// ```
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> FunArray<N, U>
+    where
+        T: Clone,
+    {
+        FunArray::from_fn(|i| f(self[i].clone()))
+    }
+
+    /// Combines the elements of two arrays pairwise, using `f`.
+    pub fn zip_with<U, V>(self, other: FunArray<N, U>, f: impl Fn(T, U) -> V) -> FunArray<N, V>
+    where
+        T: Clone,
+        U: Clone,
+    {
+        FunArray::from_fn(|i| f(self[i].clone(), other[i].clone()))
     }
 
     /// Folds over the array, accumulating a result.
@@ -62,6 +112,48 @@ impl<const N: u32, T: MachineNumeric> FunArray<N, T> {
     }
 }
 
+impl<const N: u32, T: Copy> FunArray<N, T>
+where
+    StandardUniform: Distribution<T>,
+{
+    /// Constructs a funarray whose lanes are drawn independently from `rng`.
+    ///
+    /// This is not called `random`, as that would shadow
+    /// `crate::helpers::test::HasRandom::random`, which draws from the seeded
+    /// generator of the test harness and is what tests normally go through.
+    pub fn random_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let lanes: Vec<T> = (0..N).map(|_| rng.random()).collect();
+        Self::from_fn(|i| lanes[i as usize])
+    }
+
+    /// Like `random_with`, but every lane is drawn with `random_biased_lane`.
+    pub fn random_biased_with<R: Rng + ?Sized>(rng: &mut R) -> Self
+    where
+        T: MachineInteger,
+    {
+        let lanes: Vec<T> = (0..N).map(|_| random_biased_lane(rng)).collect();
+        Self::from_fn(|i| lanes[i as usize])
+    }
+}
+
+/// Draws an integer from `rng` that is biased towards the edge cases of its
+/// type: a quarter of the values are `MIN`, `MAX`, `0` and either `-1` or, for
+/// unsigned types where `!0` is already `MAX`, `1`, so that boundary bugs show
+/// up much more often than with uniform values.
+pub fn random_biased_lane<T: MachineInteger, R: Rng + ?Sized>(rng: &mut R) -> T
+where
+    StandardUniform: Distribution<T>,
+{
+    match rng.random_range(0..16u8) {
+        0 => T::MIN,
+        1 => T::MAX,
+        2 => T::ZEROS,
+        3 if T::SIGNED => T::ONES,
+        3 => T::from_u128(1),
+        _ => rng.random(),
+    }
+}
+
 impl<const N: u32, T: Clone> TryFrom<Vec<T>> for FunArray<N, T> {
     type Error = ();
     fn try_from(v: Vec<T>) -> Result<Self, ()> {
@@ -79,6 +171,35 @@ impl<const N: u32, T: core::fmt::Debug + Clone> core::fmt::Debug for FunArray<N,
     }
 }
 
+fn unwrap_ref<T>(x: &Option<T>) -> &T {
+    x.as_ref().unwrap()
+}
+
+/// An iterator over references to the elements of a [`FunArray`], see [`FunArray::iter`].
+pub type Iter<'a, T> = core::iter::Map<core::slice::Iter<'a, Option<T>>, fn(&Option<T>) -> &T>;
+
+/// An iterator over the elements of a [`FunArray`], see [`FunArray::into_iter`].
+pub type IntoIter<T> =
+    core::iter::Map<core::iter::Take<core::array::IntoIter<Option<T>, 512>>, fn(Option<T>) -> T>;
+
+impl<const N: u32, T> IntoIterator for FunArray<N, T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter().take(N as usize).map(Option::unwrap)
+    }
+}
+
+impl<'a, const N: u32, T> IntoIterator for &'a FunArray<N, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 impl<const N: u32, T> core::ops::Index<u32> for FunArray<N, T> {
     type Output = T;
 
@@ -66,9 +66,71 @@ interpretations!(256; i32x8 [i32; 8], i64x4 [i64; 4], i16x16 [i16; 16], i128x2 [
 interpretations!(128; i32x4 [i32; 4], i64x2 [i64; 2], i16x8 [i16; 8], i128x1 [i128; 1], i8x16 [i8; 16],
             u32x4 [u32; 4], u64x2 [u64; 2], u16x8 [u16; 8], u8x16 [u8; 16], f32x4 [f32; 4], f64x2 [f64; 2]);
 
-interpretations!(512; u32x16 [u32; 16], u16x32 [u16; 32], i32x16 [i32; 16], i16x32 [i16; 32]);
+interpretations!(512; i32x16 [i32; 16], i64x8 [i64; 8], i16x32 [i16; 32], i8x64 [i8; 64],
+            u32x16 [u32; 16], u64x8 [u64; 8], u16x32 [u16; 32], u8x64 [u8; 64], f32x16 [f32; 16], f64x8 [f64; 8]);
 interpretations!(64; i64x1 [i64; 1], i32x2 [i32; 2], i16x4 [i16; 4], i8x8 [i8; 8], u64x1 [u64; 1], u32x2 [u32; 2],u16x4 [u16; 4], u8x8 [u8; 8], f32x2 [f32; 2], f64x1 [f64; 1]);
-interpretations!(32; i8x4 [i8; 4], u8x4 [u8; 4]);
+interpretations!(32; i16x2 [i16; 2], i8x4 [i8; 4], u16x2 [u16; 2], u8x4 [u8; 4]);
+interpretations!(16; i8x2 [i8; 2], u8x2 [u8; 2]);
+
+/// A trait for types that represent floating-point numbers.
+///
+/// This plays the role that [`MachineInteger`] plays for integer vectors: the
+/// floating-point SIMD operations below are generic over it.
+pub trait Float: MachineNumeric + Copy + PartialEq + PartialOrd {
+    /// Implements functionality for `simd_fadd` in `crate::abstractions::simd`.
+    fn fadd(self, rhs: Self) -> Self;
+    /// Implements functionality for `simd_fsub` in `crate::abstractions::simd`.
+    fn fsub(self, rhs: Self) -> Self;
+    /// Multiplies two floats, rounding to nearest.
+    fn fmul(self, rhs: Self) -> Self;
+    /// Divides two floats, rounding to nearest.
+    fn fdiv(self, rhs: Self) -> Self;
+    /// Computes `self * a + b` with a single rounding to nearest.
+    fn fma(self, a: Self, b: Self) -> Self;
+    /// Computes the square root, rounding to nearest.
+    fn sqrt(self) -> Self;
+    /// Rounds towards positive infinity.
+    fn ceil(self) -> Self;
+    /// Rounds towards negative infinity.
+    fn floor(self) -> Self;
+    /// Rounds towards zero.
+    fn trunc(self) -> Self;
+    /// Rounds to the nearest integer, with ties going to the even one.
+    fn round_ties_even(self) -> Self;
+    /// Flips the sign bit, leaving every other bit as is, even for NaNs.
+    fn neg(self) -> Self;
+    /// Returns `true` if the value is a NaN, whatever its payload or sign.
+    fn is_nan(self) -> bool;
+    /// Maps the bits of the float to a signed integer whose ordering is the
+    /// IEEE 754 `totalOrder` predicate (the one used by `total_cmp`).
+    fn total_order_bits(self) -> i128;
+}
+
+macro_rules! generate_float_impls {
+    ($($ty:ident $ity:ident $uty:ident),*) => {
+        $(
+        impl Float for $ty {
+            fn fadd(self, rhs: Self) -> Self { self + rhs }
+            fn fsub(self, rhs: Self) -> Self { self - rhs }
+            fn fmul(self, rhs: Self) -> Self { self * rhs }
+            fn fdiv(self, rhs: Self) -> Self { self / rhs }
+            fn fma(self, a: Self, b: Self) -> Self { $ty::mul_add(self, a, b) }
+            fn sqrt(self) -> Self { $ty::sqrt(self) }
+            fn ceil(self) -> Self { $ty::ceil(self) }
+            fn floor(self) -> Self { $ty::floor(self) }
+            fn trunc(self) -> Self { $ty::trunc(self) }
+            fn round_ties_even(self) -> Self { $ty::round_ties_even(self) }
+            fn neg(self) -> Self { $ty::from_bits(self.to_bits() ^ (1 << ($uty::BITS - 1))) }
+            fn is_nan(self) -> bool { $ty::is_nan(self) }
+            fn total_order_bits(self) -> i128 {
+                let bits = self.to_bits() as $ity;
+                (bits ^ ((((bits >> ($ity::BITS - 1)) as $uty) >> 1) as $ity)) as i128
+            }
+        }
+        )*
+    };
+}
+generate_float_impls!(f32 i32 u32, f64 i64 u64);
 
 /// Inserts an element into a vector, returning the updated vector.
 ///
@@ -104,6 +166,63 @@ pub fn simd_sub<const N: u32, T: MachineInteger + Copy>(
     FunArray::from_fn(|i| x[i].wrapping_sub(y[i]))
 }
 
+/// Adds two vectors of floats elementwise, rounding to nearest.
+pub fn simd_fadd<const N: u32, T: Float>(x: FunArray<N, T>, y: FunArray<N, T>) -> FunArray<N, T> {
+    FunArray::from_fn(|i| x[i].fadd(y[i]))
+}
+
+/// Subtracts `y` from `x` elementwise for vectors of floats, rounding to nearest.
+pub fn simd_fsub<const N: u32, T: Float>(x: FunArray<N, T>, y: FunArray<N, T>) -> FunArray<N, T> {
+    FunArray::from_fn(|i| x[i].fsub(y[i]))
+}
+
+/// Multiplies two vectors of floats elementwise, rounding to nearest.
+pub fn simd_fmul<const N: u32, T: Float>(x: FunArray<N, T>, y: FunArray<N, T>) -> FunArray<N, T> {
+    FunArray::from_fn(|i| x[i].fmul(y[i]))
+}
+
+/// Divides `x` by `y` elementwise for vectors of floats, rounding to nearest.
+pub fn simd_fdiv<const N: u32, T: Float>(x: FunArray<N, T>, y: FunArray<N, T>) -> FunArray<N, T> {
+    FunArray::from_fn(|i| x[i].fdiv(y[i]))
+}
+
+/// Computes `x * y + z` elementwise for vectors of floats, rounding only once.
+pub fn simd_fma<const N: u32, T: Float>(
+    x: FunArray<N, T>,
+    y: FunArray<N, T>,
+    z: FunArray<N, T>,
+) -> FunArray<N, T> {
+    FunArray::from_fn(|i| x[i].fma(y[i], z[i]))
+}
+
+/// Rounds a vector of floats elementwise towards positive infinity.
+pub fn simd_ceil<const N: u32, T: Float>(x: FunArray<N, T>) -> FunArray<N, T> {
+    FunArray::from_fn(|i| x[i].ceil())
+}
+
+/// Rounds a vector of floats elementwise towards negative infinity.
+pub fn simd_floor<const N: u32, T: Float>(x: FunArray<N, T>) -> FunArray<N, T> {
+    FunArray::from_fn(|i| x[i].floor())
+}
+
+/// Rounds a vector of floats elementwise towards zero.
+pub fn simd_trunc<const N: u32, T: Float>(x: FunArray<N, T>) -> FunArray<N, T> {
+    FunArray::from_fn(|i| x[i].trunc())
+}
+
+/// Rounds a vector of floats elementwise to the nearest integer, with ties
+/// going to the even one.
+pub fn simd_round_ties_even<const N: u32, T: Float>(x: FunArray<N, T>) -> FunArray<N, T> {
+    FunArray::from_fn(|i| x[i].round_ties_even())
+}
+
+/// Negates a vector of floats elementwise by flipping the sign bits, so that
+/// zeros and NaNs are negated too: `0.0` becomes `-0.0` and a NaN keeps its
+/// payload. This is `simd_neg` for floats, which upstream does not tell apart.
+pub fn simd_fneg<const N: u32, T: Float>(x: FunArray<N, T>) -> FunArray<N, T> {
+    FunArray::from_fn(|i| x[i].neg())
+}
+
 /// Multiplies two vectors elementwise with wrapping on overflow/underflow.
 pub fn simd_mul<const N: u32, T: MachineInteger + Copy>(
     x: FunArray<N, T>,
@@ -183,6 +302,37 @@ pub fn simd_xor<const N: u32, T: BitXor + Copy>(
     FunArray::from_fn(|i| x[i] ^ y[i])
 }
 
+/// The bits of an integer, zero-extended to a `u128`.
+fn zero_extended<T: MachineInteger>(x: T) -> u128 {
+    x.to_u128() & (u128::MAX >> (128 - T::BITS))
+}
+
+/// Counts the bits set in each element of a vector.
+pub fn simd_ctpop<const N: u32, T: MachineInteger + Copy>(x: FunArray<N, T>) -> FunArray<N, T> {
+    FunArray::from_fn(|i| T::from_u128(zero_extended(x[i]).count_ones() as u128))
+}
+
+/// Counts the leading zeros of each element of a vector. An element that is 0
+/// has as many leading zeros as it has bits.
+pub fn simd_ctlz<const N: u32, T: MachineInteger + Copy>(x: FunArray<N, T>) -> FunArray<N, T> {
+    FunArray::from_fn(|i| {
+        T::from_u128((zero_extended(x[i]).leading_zeros() - (128 - T::BITS)) as u128)
+    })
+}
+
+/// Counts the trailing zeros of each element of a vector. An element that is
+/// 0 has as many trailing zeros as it has bits.
+pub fn simd_cttz<const N: u32, T: MachineInteger + Copy>(x: FunArray<N, T>) -> FunArray<N, T> {
+    FunArray::from_fn(|i| T::from_u128(zero_extended(x[i]).trailing_zeros().min(T::BITS) as u128))
+}
+
+/// Reverses the order of the bits of each element of a vector.
+pub fn simd_bitreverse<const N: u32, T: MachineInteger + Copy>(
+    x: FunArray<N, T>,
+) -> FunArray<N, T> {
+    FunArray::from_fn(|i| T::from_u128(zero_extended(x[i]).reverse_bits() >> (128 - T::BITS)))
+}
+
 pub trait CastsFrom<T> {
     fn cast(a: T) -> Self;
 }
@@ -423,12 +573,26 @@ pub fn simd_ge<const N: u32, T: Ord + MachineInteger + Copy>(
 
 /// Shuffles two vectors by the indices in idx.
 ///
-/// For safety, `N2 <= N1 + N3` must hold.
+/// Lane `i` of the result is lane `idx[i]` of the concatenation of `x` and `y`,
+/// so there must be one index per lane of the result (`N2 == N3`), and every
+/// index must be below `2 * N1`. Both are checked, and a violation panics with
+/// the offending index and the bound it exceeds.
 pub fn simd_shuffle<T: Copy, const N1: u32, const N2: usize, const N3: u32>(
     x: FunArray<N1, T>,
     y: FunArray<N1, T>,
     idx: [u32; N2],
 ) -> FunArray<N3, T> {
+    assert!(
+        N2 == N3 as usize,
+        "simd_shuffle: {N2} indices given for a result of {N3} lanes"
+    );
+    for (lane, &i) in idx.iter().enumerate() {
+        assert!(
+            i < 2 * N1,
+            "simd_shuffle: index {i} (for lane {lane}) out of range for two vectors of {N1} lanes, expected an index below {}",
+            2 * N1
+        );
+    }
     FunArray::from_fn(|i| {
         let i = idx[i as usize];
         if i < N1 {
@@ -931,13 +1095,27 @@ pub(crate) use simd_bitmask_big;
 ///
 /// # Safety
 /// `mask` must only contain `0` and `!0`.
-
+///
+/// The lane count of `mask` is tied to the one of the data by `N`. Its element
+/// width must also match the one of the data, as the masks produced by
+/// `simd_lt` and friends do: a handwritten model passing, e.g., a 16-bit mask
+/// to select between 32-bit lanes is almost certainly reading the wrong lanes.
+/// Both this and the `0`/`!0` requirement are checked in debug builds.
 pub fn simd_select<const N: u32, T1: Eq + MachineInteger, T2: Copy>(
     mask: FunArray<N, T1>,
     if_true: FunArray<N, T2>,
     if_false: FunArray<N, T2>,
 ) -> FunArray<N, T2> {
+    debug_assert_eq!(
+        T1::BITS as usize,
+        8 * core::mem::size_of::<T2>(),
+        "simd_select: the mask elements must be as wide as the data elements"
+    );
     FunArray::from_fn(|i| {
+        debug_assert!(
+            mask[i] == T1::ONES || mask[i] == T1::ZEROS,
+            "simd_select: lane {i} of the mask is neither 0 nor !0"
+        );
         if mask[i] == T1::ONES {
             if_true[i]
         } else {
@@ -945,3 +1123,256 @@ pub fn simd_select<const N: u32, T1: Eq + MachineInteger, T2: Copy>(
         }
     })
 }
+
+/// Selects elements from a bitmask.
+///
+/// For each element, if bit `i` of `mask` is set, select the element from
+/// `if_true`. Otherwise, select the element from `if_false`. This is how the
+/// AVX-512 mask registers (e.g. `__mmask16`) are used to blend vectors.
+pub fn simd_select_bitmask<const N: u32, M: MachineInteger + Copy, T: Copy>(
+    mask: M,
+    if_true: FunArray<N, T>,
+    if_false: FunArray<N, T>,
+) -> FunArray<N, T> {
+    FunArray::from_fn(|i| {
+        if (mask.to_u128() >> i) & 1 == 1 {
+            if_true[i]
+        } else {
+            if_false[i]
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::test::{HasRandom, HasRandomBiased};
+
+    /// Checks the bit counts of random vectors against the ones of `core`. The
+    /// lanes are biased towards `0` and `-1`, where the counts are extremal.
+    macro_rules! mk_bit_count_tests {
+        ($($name:ident: $ty:ty),*) => {
+            $(
+            #[test]
+            fn $name() {
+                for _ in 0..1000 {
+                    let x = FunArray::<8, $ty>::random_biased();
+                    assert_eq!(simd_ctpop(x), FunArray::from_fn(|i| x[i].count_ones() as $ty));
+                    assert_eq!(simd_ctlz(x), FunArray::from_fn(|i| x[i].leading_zeros() as $ty));
+                    assert_eq!(simd_cttz(x), FunArray::from_fn(|i| x[i].trailing_zeros() as $ty));
+                }
+            }
+            )*
+        };
+    }
+    mk_bit_count_tests!(
+        bit_counts_i8: i8, bit_counts_i16: i16, bit_counts_i32: i32, bit_counts_i64: i64,
+        bit_counts_i128: i128,
+        bit_counts_u8: u8, bit_counts_u16: u16, bit_counts_u32: u32, bit_counts_u64: u64,
+        bit_counts_u128: u128
+    );
+
+    /// Checks that interpreting a bit-vector as a vector of lanes, and back,
+    /// preserves every bit, and that the lanes are laid out from the least
+    /// significant bits up.
+    macro_rules! mk_round_trip_tests {
+        ($($name:ident: $n:literal, $vty:ident, $as:ident),*) => {
+            $(
+            #[test]
+            fn $name() {
+                for _ in 0..100 {
+                    let bv = BitVec::<$n>::random();
+                    assert_eq!(BitVec::from(bv.$as()), bv);
+                    let v = $vty::random();
+                    let bytes: Vec<u8> = BitVec::from(v).to_vec();
+                    let le_bytes: Vec<u8> = v.as_vec().iter().flat_map(|x| x.to_le_bytes()).collect();
+                    assert_eq!(bytes, le_bytes);
+                    assert_eq!(BitVec::from(v).$as(), v);
+                }
+            }
+            )*
+        };
+    }
+    mk_round_trip_tests!(
+        round_trip_f32x16: 512, f32x16, as_f32x16,
+        round_trip_f64x8: 512, f64x8, as_f64x8,
+        round_trip_i8x64: 512, i8x64, as_i8x64,
+        round_trip_u8x64: 512, u8x64, as_u8x64,
+        round_trip_i64x8: 512, i64x8, as_i64x8,
+        round_trip_u64x8: 512, u64x8, as_u64x8
+    );
+
+    /// Lists, for each bit-vector width, the lane views the model files call.
+    /// Each entry expands to a call of the matching `as_*` method, so a view
+    /// missing from `interpretations!` fails to build here, with its entry as
+    /// the location, rather than in whichever model first needs it.
+    macro_rules! mk_interpretation_checks {
+        ($($n:literal: [$($vty:ident),*]),*) => {
+            const MODEL_INTERPRETATIONS: &[&str] = &[$($(stringify!($vty)),*),*];
+
+            #[test]
+            fn interpretations() {
+                $($(
+                pastey::paste! {
+                    assert_eq!(BitVec::<$n>::ZERO().[< as_ $vty >](), $vty::ZERO());
+                }
+                )*)*
+            }
+        };
+    }
+    mk_interpretation_checks!(
+        512: [i32x16],
+        256: [i8x32, i16x16, i32x8, i64x4, u8x32, u16x16, u32x8, u64x4, f32x8, f64x4],
+        128: [i8x16, i16x8, i32x4, i64x2, u8x16, u16x8, u32x4, u64x2, f32x4, f64x2]
+    );
+
+    /// Checks that `mk_interpretation_checks!` lists exactly the `.as_*()` lane
+    /// views that appear in the model files, so that it is kept in sync when a
+    /// model starts or stops needing one.
+    #[test]
+    fn interpretations_of_models() {
+        const MODELS: &[&str] = &[
+            include_str!("../core_arch/x86/models/sse.rs"),
+            include_str!("../core_arch/x86/models/sse_handwritten.rs"),
+            include_str!("../core_arch/x86/models/sse2.rs"),
+            include_str!("../core_arch/x86/models/sse2_handwritten.rs"),
+            include_str!("../core_arch/x86/models/ssse3.rs"),
+            include_str!("../core_arch/x86/models/ssse3_handwritten.rs"),
+            include_str!("../core_arch/x86/models/sse41.rs"),
+            include_str!("../core_arch/x86/models/sse41_handwritten.rs"),
+            include_str!("../core_arch/x86/models/sse42.rs"),
+            include_str!("../core_arch/x86/models/sse42_handwritten.rs"),
+            include_str!("../core_arch/x86/models/avx.rs"),
+            include_str!("../core_arch/x86/models/avx_handwritten.rs"),
+            include_str!("../core_arch/x86/models/avx2.rs"),
+            include_str!("../core_arch/x86/models/avx2_handwritten.rs"),
+            include_str!("../core_arch/x86/models/avx512f.rs"),
+            include_str!("../core_arch/arm_shared/models/neon.rs"),
+            include_str!("../core_arch/arm_shared/models/neon_handwritten.rs"),
+        ];
+        let mut called: Vec<&str> = MODELS
+            .iter()
+            .flat_map(|source| source.split(".as_").skip(1))
+            .filter_map(|rest| rest.split_once("()").map(|(name, _)| name))
+            .filter(|name| {
+                let mut parts = name.splitn(2, 'x');
+                let (lane, count) = (parts.next().unwrap(), parts.next().unwrap_or(""));
+                lane.len() > 1
+                    && matches!(lane.as_bytes()[0], b'i' | b'u' | b'f')
+                    && lane[1..].bytes().all(|b| b.is_ascii_digit())
+                    && !count.is_empty()
+                    && count.bytes().all(|b| b.is_ascii_digit())
+            })
+            .collect();
+        called.sort();
+        called.dedup();
+        let mut listed = MODEL_INTERPRETATIONS.to_vec();
+        listed.sort();
+        assert_eq!(called, listed);
+    }
+
+    /// `simd_fneg` must change the sign bit of every lane and nothing else,
+    /// including for zeros, infinities, subnormals and NaNs with a payload.
+    #[test]
+    fn fneg_flips_only_the_sign_bit() {
+        let x = f32x8::new(
+            1.5,
+            -0.0,
+            0.0,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::MIN_POSITIVE / 2.0,
+            f32::NAN,
+            f32::from_bits(0xffc0_1234),
+        );
+        let r = simd_fneg(x);
+        for i in 0..8 {
+            assert_eq!(r[i].to_bits(), x[i].to_bits() ^ (1 << 31), "lane {i}");
+        }
+        let x = f64x4::new(
+            -2.25,
+            0.0,
+            f64::NEG_INFINITY,
+            f64::from_bits(0x7ff8_0000_0000_beef),
+        );
+        let r = simd_fneg(x);
+        for i in 0..4 {
+            assert_eq!(r[i].to_bits(), x[i].to_bits() ^ (1 << 63), "lane {i}");
+        }
+        for _ in 0..100 {
+            let x = f64x8::random();
+            let r = simd_fneg(x);
+            for i in 0..8 {
+                assert_eq!(r[i].to_bits(), (-x[i]).to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn bit_counts_of_zero() {
+        let x = i32x4::splat(0);
+        assert_eq!(simd_ctpop(x), i32x4::splat(0));
+        assert_eq!(simd_ctlz(x), i32x4::splat(32));
+        assert_eq!(simd_cttz(x), i32x4::splat(32));
+        let x = u8x16::splat(0);
+        assert_eq!(simd_ctlz(x), u8x16::splat(8));
+        assert_eq!(simd_cttz(x), u8x16::splat(8));
+    }
+
+    #[test]
+    fn bit_counts_of_ones() {
+        let x = i16x8::splat(-1);
+        assert_eq!(simd_ctpop(x), i16x8::splat(16));
+        assert_eq!(simd_ctlz(x), i16x8::splat(0));
+        assert_eq!(simd_cttz(x), i16x8::splat(0));
+        let x = u64x2::splat(u64::MAX);
+        assert_eq!(simd_ctpop(x), u64x2::splat(64));
+    }
+
+    #[test]
+    fn select_with_comparison_mask() {
+        let a = i32x4::from_fn(|i| i as i32 - 2);
+        let b = i32x4::splat(0);
+        assert_eq!(simd_select(simd_lt(a, b), a, b), i32x4::new(-2, -1, 0, 0));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "must be as wide as the data elements")]
+    fn select_with_narrow_mask() {
+        let mask = i16x4::splat(-1);
+        simd_select(mask, i32x4::splat(1), i32x4::splat(0));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "lane 2 of the mask is neither 0 nor !0")]
+    fn select_with_non_canonical_mask() {
+        let mask = i32x4::new(-1, 0, 1, 0);
+        simd_select(mask, i32x4::splat(1), i32x4::splat(0));
+    }
+
+    #[test]
+    fn shuffle_concatenates() {
+        let x = i32x4::new(0, 1, 2, 3);
+        let y = i32x4::new(4, 5, 6, 7);
+        let r: i32x4 = simd_shuffle(x, y, [7, 0, 4, 3]);
+        assert_eq!(r, i32x4::new(7, 0, 4, 3));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "index 8 (for lane 2) out of range for two vectors of 4 lanes, expected an index below 8"
+    )]
+    fn shuffle_out_of_range() {
+        let x = i32x4::splat(0);
+        let _: i32x4 = simd_shuffle(x, x, [0, 1, 8, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "3 indices given for a result of 4 lanes")]
+    fn shuffle_wrong_index_count() {
+        let x = i32x4::splat(0);
+        let _: i32x4 = simd_shuffle(x, x, [0, 1, 2]);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::abstractions::bit::MachineNumeric;
+
 /// Converts one type to another
 pub fn transmute<T, U: From<T>>(a: T) -> U {
     a.into()
@@ -54,6 +56,27 @@ macro_rules! static_assert_simm_bits {
     };
 }
 
+#[allow(unused_macros)]
+#[macro_export]
+macro_rules! static_assert_imm8_scale {
+    ($imm:ident) => {
+        static_assert!(
+            $imm == 1 || $imm == 2 || $imm == 4 || $imm == 8,
+            "Index scale must be 1, 2, 4, or 8"
+        )
+    };
+}
+
 pub use static_assert;
+pub use static_assert_imm8_scale;
 pub use static_assert_simm_bits;
 pub use static_assert_uimm_bits;
+
+/// Views a slice of machine numbers as the bytes making it up in memory
+/// (little-endian), the way a pointer to it is cast to `*const i8` upstream.
+pub fn slice_as_bytes<T: MachineNumeric + Copy>(slice: &[T]) -> Vec<i8> {
+    slice
+        .iter()
+        .flat_map(|x| (0..T::BITS / 8).map(move |k| (x.to_u128() >> (8 * k)) as i8))
+        .collect()
+}
@@ -3,3 +3,6 @@ pub mod x86;
 pub use x86 as x86_64;
 
 pub mod arm_shared;
+
+#[cfg(test)]
+mod coverage;
@@ -39,6 +39,36 @@ mod types {
     pub type uint32x2_t = u32x2;
     pub type uint16x4_t = u16x4;
     pub type uint8x8_t = u8x8;
+    pub type float32x2_t = f32x2;
+    pub type float32x4_t = f32x4;
+    pub type int8x8x2_t = (int8x8_t, int8x8_t);
+    pub type int8x16x2_t = (int8x16_t, int8x16_t);
+    pub type int16x4x2_t = (int16x4_t, int16x4_t);
+    pub type int16x8x2_t = (int16x8_t, int16x8_t);
+    pub type int32x2x2_t = (int32x2_t, int32x2_t);
+    pub type int32x4x2_t = (int32x4_t, int32x4_t);
+    pub type uint8x8x2_t = (uint8x8_t, uint8x8_t);
+    pub type uint8x16x2_t = (uint8x16_t, uint8x16_t);
+    pub type uint16x4x2_t = (uint16x4_t, uint16x4_t);
+    pub type uint16x8x2_t = (uint16x8_t, uint16x8_t);
+    pub type uint32x2x2_t = (uint32x2_t, uint32x2_t);
+    pub type uint32x4x2_t = (uint32x4_t, uint32x4_t);
+    pub type float32x4x2_t = (float32x4_t, float32x4_t);
+    pub type int8x16x3_t = (int8x16_t, int8x16_t, int8x16_t);
+    pub type int16x8x3_t = (int16x8_t, int16x8_t, int16x8_t);
+    pub type int32x4x3_t = (int32x4_t, int32x4_t, int32x4_t);
+    pub type uint8x16x3_t = (uint8x16_t, uint8x16_t, uint8x16_t);
+    pub type uint16x8x3_t = (uint16x8_t, uint16x8_t, uint16x8_t);
+    pub type uint32x4x3_t = (uint32x4_t, uint32x4_t, uint32x4_t);
+    pub type float32x4x3_t = (float32x4_t, float32x4_t, float32x4_t);
+    pub type int8x16x4_t = (int8x16_t, int8x16_t, int8x16_t, int8x16_t);
+    pub type int16x8x4_t = (int16x8_t, int16x8_t, int16x8_t, int16x8_t);
+    pub type int32x4x4_t = (int32x4_t, int32x4_t, int32x4_t, int32x4_t);
+    pub type uint8x16x4_t = (uint8x16_t, uint8x16_t, uint8x16_t, uint8x16_t);
+    pub type uint16x8x4_t = (uint16x8_t, uint16x8_t, uint16x8_t, uint16x8_t);
+    pub type uint32x4x4_t = (uint32x4_t, uint32x4_t, uint32x4_t, uint32x4_t);
+    pub type float32x4x4_t = (float32x4_t, float32x4_t, float32x4_t, float32x4_t);
 }
 
 pub mod neon;
+pub mod neon_handwritten;
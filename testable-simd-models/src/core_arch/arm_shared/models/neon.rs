@@ -1,5 +1,7 @@
+use super::neon_handwritten::*;
 use super::types::*;
 use crate::abstractions::simd::*;
+use crate::abstractions::utilities::*;
 
 pub fn vaba_s16(a: int16x4_t, b: int16x4_t, c: int16x4_t) -> int16x4_t {
     simd_add(a, vabd_s16(b, c))
@@ -148,6 +150,14 @@ pub fn vabsq_s32(a: int32x4_t) -> int32x4_t {
     simd_abs(a)
 }
 
+pub fn vabs_s64(a: int64x1_t) -> int64x1_t {
+    simd_abs(a)
+}
+
+pub fn vabsq_s64(a: int64x2_t) -> int64x2_t {
+    simd_abs(a)
+}
+
 pub fn vadd_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t {
     simd_add(a, b)
 }
@@ -156,6 +166,10 @@ pub fn vadd_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t {
     simd_add(a, b)
 }
 
+pub fn vadd_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t {
+    simd_add(a, b)
+}
+
 pub fn vadd_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t {
     simd_add(a, b)
 }
@@ -168,6 +182,10 @@ pub fn vadd_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t {
     simd_add(a, b)
 }
 
+pub fn vadd_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t {
+    simd_add(a, b)
+}
+
 pub fn vadd_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t {
     simd_add(a, b)
 }
@@ -378,6 +396,62 @@ pub fn vaddw_high_u8(a: uint16x8_t, b: uint8x16_t) -> uint16x8_t {
     simd_add(a, b)
 }
 
+pub fn vaddv_s8(a: int8x8_t) -> i8 {
+    a.iter().fold(0, |acc, &x| acc.wrapping_add(x))
+}
+
+pub fn vaddvq_s8(a: int8x16_t) -> i8 {
+    a.iter().fold(0, |acc, &x| acc.wrapping_add(x))
+}
+
+pub fn vaddv_s16(a: int16x4_t) -> i16 {
+    a.iter().fold(0, |acc, &x| acc.wrapping_add(x))
+}
+
+pub fn vaddvq_s16(a: int16x8_t) -> i16 {
+    a.iter().fold(0, |acc, &x| acc.wrapping_add(x))
+}
+
+pub fn vaddv_s32(a: int32x2_t) -> i32 {
+    a.iter().fold(0, |acc, &x| acc.wrapping_add(x))
+}
+
+pub fn vaddvq_s32(a: int32x4_t) -> i32 {
+    a.iter().fold(0, |acc, &x| acc.wrapping_add(x))
+}
+
+pub fn vaddv_u8(a: uint8x8_t) -> u8 {
+    a.iter().fold(0, |acc, &x| acc.wrapping_add(x))
+}
+
+pub fn vaddvq_u8(a: uint8x16_t) -> u8 {
+    a.iter().fold(0, |acc, &x| acc.wrapping_add(x))
+}
+
+pub fn vaddv_u16(a: uint16x4_t) -> u16 {
+    a.iter().fold(0, |acc, &x| acc.wrapping_add(x))
+}
+
+pub fn vaddvq_u16(a: uint16x8_t) -> u16 {
+    a.iter().fold(0, |acc, &x| acc.wrapping_add(x))
+}
+
+pub fn vaddv_u32(a: uint32x2_t) -> u32 {
+    a.iter().fold(0, |acc, &x| acc.wrapping_add(x))
+}
+
+pub fn vaddvq_u32(a: uint32x4_t) -> u32 {
+    a.iter().fold(0, |acc, &x| acc.wrapping_add(x))
+}
+
+pub fn vaddvq_s64(a: int64x2_t) -> i64 {
+    a.iter().fold(0, |acc, &x| acc.wrapping_add(x))
+}
+
+pub fn vaddvq_u64(a: uint64x2_t) -> u64 {
+    a.iter().fold(0, |acc, &x| acc.wrapping_add(x))
+}
+
 pub fn vaddw_s16(a: int32x4_t, b: int16x4_t) -> int32x4_t {
     let b: int32x4_t = simd_cast(b);
     simd_add(a, b)
@@ -728,6 +802,22 @@ pub fn vceqq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
     simd_eq(a, b)
 }
 
+pub fn vceq_s64(a: int64x1_t, b: int64x1_t) -> uint64x1_t {
+    simd_cast(simd_eq(a, b))
+}
+
+pub fn vceqq_s64(a: int64x2_t, b: int64x2_t) -> uint64x2_t {
+    simd_cast(simd_eq(a, b))
+}
+
+pub fn vceq_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t {
+    simd_eq(a, b)
+}
+
+pub fn vceqq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+    simd_eq(a, b)
+}
+
 pub fn vcge_s8(a: int8x8_t, b: int8x8_t) -> uint8x8_t {
     simd_cast(simd_ge(a, b))
 }
@@ -776,6 +866,22 @@ pub fn vcgeq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
     simd_ge(a, b)
 }
 
+pub fn vcge_s64(a: int64x1_t, b: int64x1_t) -> uint64x1_t {
+    simd_cast(simd_ge(a, b))
+}
+
+pub fn vcgeq_s64(a: int64x2_t, b: int64x2_t) -> uint64x2_t {
+    simd_cast(simd_ge(a, b))
+}
+
+pub fn vcge_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t {
+    simd_ge(a, b)
+}
+
+pub fn vcgeq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+    simd_ge(a, b)
+}
+
 pub fn vcgt_s8(a: int8x8_t, b: int8x8_t) -> uint8x8_t {
     simd_cast(simd_gt(a, b))
 }
@@ -824,6 +930,22 @@ pub fn vcgtq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
     simd_gt(a, b)
 }
 
+pub fn vcgt_s64(a: int64x1_t, b: int64x1_t) -> uint64x1_t {
+    simd_cast(simd_gt(a, b))
+}
+
+pub fn vcgtq_s64(a: int64x2_t, b: int64x2_t) -> uint64x2_t {
+    simd_cast(simd_gt(a, b))
+}
+
+pub fn vcgt_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t {
+    simd_gt(a, b)
+}
+
+pub fn vcgtq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+    simd_gt(a, b)
+}
+
 pub fn vcle_s8(a: int8x8_t, b: int8x8_t) -> uint8x8_t {
     simd_cast(simd_le(a, b))
 }
@@ -870,4 +992,2895 @@ pub fn vcle_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t {
 
 pub fn vcleq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
     simd_le(a, b)
-}
\ No newline at end of file
+}
+
+pub fn vcle_s64(a: int64x1_t, b: int64x1_t) -> uint64x1_t {
+    simd_cast(simd_le(a, b))
+}
+
+pub fn vcleq_s64(a: int64x2_t, b: int64x2_t) -> uint64x2_t {
+    simd_cast(simd_le(a, b))
+}
+
+pub fn vcle_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t {
+    simd_le(a, b)
+}
+
+pub fn vcleq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+    simd_le(a, b)
+}
+
+pub fn vclt_s8(a: int8x8_t, b: int8x8_t) -> uint8x8_t {
+    simd_cast(simd_lt(a, b))
+}
+
+pub fn vcltq_s8(a: int8x16_t, b: int8x16_t) -> uint8x16_t {
+    simd_cast(simd_lt(a, b))
+}
+
+pub fn vclt_s16(a: int16x4_t, b: int16x4_t) -> uint16x4_t {
+    simd_cast(simd_lt(a, b))
+}
+
+pub fn vcltq_s16(a: int16x8_t, b: int16x8_t) -> uint16x8_t {
+    simd_cast(simd_lt(a, b))
+}
+
+pub fn vclt_s32(a: int32x2_t, b: int32x2_t) -> uint32x2_t {
+    simd_cast(simd_lt(a, b))
+}
+
+pub fn vcltq_s32(a: int32x4_t, b: int32x4_t) -> uint32x4_t {
+    simd_cast(simd_lt(a, b))
+}
+
+pub fn vclt_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t {
+    simd_lt(a, b)
+}
+
+pub fn vcltq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    simd_lt(a, b)
+}
+
+pub fn vclt_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t {
+    simd_lt(a, b)
+}
+
+pub fn vcltq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+    simd_lt(a, b)
+}
+
+pub fn vclt_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t {
+    simd_lt(a, b)
+}
+
+pub fn vcltq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+    simd_lt(a, b)
+}
+
+pub fn vclt_s64(a: int64x1_t, b: int64x1_t) -> uint64x1_t {
+    simd_cast(simd_lt(a, b))
+}
+
+pub fn vcltq_s64(a: int64x2_t, b: int64x2_t) -> uint64x2_t {
+    simd_cast(simd_lt(a, b))
+}
+
+pub fn vclt_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t {
+    simd_lt(a, b)
+}
+
+pub fn vcltq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+    simd_lt(a, b)
+}
+
+pub fn vclz_s8(a: int8x8_t) -> int8x8_t {
+    simd_ctlz(a)
+}
+
+pub fn vclzq_s8(a: int8x16_t) -> int8x16_t {
+    simd_ctlz(a)
+}
+
+pub fn vclz_s16(a: int16x4_t) -> int16x4_t {
+    simd_ctlz(a)
+}
+
+pub fn vclzq_s16(a: int16x8_t) -> int16x8_t {
+    simd_ctlz(a)
+}
+
+pub fn vclz_s32(a: int32x2_t) -> int32x2_t {
+    simd_ctlz(a)
+}
+
+pub fn vclzq_s32(a: int32x4_t) -> int32x4_t {
+    simd_ctlz(a)
+}
+
+pub fn vclz_u8(a: uint8x8_t) -> uint8x8_t {
+    simd_ctlz(a)
+}
+
+pub fn vclzq_u8(a: uint8x16_t) -> uint8x16_t {
+    simd_ctlz(a)
+}
+
+pub fn vclz_u16(a: uint16x4_t) -> uint16x4_t {
+    simd_ctlz(a)
+}
+
+pub fn vclzq_u16(a: uint16x8_t) -> uint16x8_t {
+    simd_ctlz(a)
+}
+
+pub fn vclz_u32(a: uint32x2_t) -> uint32x2_t {
+    simd_ctlz(a)
+}
+
+pub fn vclzq_u32(a: uint32x4_t) -> uint32x4_t {
+    simd_ctlz(a)
+}
+
+pub fn vcnt_s8(a: int8x8_t) -> int8x8_t {
+    simd_ctpop(a)
+}
+
+pub fn vcntq_s8(a: int8x16_t) -> int8x16_t {
+    simd_ctpop(a)
+}
+
+pub fn vcnt_u8(a: uint8x8_t) -> uint8x8_t {
+    simd_ctpop(a)
+}
+
+pub fn vcntq_u8(a: uint8x16_t) -> uint8x16_t {
+    simd_ctpop(a)
+}
+
+pub fn vcombine_s8(low: int8x8_t, high: int8x8_t) -> int8x16_t {
+    simd_shuffle(
+        low,
+        high,
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    )
+}
+
+pub fn vcombine_s16(low: int16x4_t, high: int16x4_t) -> int16x8_t {
+    simd_shuffle(low, high, [0, 1, 2, 3, 4, 5, 6, 7])
+}
+
+pub fn vcombine_s32(low: int32x2_t, high: int32x2_t) -> int32x4_t {
+    simd_shuffle(low, high, [0, 1, 2, 3])
+}
+
+pub fn vcombine_s64(low: int64x1_t, high: int64x1_t) -> int64x2_t {
+    simd_shuffle(low, high, [0, 1])
+}
+
+pub fn vcombine_u8(low: uint8x8_t, high: uint8x8_t) -> uint8x16_t {
+    simd_shuffle(
+        low,
+        high,
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    )
+}
+
+pub fn vcombine_u16(low: uint16x4_t, high: uint16x4_t) -> uint16x8_t {
+    simd_shuffle(low, high, [0, 1, 2, 3, 4, 5, 6, 7])
+}
+
+pub fn vcombine_u32(low: uint32x2_t, high: uint32x2_t) -> uint32x4_t {
+    simd_shuffle(low, high, [0, 1, 2, 3])
+}
+
+pub fn vcombine_u64(low: uint64x1_t, high: uint64x1_t) -> uint64x2_t {
+    simd_shuffle(low, high, [0, 1])
+}
+
+pub fn vdup_n_s8(value: i8) -> int8x8_t {
+    int8x8_t::splat(value)
+}
+
+pub fn vdupq_n_s8(value: i8) -> int8x16_t {
+    int8x16_t::splat(value)
+}
+
+pub fn vdup_n_s16(value: i16) -> int16x4_t {
+    int16x4_t::splat(value)
+}
+
+pub fn vdupq_n_s16(value: i16) -> int16x8_t {
+    int16x8_t::splat(value)
+}
+
+pub fn vdup_n_s32(value: i32) -> int32x2_t {
+    int32x2_t::splat(value)
+}
+
+pub fn vdupq_n_s32(value: i32) -> int32x4_t {
+    int32x4_t::splat(value)
+}
+
+pub fn vdup_n_s64(value: i64) -> int64x1_t {
+    int64x1_t::splat(value)
+}
+
+pub fn vdupq_n_s64(value: i64) -> int64x2_t {
+    int64x2_t::splat(value)
+}
+
+pub fn vdup_n_u8(value: u8) -> uint8x8_t {
+    uint8x8_t::splat(value)
+}
+
+pub fn vdupq_n_u8(value: u8) -> uint8x16_t {
+    uint8x16_t::splat(value)
+}
+
+pub fn vdup_n_u16(value: u16) -> uint16x4_t {
+    uint16x4_t::splat(value)
+}
+
+pub fn vdupq_n_u16(value: u16) -> uint16x8_t {
+    uint16x8_t::splat(value)
+}
+
+pub fn vdup_n_u32(value: u32) -> uint32x2_t {
+    uint32x2_t::splat(value)
+}
+
+pub fn vdupq_n_u32(value: u32) -> uint32x4_t {
+    uint32x4_t::splat(value)
+}
+
+pub fn vdup_n_u64(value: u64) -> uint64x1_t {
+    uint64x1_t::splat(value)
+}
+
+pub fn vdupq_n_u64(value: u64) -> uint64x2_t {
+    uint64x2_t::splat(value)
+}
+
+pub fn vdup_lane_s8<const N: i32>(a: int8x8_t) -> int8x8_t {
+    static_assert_uimm_bits!(N, 3);
+    simd_shuffle(a, a, [N as u32; 8])
+}
+
+pub fn vdupq_lane_s8<const N: i32>(a: int8x8_t) -> int8x16_t {
+    static_assert_uimm_bits!(N, 3);
+    simd_shuffle(a, a, [N as u32; 16])
+}
+
+pub fn vdup_lane_s16<const N: i32>(a: int16x4_t) -> int16x4_t {
+    static_assert_uimm_bits!(N, 2);
+    simd_shuffle(a, a, [N as u32; 4])
+}
+
+pub fn vdupq_lane_s16<const N: i32>(a: int16x4_t) -> int16x8_t {
+    static_assert_uimm_bits!(N, 2);
+    simd_shuffle(a, a, [N as u32; 8])
+}
+
+pub fn vdup_lane_s32<const N: i32>(a: int32x2_t) -> int32x2_t {
+    static_assert_uimm_bits!(N, 1);
+    simd_shuffle(a, a, [N as u32; 2])
+}
+
+pub fn vdupq_lane_s32<const N: i32>(a: int32x2_t) -> int32x4_t {
+    static_assert_uimm_bits!(N, 1);
+    simd_shuffle(a, a, [N as u32; 4])
+}
+
+pub fn vdup_lane_s64<const N: i32>(a: int64x1_t) -> int64x1_t {
+    static_assert_uimm_bits!(N, 0);
+    simd_shuffle(a, a, [N as u32; 1])
+}
+
+pub fn vdupq_lane_s64<const N: i32>(a: int64x1_t) -> int64x2_t {
+    static_assert_uimm_bits!(N, 0);
+    simd_shuffle(a, a, [N as u32; 2])
+}
+
+pub fn vdup_lane_u8<const N: i32>(a: uint8x8_t) -> uint8x8_t {
+    static_assert_uimm_bits!(N, 3);
+    simd_shuffle(a, a, [N as u32; 8])
+}
+
+pub fn vdupq_lane_u8<const N: i32>(a: uint8x8_t) -> uint8x16_t {
+    static_assert_uimm_bits!(N, 3);
+    simd_shuffle(a, a, [N as u32; 16])
+}
+
+pub fn vdup_lane_u16<const N: i32>(a: uint16x4_t) -> uint16x4_t {
+    static_assert_uimm_bits!(N, 2);
+    simd_shuffle(a, a, [N as u32; 4])
+}
+
+pub fn vdupq_lane_u16<const N: i32>(a: uint16x4_t) -> uint16x8_t {
+    static_assert_uimm_bits!(N, 2);
+    simd_shuffle(a, a, [N as u32; 8])
+}
+
+pub fn vdup_lane_u32<const N: i32>(a: uint32x2_t) -> uint32x2_t {
+    static_assert_uimm_bits!(N, 1);
+    simd_shuffle(a, a, [N as u32; 2])
+}
+
+pub fn vdupq_lane_u32<const N: i32>(a: uint32x2_t) -> uint32x4_t {
+    static_assert_uimm_bits!(N, 1);
+    simd_shuffle(a, a, [N as u32; 4])
+}
+
+pub fn vdup_lane_u64<const N: i32>(a: uint64x1_t) -> uint64x1_t {
+    static_assert_uimm_bits!(N, 0);
+    simd_shuffle(a, a, [N as u32; 1])
+}
+
+pub fn vdupq_lane_u64<const N: i32>(a: uint64x1_t) -> uint64x2_t {
+    static_assert_uimm_bits!(N, 0);
+    simd_shuffle(a, a, [N as u32; 2])
+}
+
+pub fn veor_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t {
+    simd_xor(a, b)
+}
+
+pub fn veorq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+    simd_xor(a, b)
+}
+
+pub fn veor_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t {
+    simd_xor(a, b)
+}
+
+pub fn veorq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+    simd_xor(a, b)
+}
+
+pub fn veor_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t {
+    simd_xor(a, b)
+}
+
+pub fn veorq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+    simd_xor(a, b)
+}
+
+pub fn veor_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t {
+    simd_xor(a, b)
+}
+
+pub fn veorq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t {
+    simd_xor(a, b)
+}
+
+pub fn veor_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t {
+    simd_xor(a, b)
+}
+
+pub fn veorq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    simd_xor(a, b)
+}
+
+pub fn veor_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t {
+    simd_xor(a, b)
+}
+
+pub fn veorq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+    simd_xor(a, b)
+}
+
+pub fn veor_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t {
+    simd_xor(a, b)
+}
+
+pub fn veorq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+    simd_xor(a, b)
+}
+
+pub fn veor_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t {
+    simd_xor(a, b)
+}
+
+pub fn veorq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+    simd_xor(a, b)
+}
+
+pub fn vext_s8<const N: i32>(a: int8x8_t, b: int8x8_t) -> int8x8_t {
+    static_assert_uimm_bits!(N, 3);
+    simd_shuffle(
+        a,
+        b,
+        core::array::from_fn::<u32, 8, _>(|i| N as u32 + i as u32),
+    )
+}
+
+pub fn vextq_s8<const N: i32>(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+    static_assert_uimm_bits!(N, 4);
+    simd_shuffle(
+        a,
+        b,
+        core::array::from_fn::<u32, 16, _>(|i| N as u32 + i as u32),
+    )
+}
+
+pub fn vext_s16<const N: i32>(a: int16x4_t, b: int16x4_t) -> int16x4_t {
+    static_assert_uimm_bits!(N, 2);
+    simd_shuffle(
+        a,
+        b,
+        core::array::from_fn::<u32, 4, _>(|i| N as u32 + i as u32),
+    )
+}
+
+pub fn vextq_s16<const N: i32>(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+    static_assert_uimm_bits!(N, 3);
+    simd_shuffle(
+        a,
+        b,
+        core::array::from_fn::<u32, 8, _>(|i| N as u32 + i as u32),
+    )
+}
+
+pub fn vext_s32<const N: i32>(a: int32x2_t, b: int32x2_t) -> int32x2_t {
+    static_assert_uimm_bits!(N, 1);
+    simd_shuffle(
+        a,
+        b,
+        core::array::from_fn::<u32, 2, _>(|i| N as u32 + i as u32),
+    )
+}
+
+pub fn vextq_s32<const N: i32>(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+    static_assert_uimm_bits!(N, 2);
+    simd_shuffle(
+        a,
+        b,
+        core::array::from_fn::<u32, 4, _>(|i| N as u32 + i as u32),
+    )
+}
+
+pub fn vext_u8<const N: i32>(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t {
+    static_assert_uimm_bits!(N, 3);
+    simd_shuffle(
+        a,
+        b,
+        core::array::from_fn::<u32, 8, _>(|i| N as u32 + i as u32),
+    )
+}
+
+pub fn vextq_u8<const N: i32>(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    static_assert_uimm_bits!(N, 4);
+    simd_shuffle(
+        a,
+        b,
+        core::array::from_fn::<u32, 16, _>(|i| N as u32 + i as u32),
+    )
+}
+
+pub fn vext_u16<const N: i32>(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t {
+    static_assert_uimm_bits!(N, 2);
+    simd_shuffle(
+        a,
+        b,
+        core::array::from_fn::<u32, 4, _>(|i| N as u32 + i as u32),
+    )
+}
+
+pub fn vextq_u16<const N: i32>(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+    static_assert_uimm_bits!(N, 3);
+    simd_shuffle(
+        a,
+        b,
+        core::array::from_fn::<u32, 8, _>(|i| N as u32 + i as u32),
+    )
+}
+
+pub fn vext_u32<const N: i32>(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t {
+    static_assert_uimm_bits!(N, 1);
+    simd_shuffle(
+        a,
+        b,
+        core::array::from_fn::<u32, 2, _>(|i| N as u32 + i as u32),
+    )
+}
+
+pub fn vextq_u32<const N: i32>(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+    static_assert_uimm_bits!(N, 2);
+    simd_shuffle(
+        a,
+        b,
+        core::array::from_fn::<u32, 4, _>(|i| N as u32 + i as u32),
+    )
+}
+
+pub fn vfma_f32(a: float32x2_t, b: float32x2_t, c: float32x2_t) -> float32x2_t {
+    simd_fma(b, c, a)
+}
+
+pub fn vfmaq_f32(a: float32x4_t, b: float32x4_t, c: float32x4_t) -> float32x4_t {
+    simd_fma(b, c, a)
+}
+
+pub fn vget_high_s8(a: int8x16_t) -> int8x8_t {
+    simd_shuffle(a, a, [8, 9, 10, 11, 12, 13, 14, 15])
+}
+
+pub fn vget_high_s16(a: int16x8_t) -> int16x4_t {
+    simd_shuffle(a, a, [4, 5, 6, 7])
+}
+
+pub fn vget_high_s32(a: int32x4_t) -> int32x2_t {
+    simd_shuffle(a, a, [2, 3])
+}
+
+pub fn vget_high_s64(a: int64x2_t) -> int64x1_t {
+    simd_shuffle(a, a, [1])
+}
+
+pub fn vget_high_u8(a: uint8x16_t) -> uint8x8_t {
+    simd_shuffle(a, a, [8, 9, 10, 11, 12, 13, 14, 15])
+}
+
+pub fn vget_high_u16(a: uint16x8_t) -> uint16x4_t {
+    simd_shuffle(a, a, [4, 5, 6, 7])
+}
+
+pub fn vget_high_u32(a: uint32x4_t) -> uint32x2_t {
+    simd_shuffle(a, a, [2, 3])
+}
+
+pub fn vget_high_u64(a: uint64x2_t) -> uint64x1_t {
+    simd_shuffle(a, a, [1])
+}
+
+pub fn vget_lane_s8<const IMM5: i32>(v: int8x8_t) -> i8 {
+    static_assert_uimm_bits!(IMM5, 3);
+    simd_extract(v, IMM5 as u32)
+}
+
+pub fn vgetq_lane_s8<const IMM5: i32>(v: int8x16_t) -> i8 {
+    static_assert_uimm_bits!(IMM5, 4);
+    simd_extract(v, IMM5 as u32)
+}
+
+pub fn vget_lane_s16<const IMM5: i32>(v: int16x4_t) -> i16 {
+    static_assert_uimm_bits!(IMM5, 2);
+    simd_extract(v, IMM5 as u32)
+}
+
+pub fn vgetq_lane_s16<const IMM5: i32>(v: int16x8_t) -> i16 {
+    static_assert_uimm_bits!(IMM5, 3);
+    simd_extract(v, IMM5 as u32)
+}
+
+pub fn vget_lane_s32<const IMM5: i32>(v: int32x2_t) -> i32 {
+    static_assert_uimm_bits!(IMM5, 1);
+    simd_extract(v, IMM5 as u32)
+}
+
+pub fn vgetq_lane_s32<const IMM5: i32>(v: int32x4_t) -> i32 {
+    static_assert_uimm_bits!(IMM5, 2);
+    simd_extract(v, IMM5 as u32)
+}
+
+pub fn vget_lane_s64<const IMM5: i32>(v: int64x1_t) -> i64 {
+    static_assert_uimm_bits!(IMM5, 0);
+    simd_extract(v, IMM5 as u32)
+}
+
+pub fn vgetq_lane_s64<const IMM5: i32>(v: int64x2_t) -> i64 {
+    static_assert_uimm_bits!(IMM5, 1);
+    simd_extract(v, IMM5 as u32)
+}
+
+pub fn vget_lane_u8<const IMM5: i32>(v: uint8x8_t) -> u8 {
+    static_assert_uimm_bits!(IMM5, 3);
+    simd_extract(v, IMM5 as u32)
+}
+
+pub fn vgetq_lane_u8<const IMM5: i32>(v: uint8x16_t) -> u8 {
+    static_assert_uimm_bits!(IMM5, 4);
+    simd_extract(v, IMM5 as u32)
+}
+
+pub fn vget_lane_u16<const IMM5: i32>(v: uint16x4_t) -> u16 {
+    static_assert_uimm_bits!(IMM5, 2);
+    simd_extract(v, IMM5 as u32)
+}
+
+pub fn vgetq_lane_u16<const IMM5: i32>(v: uint16x8_t) -> u16 {
+    static_assert_uimm_bits!(IMM5, 3);
+    simd_extract(v, IMM5 as u32)
+}
+
+pub fn vget_lane_u32<const IMM5: i32>(v: uint32x2_t) -> u32 {
+    static_assert_uimm_bits!(IMM5, 1);
+    simd_extract(v, IMM5 as u32)
+}
+
+pub fn vgetq_lane_u32<const IMM5: i32>(v: uint32x4_t) -> u32 {
+    static_assert_uimm_bits!(IMM5, 2);
+    simd_extract(v, IMM5 as u32)
+}
+
+pub fn vget_lane_u64<const IMM5: i32>(v: uint64x1_t) -> u64 {
+    static_assert_uimm_bits!(IMM5, 0);
+    simd_extract(v, IMM5 as u32)
+}
+
+pub fn vgetq_lane_u64<const IMM5: i32>(v: uint64x2_t) -> u64 {
+    static_assert_uimm_bits!(IMM5, 1);
+    simd_extract(v, IMM5 as u32)
+}
+
+pub fn vget_low_s8(a: int8x16_t) -> int8x8_t {
+    simd_shuffle(a, a, [0, 1, 2, 3, 4, 5, 6, 7])
+}
+
+pub fn vget_low_s16(a: int16x8_t) -> int16x4_t {
+    simd_shuffle(a, a, [0, 1, 2, 3])
+}
+
+pub fn vget_low_s32(a: int32x4_t) -> int32x2_t {
+    simd_shuffle(a, a, [0, 1])
+}
+
+pub fn vget_low_s64(a: int64x2_t) -> int64x1_t {
+    simd_shuffle(a, a, [0])
+}
+
+pub fn vget_low_u8(a: uint8x16_t) -> uint8x8_t {
+    simd_shuffle(a, a, [0, 1, 2, 3, 4, 5, 6, 7])
+}
+
+pub fn vget_low_u16(a: uint16x8_t) -> uint16x4_t {
+    simd_shuffle(a, a, [0, 1, 2, 3])
+}
+
+pub fn vget_low_u32(a: uint32x4_t) -> uint32x2_t {
+    simd_shuffle(a, a, [0, 1])
+}
+
+pub fn vget_low_u64(a: uint64x2_t) -> uint64x1_t {
+    simd_shuffle(a, a, [0])
+}
+
+// The memory is modeled as the slice `ptr`, the lowest lane being read from
+// (and written to) `ptr[0]`. The `vld2q` to `vld4q` loads (and the matching
+// stores) de-interleave 2 to 4 channels: channel `c` of `k` gets the elements
+// `c, k + c, 2 * k + c, ...`.
+pub fn vld1q_s8(ptr: &[i8]) -> int8x16_t {
+    int8x16_t::from_fn(|i| ptr[i as usize])
+}
+
+pub fn vld1q_s16(ptr: &[i16]) -> int16x8_t {
+    int16x8_t::from_fn(|i| ptr[i as usize])
+}
+
+pub fn vld1q_s32(ptr: &[i32]) -> int32x4_t {
+    int32x4_t::from_fn(|i| ptr[i as usize])
+}
+
+pub fn vld1q_s64(ptr: &[i64]) -> int64x2_t {
+    int64x2_t::from_fn(|i| ptr[i as usize])
+}
+
+pub fn vld1q_u8(ptr: &[u8]) -> uint8x16_t {
+    uint8x16_t::from_fn(|i| ptr[i as usize])
+}
+
+pub fn vld1q_u16(ptr: &[u16]) -> uint16x8_t {
+    uint16x8_t::from_fn(|i| ptr[i as usize])
+}
+
+pub fn vld1q_u32(ptr: &[u32]) -> uint32x4_t {
+    uint32x4_t::from_fn(|i| ptr[i as usize])
+}
+
+pub fn vld1q_u64(ptr: &[u64]) -> uint64x2_t {
+    uint64x2_t::from_fn(|i| ptr[i as usize])
+}
+
+pub fn vld1q_f32(ptr: &[f32]) -> float32x4_t {
+    float32x4_t::from_fn(|i| ptr[i as usize])
+}
+
+pub fn vld2q_s8(ptr: &[i8]) -> int8x16x2_t {
+    (deinterleave(ptr, 2, 0), deinterleave(ptr, 2, 1))
+}
+
+pub fn vld2q_s16(ptr: &[i16]) -> int16x8x2_t {
+    (deinterleave(ptr, 2, 0), deinterleave(ptr, 2, 1))
+}
+
+pub fn vld2q_s32(ptr: &[i32]) -> int32x4x2_t {
+    (deinterleave(ptr, 2, 0), deinterleave(ptr, 2, 1))
+}
+
+pub fn vld2q_u8(ptr: &[u8]) -> uint8x16x2_t {
+    (deinterleave(ptr, 2, 0), deinterleave(ptr, 2, 1))
+}
+
+pub fn vld2q_u16(ptr: &[u16]) -> uint16x8x2_t {
+    (deinterleave(ptr, 2, 0), deinterleave(ptr, 2, 1))
+}
+
+pub fn vld2q_u32(ptr: &[u32]) -> uint32x4x2_t {
+    (deinterleave(ptr, 2, 0), deinterleave(ptr, 2, 1))
+}
+
+pub fn vld2q_f32(ptr: &[f32]) -> float32x4x2_t {
+    (deinterleave(ptr, 2, 0), deinterleave(ptr, 2, 1))
+}
+
+pub fn vld3q_s8(ptr: &[i8]) -> int8x16x3_t {
+    (
+        deinterleave(ptr, 3, 0),
+        deinterleave(ptr, 3, 1),
+        deinterleave(ptr, 3, 2),
+    )
+}
+
+pub fn vld3q_s16(ptr: &[i16]) -> int16x8x3_t {
+    (
+        deinterleave(ptr, 3, 0),
+        deinterleave(ptr, 3, 1),
+        deinterleave(ptr, 3, 2),
+    )
+}
+
+pub fn vld3q_s32(ptr: &[i32]) -> int32x4x3_t {
+    (
+        deinterleave(ptr, 3, 0),
+        deinterleave(ptr, 3, 1),
+        deinterleave(ptr, 3, 2),
+    )
+}
+
+pub fn vld3q_u8(ptr: &[u8]) -> uint8x16x3_t {
+    (
+        deinterleave(ptr, 3, 0),
+        deinterleave(ptr, 3, 1),
+        deinterleave(ptr, 3, 2),
+    )
+}
+
+pub fn vld3q_u16(ptr: &[u16]) -> uint16x8x3_t {
+    (
+        deinterleave(ptr, 3, 0),
+        deinterleave(ptr, 3, 1),
+        deinterleave(ptr, 3, 2),
+    )
+}
+
+pub fn vld3q_u32(ptr: &[u32]) -> uint32x4x3_t {
+    (
+        deinterleave(ptr, 3, 0),
+        deinterleave(ptr, 3, 1),
+        deinterleave(ptr, 3, 2),
+    )
+}
+
+pub fn vld3q_f32(ptr: &[f32]) -> float32x4x3_t {
+    (
+        deinterleave(ptr, 3, 0),
+        deinterleave(ptr, 3, 1),
+        deinterleave(ptr, 3, 2),
+    )
+}
+
+pub fn vld4q_s8(ptr: &[i8]) -> int8x16x4_t {
+    (
+        deinterleave(ptr, 4, 0),
+        deinterleave(ptr, 4, 1),
+        deinterleave(ptr, 4, 2),
+        deinterleave(ptr, 4, 3),
+    )
+}
+
+pub fn vld4q_s16(ptr: &[i16]) -> int16x8x4_t {
+    (
+        deinterleave(ptr, 4, 0),
+        deinterleave(ptr, 4, 1),
+        deinterleave(ptr, 4, 2),
+        deinterleave(ptr, 4, 3),
+    )
+}
+
+pub fn vld4q_s32(ptr: &[i32]) -> int32x4x4_t {
+    (
+        deinterleave(ptr, 4, 0),
+        deinterleave(ptr, 4, 1),
+        deinterleave(ptr, 4, 2),
+        deinterleave(ptr, 4, 3),
+    )
+}
+
+pub fn vld4q_u8(ptr: &[u8]) -> uint8x16x4_t {
+    (
+        deinterleave(ptr, 4, 0),
+        deinterleave(ptr, 4, 1),
+        deinterleave(ptr, 4, 2),
+        deinterleave(ptr, 4, 3),
+    )
+}
+
+pub fn vld4q_u16(ptr: &[u16]) -> uint16x8x4_t {
+    (
+        deinterleave(ptr, 4, 0),
+        deinterleave(ptr, 4, 1),
+        deinterleave(ptr, 4, 2),
+        deinterleave(ptr, 4, 3),
+    )
+}
+
+pub fn vld4q_u32(ptr: &[u32]) -> uint32x4x4_t {
+    (
+        deinterleave(ptr, 4, 0),
+        deinterleave(ptr, 4, 1),
+        deinterleave(ptr, 4, 2),
+        deinterleave(ptr, 4, 3),
+    )
+}
+
+pub fn vld4q_f32(ptr: &[f32]) -> float32x4x4_t {
+    (
+        deinterleave(ptr, 4, 0),
+        deinterleave(ptr, 4, 1),
+        deinterleave(ptr, 4, 2),
+        deinterleave(ptr, 4, 3),
+    )
+}
+
+pub fn vmax_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t {
+    simd_select(simd_gt(a, b), a, b)
+}
+
+pub fn vmaxq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+    simd_select(simd_gt(a, b), a, b)
+}
+
+pub fn vmax_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t {
+    simd_select(simd_gt(a, b), a, b)
+}
+
+pub fn vmaxq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+    simd_select(simd_gt(a, b), a, b)
+}
+
+pub fn vmax_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t {
+    simd_select(simd_gt(a, b), a, b)
+}
+
+pub fn vmaxq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+    simd_select(simd_gt(a, b), a, b)
+}
+
+pub fn vmax_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t {
+    simd_select(simd_gt(a, b), a, b)
+}
+
+pub fn vmaxq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    simd_select(simd_gt(a, b), a, b)
+}
+
+pub fn vmax_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t {
+    simd_select(simd_gt(a, b), a, b)
+}
+
+pub fn vmaxq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+    simd_select(simd_gt(a, b), a, b)
+}
+
+pub fn vmax_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t {
+    simd_select(simd_gt(a, b), a, b)
+}
+
+pub fn vmaxq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+    simd_select(simd_gt(a, b), a, b)
+}
+
+pub fn vmaxv_s8(a: int8x8_t) -> i8 {
+    a.iter().fold(i8::MIN, |acc, &x| acc.max(x))
+}
+
+pub fn vmaxvq_s8(a: int8x16_t) -> i8 {
+    a.iter().fold(i8::MIN, |acc, &x| acc.max(x))
+}
+
+pub fn vmaxv_s16(a: int16x4_t) -> i16 {
+    a.iter().fold(i16::MIN, |acc, &x| acc.max(x))
+}
+
+pub fn vmaxvq_s16(a: int16x8_t) -> i16 {
+    a.iter().fold(i16::MIN, |acc, &x| acc.max(x))
+}
+
+pub fn vmaxv_s32(a: int32x2_t) -> i32 {
+    a.iter().fold(i32::MIN, |acc, &x| acc.max(x))
+}
+
+pub fn vmaxvq_s32(a: int32x4_t) -> i32 {
+    a.iter().fold(i32::MIN, |acc, &x| acc.max(x))
+}
+
+pub fn vmaxv_u8(a: uint8x8_t) -> u8 {
+    a.iter().fold(u8::MIN, |acc, &x| acc.max(x))
+}
+
+pub fn vmaxvq_u8(a: uint8x16_t) -> u8 {
+    a.iter().fold(u8::MIN, |acc, &x| acc.max(x))
+}
+
+pub fn vmaxv_u16(a: uint16x4_t) -> u16 {
+    a.iter().fold(u16::MIN, |acc, &x| acc.max(x))
+}
+
+pub fn vmaxvq_u16(a: uint16x8_t) -> u16 {
+    a.iter().fold(u16::MIN, |acc, &x| acc.max(x))
+}
+
+pub fn vmaxv_u32(a: uint32x2_t) -> u32 {
+    a.iter().fold(u32::MIN, |acc, &x| acc.max(x))
+}
+
+pub fn vmaxvq_u32(a: uint32x4_t) -> u32 {
+    a.iter().fold(u32::MIN, |acc, &x| acc.max(x))
+}
+
+pub fn vmin_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t {
+    simd_select(simd_lt(a, b), a, b)
+}
+
+pub fn vminq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+    simd_select(simd_lt(a, b), a, b)
+}
+
+pub fn vmin_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t {
+    simd_select(simd_lt(a, b), a, b)
+}
+
+pub fn vminq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+    simd_select(simd_lt(a, b), a, b)
+}
+
+pub fn vmin_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t {
+    simd_select(simd_lt(a, b), a, b)
+}
+
+pub fn vminq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+    simd_select(simd_lt(a, b), a, b)
+}
+
+pub fn vmin_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t {
+    simd_select(simd_lt(a, b), a, b)
+}
+
+pub fn vminq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    simd_select(simd_lt(a, b), a, b)
+}
+
+pub fn vmin_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t {
+    simd_select(simd_lt(a, b), a, b)
+}
+
+pub fn vminq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+    simd_select(simd_lt(a, b), a, b)
+}
+
+pub fn vmin_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t {
+    simd_select(simd_lt(a, b), a, b)
+}
+
+pub fn vminq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+    simd_select(simd_lt(a, b), a, b)
+}
+
+pub fn vminv_s8(a: int8x8_t) -> i8 {
+    a.iter().fold(i8::MAX, |acc, &x| acc.min(x))
+}
+
+pub fn vminvq_s8(a: int8x16_t) -> i8 {
+    a.iter().fold(i8::MAX, |acc, &x| acc.min(x))
+}
+
+pub fn vminv_s16(a: int16x4_t) -> i16 {
+    a.iter().fold(i16::MAX, |acc, &x| acc.min(x))
+}
+
+pub fn vminvq_s16(a: int16x8_t) -> i16 {
+    a.iter().fold(i16::MAX, |acc, &x| acc.min(x))
+}
+
+pub fn vminv_s32(a: int32x2_t) -> i32 {
+    a.iter().fold(i32::MAX, |acc, &x| acc.min(x))
+}
+
+pub fn vminvq_s32(a: int32x4_t) -> i32 {
+    a.iter().fold(i32::MAX, |acc, &x| acc.min(x))
+}
+
+pub fn vminv_u8(a: uint8x8_t) -> u8 {
+    a.iter().fold(u8::MAX, |acc, &x| acc.min(x))
+}
+
+pub fn vminvq_u8(a: uint8x16_t) -> u8 {
+    a.iter().fold(u8::MAX, |acc, &x| acc.min(x))
+}
+
+pub fn vminv_u16(a: uint16x4_t) -> u16 {
+    a.iter().fold(u16::MAX, |acc, &x| acc.min(x))
+}
+
+pub fn vminvq_u16(a: uint16x8_t) -> u16 {
+    a.iter().fold(u16::MAX, |acc, &x| acc.min(x))
+}
+
+pub fn vminv_u32(a: uint32x2_t) -> u32 {
+    a.iter().fold(u32::MAX, |acc, &x| acc.min(x))
+}
+
+pub fn vminvq_u32(a: uint32x4_t) -> u32 {
+    a.iter().fold(u32::MAX, |acc, &x| acc.min(x))
+}
+
+pub fn vmla_s8(a: int8x8_t, b: int8x8_t, c: int8x8_t) -> int8x8_t {
+    simd_add(a, simd_mul(b, c))
+}
+
+pub fn vmla_s16(a: int16x4_t, b: int16x4_t, c: int16x4_t) -> int16x4_t {
+    simd_add(a, simd_mul(b, c))
+}
+
+pub fn vmla_s32(a: int32x2_t, b: int32x2_t, c: int32x2_t) -> int32x2_t {
+    simd_add(a, simd_mul(b, c))
+}
+
+pub fn vmla_u8(a: uint8x8_t, b: uint8x8_t, c: uint8x8_t) -> uint8x8_t {
+    simd_add(a, simd_mul(b, c))
+}
+
+pub fn vmla_u16(a: uint16x4_t, b: uint16x4_t, c: uint16x4_t) -> uint16x4_t {
+    simd_add(a, simd_mul(b, c))
+}
+
+pub fn vmla_u32(a: uint32x2_t, b: uint32x2_t, c: uint32x2_t) -> uint32x2_t {
+    simd_add(a, simd_mul(b, c))
+}
+
+pub fn vmlaq_s8(a: int8x16_t, b: int8x16_t, c: int8x16_t) -> int8x16_t {
+    simd_add(a, simd_mul(b, c))
+}
+
+pub fn vmlaq_s16(a: int16x8_t, b: int16x8_t, c: int16x8_t) -> int16x8_t {
+    simd_add(a, simd_mul(b, c))
+}
+
+pub fn vmlaq_s32(a: int32x4_t, b: int32x4_t, c: int32x4_t) -> int32x4_t {
+    simd_add(a, simd_mul(b, c))
+}
+
+pub fn vmlaq_u8(a: uint8x16_t, b: uint8x16_t, c: uint8x16_t) -> uint8x16_t {
+    simd_add(a, simd_mul(b, c))
+}
+
+pub fn vmlaq_u16(a: uint16x8_t, b: uint16x8_t, c: uint16x8_t) -> uint16x8_t {
+    simd_add(a, simd_mul(b, c))
+}
+
+pub fn vmlaq_u32(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t {
+    simd_add(a, simd_mul(b, c))
+}
+
+pub fn vmls_s8(a: int8x8_t, b: int8x8_t, c: int8x8_t) -> int8x8_t {
+    simd_sub(a, simd_mul(b, c))
+}
+
+pub fn vmls_s16(a: int16x4_t, b: int16x4_t, c: int16x4_t) -> int16x4_t {
+    simd_sub(a, simd_mul(b, c))
+}
+
+pub fn vmls_s32(a: int32x2_t, b: int32x2_t, c: int32x2_t) -> int32x2_t {
+    simd_sub(a, simd_mul(b, c))
+}
+
+pub fn vmls_u8(a: uint8x8_t, b: uint8x8_t, c: uint8x8_t) -> uint8x8_t {
+    simd_sub(a, simd_mul(b, c))
+}
+
+pub fn vmls_u16(a: uint16x4_t, b: uint16x4_t, c: uint16x4_t) -> uint16x4_t {
+    simd_sub(a, simd_mul(b, c))
+}
+
+pub fn vmls_u32(a: uint32x2_t, b: uint32x2_t, c: uint32x2_t) -> uint32x2_t {
+    simd_sub(a, simd_mul(b, c))
+}
+
+pub fn vmlsq_s8(a: int8x16_t, b: int8x16_t, c: int8x16_t) -> int8x16_t {
+    simd_sub(a, simd_mul(b, c))
+}
+
+pub fn vmlsq_s16(a: int16x8_t, b: int16x8_t, c: int16x8_t) -> int16x8_t {
+    simd_sub(a, simd_mul(b, c))
+}
+
+pub fn vmlsq_s32(a: int32x4_t, b: int32x4_t, c: int32x4_t) -> int32x4_t {
+    simd_sub(a, simd_mul(b, c))
+}
+
+pub fn vmlsq_u8(a: uint8x16_t, b: uint8x16_t, c: uint8x16_t) -> uint8x16_t {
+    simd_sub(a, simd_mul(b, c))
+}
+
+pub fn vmlsq_u16(a: uint16x8_t, b: uint16x8_t, c: uint16x8_t) -> uint16x8_t {
+    simd_sub(a, simd_mul(b, c))
+}
+
+pub fn vmlsq_u32(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t) -> uint32x4_t {
+    simd_sub(a, simd_mul(b, c))
+}
+
+pub fn vmovn_s16(a: int16x8_t) -> int8x8_t {
+    simd_cast(a)
+}
+
+pub fn vmovn_s32(a: int32x4_t) -> int16x4_t {
+    simd_cast(a)
+}
+
+pub fn vmovn_s64(a: int64x2_t) -> int32x2_t {
+    simd_cast(a)
+}
+
+pub fn vmovn_u16(a: uint16x8_t) -> uint8x8_t {
+    simd_cast(a)
+}
+
+pub fn vmovn_u32(a: uint32x4_t) -> uint16x4_t {
+    simd_cast(a)
+}
+
+pub fn vmovn_u64(a: uint64x2_t) -> uint32x2_t {
+    simd_cast(a)
+}
+
+pub fn vmull_s8(a: int8x8_t, b: int8x8_t) -> int16x8_t {
+    let a: int16x8_t = simd_cast(a);
+    let b: int16x8_t = simd_cast(b);
+    simd_mul(a, b)
+}
+
+pub fn vmull_s16(a: int16x4_t, b: int16x4_t) -> int32x4_t {
+    let a: int32x4_t = simd_cast(a);
+    let b: int32x4_t = simd_cast(b);
+    simd_mul(a, b)
+}
+
+pub fn vmull_s32(a: int32x2_t, b: int32x2_t) -> int64x2_t {
+    let a: int64x2_t = simd_cast(a);
+    let b: int64x2_t = simd_cast(b);
+    simd_mul(a, b)
+}
+
+pub fn vmull_u8(a: uint8x8_t, b: uint8x8_t) -> uint16x8_t {
+    let a: uint16x8_t = simd_cast(a);
+    let b: uint16x8_t = simd_cast(b);
+    simd_mul(a, b)
+}
+
+pub fn vmull_u16(a: uint16x4_t, b: uint16x4_t) -> uint32x4_t {
+    let a: uint32x4_t = simd_cast(a);
+    let b: uint32x4_t = simd_cast(b);
+    simd_mul(a, b)
+}
+
+pub fn vmull_u32(a: uint32x2_t, b: uint32x2_t) -> uint64x2_t {
+    let a: uint64x2_t = simd_cast(a);
+    let b: uint64x2_t = simd_cast(b);
+    simd_mul(a, b)
+}
+
+pub fn vmvn_s8(a: int8x8_t) -> int8x8_t {
+    let b = int8x8_t::splat(-1);
+    simd_xor(a, b)
+}
+
+pub fn vmvnq_s8(a: int8x16_t) -> int8x16_t {
+    let b = int8x16_t::splat(-1);
+    simd_xor(a, b)
+}
+
+pub fn vmvn_s16(a: int16x4_t) -> int16x4_t {
+    let b = int16x4_t::splat(-1);
+    simd_xor(a, b)
+}
+
+pub fn vmvnq_s16(a: int16x8_t) -> int16x8_t {
+    let b = int16x8_t::splat(-1);
+    simd_xor(a, b)
+}
+
+pub fn vmvn_s32(a: int32x2_t) -> int32x2_t {
+    let b = int32x2_t::splat(-1);
+    simd_xor(a, b)
+}
+
+pub fn vmvnq_s32(a: int32x4_t) -> int32x4_t {
+    let b = int32x4_t::splat(-1);
+    simd_xor(a, b)
+}
+
+pub fn vmvn_u8(a: uint8x8_t) -> uint8x8_t {
+    let b = uint8x8_t::splat(u8::MAX);
+    simd_xor(a, b)
+}
+
+pub fn vmvnq_u8(a: uint8x16_t) -> uint8x16_t {
+    let b = uint8x16_t::splat(u8::MAX);
+    simd_xor(a, b)
+}
+
+pub fn vmvn_u16(a: uint16x4_t) -> uint16x4_t {
+    let b = uint16x4_t::splat(u16::MAX);
+    simd_xor(a, b)
+}
+
+pub fn vmvnq_u16(a: uint16x8_t) -> uint16x8_t {
+    let b = uint16x8_t::splat(u16::MAX);
+    simd_xor(a, b)
+}
+
+pub fn vmvn_u32(a: uint32x2_t) -> uint32x2_t {
+    let b = uint32x2_t::splat(u32::MAX);
+    simd_xor(a, b)
+}
+
+pub fn vmvnq_u32(a: uint32x4_t) -> uint32x4_t {
+    let b = uint32x4_t::splat(u32::MAX);
+    simd_xor(a, b)
+}
+
+pub fn vneg_s8(a: int8x8_t) -> int8x8_t {
+    simd_neg(a)
+}
+
+pub fn vnegq_s8(a: int8x16_t) -> int8x16_t {
+    simd_neg(a)
+}
+
+pub fn vneg_s16(a: int16x4_t) -> int16x4_t {
+    simd_neg(a)
+}
+
+pub fn vnegq_s16(a: int16x8_t) -> int16x8_t {
+    simd_neg(a)
+}
+
+pub fn vneg_s32(a: int32x2_t) -> int32x2_t {
+    simd_neg(a)
+}
+
+pub fn vnegq_s32(a: int32x4_t) -> int32x4_t {
+    simd_neg(a)
+}
+
+pub fn vneg_s64(a: int64x1_t) -> int64x1_t {
+    simd_neg(a)
+}
+
+pub fn vnegq_s64(a: int64x2_t) -> int64x2_t {
+    simd_neg(a)
+}
+
+pub fn vneg_f32(a: float32x2_t) -> float32x2_t {
+    simd_fneg(a)
+}
+
+pub fn vnegq_f32(a: float32x4_t) -> float32x4_t {
+    simd_fneg(a)
+}
+
+pub fn vorn_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t {
+    let c = int8x8_t::splat(-1);
+    simd_or(simd_xor(b, c), a)
+}
+
+pub fn vornq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+    let c = int8x16_t::splat(-1);
+    simd_or(simd_xor(b, c), a)
+}
+
+pub fn vorn_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t {
+    let c = int16x4_t::splat(-1);
+    simd_or(simd_xor(b, c), a)
+}
+
+pub fn vornq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+    let c = int16x8_t::splat(-1);
+    simd_or(simd_xor(b, c), a)
+}
+
+pub fn vorn_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t {
+    let c = int32x2_t::splat(-1);
+    simd_or(simd_xor(b, c), a)
+}
+
+pub fn vornq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+    let c = int32x4_t::splat(-1);
+    simd_or(simd_xor(b, c), a)
+}
+
+pub fn vorn_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t {
+    let c = int64x1_t::splat(-1);
+    simd_or(simd_xor(b, c), a)
+}
+
+pub fn vornq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t {
+    let c = int64x2_t::splat(-1);
+    simd_or(simd_xor(b, c), a)
+}
+
+pub fn vorn_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t {
+    let c = int8x8_t::splat(-1);
+    simd_or(simd_xor(b, simd_cast(c)), a)
+}
+
+pub fn vornq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    let c = int8x16_t::splat(-1);
+    simd_or(simd_xor(b, simd_cast(c)), a)
+}
+
+pub fn vorn_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t {
+    let c = int16x4_t::splat(-1);
+    simd_or(simd_xor(b, simd_cast(c)), a)
+}
+
+pub fn vornq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+    let c = int16x8_t::splat(-1);
+    simd_or(simd_xor(b, simd_cast(c)), a)
+}
+
+pub fn vorn_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t {
+    let c = int32x2_t::splat(-1);
+    simd_or(simd_xor(b, simd_cast(c)), a)
+}
+
+pub fn vornq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+    let c = int32x4_t::splat(-1);
+    simd_or(simd_xor(b, simd_cast(c)), a)
+}
+
+pub fn vorn_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t {
+    let c = int64x1_t::splat(-1);
+    simd_or(simd_xor(b, simd_cast(c)), a)
+}
+
+pub fn vornq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+    let c = int64x2_t::splat(-1);
+    simd_or(simd_xor(b, simd_cast(c)), a)
+}
+
+pub fn vorr_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t {
+    simd_or(a, b)
+}
+
+pub fn vorrq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+    simd_or(a, b)
+}
+
+pub fn vorr_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t {
+    simd_or(a, b)
+}
+
+pub fn vorrq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+    simd_or(a, b)
+}
+
+pub fn vorr_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t {
+    simd_or(a, b)
+}
+
+pub fn vorrq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+    simd_or(a, b)
+}
+
+pub fn vorr_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t {
+    simd_or(a, b)
+}
+
+pub fn vorrq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t {
+    simd_or(a, b)
+}
+
+pub fn vorr_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t {
+    simd_or(a, b)
+}
+
+pub fn vorrq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    simd_or(a, b)
+}
+
+pub fn vorr_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t {
+    simd_or(a, b)
+}
+
+pub fn vorrq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+    simd_or(a, b)
+}
+
+pub fn vorr_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t {
+    simd_or(a, b)
+}
+
+pub fn vorrq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+    simd_or(a, b)
+}
+
+pub fn vorr_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t {
+    simd_or(a, b)
+}
+
+pub fn vorrq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+    simd_or(a, b)
+}
+
+pub fn vpadd_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t {
+    addp(a, b)
+}
+
+pub fn vpaddq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+    addp(a, b)
+}
+
+pub fn vpadd_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t {
+    addp(a, b)
+}
+
+pub fn vpaddq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+    addp(a, b)
+}
+
+pub fn vpadd_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t {
+    addp(a, b)
+}
+
+pub fn vpaddq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+    addp(a, b)
+}
+
+pub fn vpadd_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t {
+    addp(a, b)
+}
+
+pub fn vpaddq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    addp(a, b)
+}
+
+pub fn vpadd_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t {
+    addp(a, b)
+}
+
+pub fn vpaddq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+    addp(a, b)
+}
+
+pub fn vpadd_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t {
+    addp(a, b)
+}
+
+pub fn vpaddq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+    addp(a, b)
+}
+
+pub fn vpmax_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t {
+    maxp(a, b)
+}
+
+pub fn vpmaxq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+    maxp(a, b)
+}
+
+pub fn vpmax_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t {
+    maxp(a, b)
+}
+
+pub fn vpmaxq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+    maxp(a, b)
+}
+
+pub fn vpmax_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t {
+    maxp(a, b)
+}
+
+pub fn vpmaxq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+    maxp(a, b)
+}
+
+pub fn vpmax_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t {
+    maxp(a, b)
+}
+
+pub fn vpmaxq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    maxp(a, b)
+}
+
+pub fn vpmax_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t {
+    maxp(a, b)
+}
+
+pub fn vpmaxq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+    maxp(a, b)
+}
+
+pub fn vpmax_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t {
+    maxp(a, b)
+}
+
+pub fn vpmaxq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+    maxp(a, b)
+}
+
+pub fn vpmin_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t {
+    minp(a, b)
+}
+
+pub fn vpminq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+    minp(a, b)
+}
+
+pub fn vpmin_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t {
+    minp(a, b)
+}
+
+pub fn vpminq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+    minp(a, b)
+}
+
+pub fn vpmin_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t {
+    minp(a, b)
+}
+
+pub fn vpminq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+    minp(a, b)
+}
+
+pub fn vpmin_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t {
+    minp(a, b)
+}
+
+pub fn vpminq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    minp(a, b)
+}
+
+pub fn vpmin_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t {
+    minp(a, b)
+}
+
+pub fn vpminq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+    minp(a, b)
+}
+
+pub fn vpmin_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t {
+    minp(a, b)
+}
+
+pub fn vpminq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+    minp(a, b)
+}
+
+pub fn vqadd_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t {
+    simd_saturating_add(a, b)
+}
+
+pub fn vqaddq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+    simd_saturating_add(a, b)
+}
+
+pub fn vqadd_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t {
+    simd_saturating_add(a, b)
+}
+
+pub fn vqaddq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+    simd_saturating_add(a, b)
+}
+
+pub fn vqadd_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t {
+    simd_saturating_add(a, b)
+}
+
+pub fn vqaddq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+    simd_saturating_add(a, b)
+}
+
+pub fn vqadd_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t {
+    simd_saturating_add(a, b)
+}
+
+pub fn vqaddq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t {
+    simd_saturating_add(a, b)
+}
+
+pub fn vqadd_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t {
+    simd_saturating_add(a, b)
+}
+
+pub fn vqaddq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    simd_saturating_add(a, b)
+}
+
+pub fn vqadd_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t {
+    simd_saturating_add(a, b)
+}
+
+pub fn vqaddq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+    simd_saturating_add(a, b)
+}
+
+pub fn vqadd_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t {
+    simd_saturating_add(a, b)
+}
+
+pub fn vqaddq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+    simd_saturating_add(a, b)
+}
+
+pub fn vqadd_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t {
+    simd_saturating_add(a, b)
+}
+
+pub fn vqaddq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+    simd_saturating_add(a, b)
+}
+
+pub fn vqmovn_s16(a: int16x8_t) -> int8x8_t {
+    qmovn(a)
+}
+
+pub fn vqmovn_s32(a: int32x4_t) -> int16x4_t {
+    qmovn(a)
+}
+
+pub fn vqmovn_s64(a: int64x2_t) -> int32x2_t {
+    qmovn(a)
+}
+
+pub fn vqmovn_u16(a: uint16x8_t) -> uint8x8_t {
+    qmovn(a)
+}
+
+pub fn vqmovn_u32(a: uint32x4_t) -> uint16x4_t {
+    qmovn(a)
+}
+
+pub fn vqmovn_u64(a: uint64x2_t) -> uint32x2_t {
+    qmovn(a)
+}
+
+pub fn vqmovun_s16(a: int16x8_t) -> uint8x8_t {
+    qmovn(a)
+}
+
+pub fn vqmovun_s32(a: int32x4_t) -> uint16x4_t {
+    qmovn(a)
+}
+
+pub fn vqmovun_s64(a: int64x2_t) -> uint32x2_t {
+    qmovn(a)
+}
+
+pub fn vqshl_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t {
+    qshl(a, b)
+}
+
+pub fn vqshlq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+    qshl(a, b)
+}
+
+pub fn vqshl_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t {
+    qshl(a, b)
+}
+
+pub fn vqshlq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+    qshl(a, b)
+}
+
+pub fn vqshl_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t {
+    qshl(a, b)
+}
+
+pub fn vqshlq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+    qshl(a, b)
+}
+
+pub fn vqshl_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t {
+    qshl(a, b)
+}
+
+pub fn vqshlq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t {
+    qshl(a, b)
+}
+
+pub fn vqshl_u8(a: uint8x8_t, b: int8x8_t) -> uint8x8_t {
+    qshl(a, b)
+}
+
+pub fn vqshlq_u8(a: uint8x16_t, b: int8x16_t) -> uint8x16_t {
+    qshl(a, b)
+}
+
+pub fn vqshl_u16(a: uint16x4_t, b: int16x4_t) -> uint16x4_t {
+    qshl(a, b)
+}
+
+pub fn vqshlq_u16(a: uint16x8_t, b: int16x8_t) -> uint16x8_t {
+    qshl(a, b)
+}
+
+pub fn vqshl_u32(a: uint32x2_t, b: int32x2_t) -> uint32x2_t {
+    qshl(a, b)
+}
+
+pub fn vqshlq_u32(a: uint32x4_t, b: int32x4_t) -> uint32x4_t {
+    qshl(a, b)
+}
+
+pub fn vqshl_u64(a: uint64x1_t, b: int64x1_t) -> uint64x1_t {
+    qshl(a, b)
+}
+
+pub fn vqshlq_u64(a: uint64x2_t, b: int64x2_t) -> uint64x2_t {
+    qshl(a, b)
+}
+
+pub fn vqshl_n_s8<const N: i32>(a: int8x8_t) -> int8x8_t {
+    static_assert_uimm_bits!(N, 3);
+    vqshl_s8(a, vdup_n_s8(N as _))
+}
+
+pub fn vqshlq_n_s8<const N: i32>(a: int8x16_t) -> int8x16_t {
+    static_assert_uimm_bits!(N, 3);
+    vqshlq_s8(a, vdupq_n_s8(N as _))
+}
+
+pub fn vqshl_n_s16<const N: i32>(a: int16x4_t) -> int16x4_t {
+    static_assert_uimm_bits!(N, 4);
+    vqshl_s16(a, vdup_n_s16(N as _))
+}
+
+pub fn vqshlq_n_s16<const N: i32>(a: int16x8_t) -> int16x8_t {
+    static_assert_uimm_bits!(N, 4);
+    vqshlq_s16(a, vdupq_n_s16(N as _))
+}
+
+pub fn vqshl_n_s32<const N: i32>(a: int32x2_t) -> int32x2_t {
+    static_assert_uimm_bits!(N, 5);
+    vqshl_s32(a, vdup_n_s32(N as _))
+}
+
+pub fn vqshlq_n_s32<const N: i32>(a: int32x4_t) -> int32x4_t {
+    static_assert_uimm_bits!(N, 5);
+    vqshlq_s32(a, vdupq_n_s32(N as _))
+}
+
+pub fn vqshl_n_s64<const N: i32>(a: int64x1_t) -> int64x1_t {
+    static_assert_uimm_bits!(N, 6);
+    vqshl_s64(a, vdup_n_s64(N as _))
+}
+
+pub fn vqshlq_n_s64<const N: i32>(a: int64x2_t) -> int64x2_t {
+    static_assert_uimm_bits!(N, 6);
+    vqshlq_s64(a, vdupq_n_s64(N as _))
+}
+
+pub fn vqshl_n_u8<const N: i32>(a: uint8x8_t) -> uint8x8_t {
+    static_assert_uimm_bits!(N, 3);
+    vqshl_u8(a, vdup_n_s8(N as _))
+}
+
+pub fn vqshlq_n_u8<const N: i32>(a: uint8x16_t) -> uint8x16_t {
+    static_assert_uimm_bits!(N, 3);
+    vqshlq_u8(a, vdupq_n_s8(N as _))
+}
+
+pub fn vqshl_n_u16<const N: i32>(a: uint16x4_t) -> uint16x4_t {
+    static_assert_uimm_bits!(N, 4);
+    vqshl_u16(a, vdup_n_s16(N as _))
+}
+
+pub fn vqshlq_n_u16<const N: i32>(a: uint16x8_t) -> uint16x8_t {
+    static_assert_uimm_bits!(N, 4);
+    vqshlq_u16(a, vdupq_n_s16(N as _))
+}
+
+pub fn vqshl_n_u32<const N: i32>(a: uint32x2_t) -> uint32x2_t {
+    static_assert_uimm_bits!(N, 5);
+    vqshl_u32(a, vdup_n_s32(N as _))
+}
+
+pub fn vqshlq_n_u32<const N: i32>(a: uint32x4_t) -> uint32x4_t {
+    static_assert_uimm_bits!(N, 5);
+    vqshlq_u32(a, vdupq_n_s32(N as _))
+}
+
+pub fn vqshl_n_u64<const N: i32>(a: uint64x1_t) -> uint64x1_t {
+    static_assert_uimm_bits!(N, 6);
+    vqshl_u64(a, vdup_n_s64(N as _))
+}
+
+pub fn vqshlq_n_u64<const N: i32>(a: uint64x2_t) -> uint64x2_t {
+    static_assert_uimm_bits!(N, 6);
+    vqshlq_u64(a, vdupq_n_s64(N as _))
+}
+
+pub fn vqsub_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t {
+    simd_saturating_sub(a, b)
+}
+
+pub fn vqsubq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+    simd_saturating_sub(a, b)
+}
+
+pub fn vqsub_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t {
+    simd_saturating_sub(a, b)
+}
+
+pub fn vqsubq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+    simd_saturating_sub(a, b)
+}
+
+pub fn vqsub_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t {
+    simd_saturating_sub(a, b)
+}
+
+pub fn vqsubq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+    simd_saturating_sub(a, b)
+}
+
+pub fn vqsub_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t {
+    simd_saturating_sub(a, b)
+}
+
+pub fn vqsubq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t {
+    simd_saturating_sub(a, b)
+}
+
+pub fn vqsub_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t {
+    simd_saturating_sub(a, b)
+}
+
+pub fn vqsubq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    simd_saturating_sub(a, b)
+}
+
+pub fn vqsub_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t {
+    simd_saturating_sub(a, b)
+}
+
+pub fn vqsubq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+    simd_saturating_sub(a, b)
+}
+
+pub fn vqsub_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t {
+    simd_saturating_sub(a, b)
+}
+
+pub fn vqsubq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+    simd_saturating_sub(a, b)
+}
+
+pub fn vqsub_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t {
+    simd_saturating_sub(a, b)
+}
+
+pub fn vqsubq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+    simd_saturating_sub(a, b)
+}
+
+pub fn vqtbl1_s8(a: int8x16_t, b: uint8x8_t) -> int8x8_t {
+    tbl(a, b)
+}
+
+pub fn vqtbl1q_s8(a: int8x16_t, b: uint8x16_t) -> int8x16_t {
+    tbl(a, b)
+}
+
+pub fn vqtbl1_u8(a: uint8x16_t, b: uint8x8_t) -> uint8x8_t {
+    tbl(a, b)
+}
+
+pub fn vqtbl1q_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    tbl(a, b)
+}
+
+pub fn vqtbx1_s8(a: int8x8_t, b: int8x16_t, c: uint8x8_t) -> int8x8_t {
+    tbx(a, b, c)
+}
+
+pub fn vqtbx1q_s8(a: int8x16_t, b: int8x16_t, c: uint8x16_t) -> int8x16_t {
+    tbx(a, b, c)
+}
+
+pub fn vqtbx1_u8(a: uint8x8_t, b: uint8x16_t, c: uint8x8_t) -> uint8x8_t {
+    tbx(a, b, c)
+}
+
+pub fn vqtbx1q_u8(a: uint8x16_t, b: uint8x16_t, c: uint8x16_t) -> uint8x16_t {
+    tbx(a, b, c)
+}
+
+pub fn vrbit_s8(a: int8x8_t) -> int8x8_t {
+    simd_bitreverse(a)
+}
+
+pub fn vrbitq_s8(a: int8x16_t) -> int8x16_t {
+    simd_bitreverse(a)
+}
+
+pub fn vrbit_u8(a: uint8x8_t) -> uint8x8_t {
+    simd_bitreverse(a)
+}
+
+pub fn vrbitq_u8(a: uint8x16_t) -> uint8x16_t {
+    simd_bitreverse(a)
+}
+
+pub fn vrev16_s8(a: int8x8_t) -> int8x8_t {
+    simd_shuffle(a, a, [1, 0, 3, 2, 5, 4, 7, 6])
+}
+
+pub fn vrev16q_s8(a: int8x16_t) -> int8x16_t {
+    simd_shuffle(a, a, [1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 11, 10, 13, 12, 15, 14])
+}
+
+pub fn vrev16_u8(a: uint8x8_t) -> uint8x8_t {
+    simd_shuffle(a, a, [1, 0, 3, 2, 5, 4, 7, 6])
+}
+
+pub fn vrev16q_u8(a: uint8x16_t) -> uint8x16_t {
+    simd_shuffle(a, a, [1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 11, 10, 13, 12, 15, 14])
+}
+
+pub fn vrev32_s8(a: int8x8_t) -> int8x8_t {
+    simd_shuffle(a, a, [3, 2, 1, 0, 7, 6, 5, 4])
+}
+
+pub fn vrev32q_s8(a: int8x16_t) -> int8x16_t {
+    simd_shuffle(a, a, [3, 2, 1, 0, 7, 6, 5, 4, 11, 10, 9, 8, 15, 14, 13, 12])
+}
+
+pub fn vrev32_u8(a: uint8x8_t) -> uint8x8_t {
+    simd_shuffle(a, a, [3, 2, 1, 0, 7, 6, 5, 4])
+}
+
+pub fn vrev32q_u8(a: uint8x16_t) -> uint8x16_t {
+    simd_shuffle(a, a, [3, 2, 1, 0, 7, 6, 5, 4, 11, 10, 9, 8, 15, 14, 13, 12])
+}
+
+pub fn vrev32_s16(a: int16x4_t) -> int16x4_t {
+    simd_shuffle(a, a, [1, 0, 3, 2])
+}
+
+pub fn vrev32q_s16(a: int16x8_t) -> int16x8_t {
+    simd_shuffle(a, a, [1, 0, 3, 2, 5, 4, 7, 6])
+}
+
+pub fn vrev32_u16(a: uint16x4_t) -> uint16x4_t {
+    simd_shuffle(a, a, [1, 0, 3, 2])
+}
+
+pub fn vrev32q_u16(a: uint16x8_t) -> uint16x8_t {
+    simd_shuffle(a, a, [1, 0, 3, 2, 5, 4, 7, 6])
+}
+
+pub fn vrev64_s8(a: int8x8_t) -> int8x8_t {
+    simd_shuffle(a, a, [7, 6, 5, 4, 3, 2, 1, 0])
+}
+
+pub fn vrev64q_s8(a: int8x16_t) -> int8x16_t {
+    simd_shuffle(a, a, [7, 6, 5, 4, 3, 2, 1, 0, 15, 14, 13, 12, 11, 10, 9, 8])
+}
+
+pub fn vrev64_u8(a: uint8x8_t) -> uint8x8_t {
+    simd_shuffle(a, a, [7, 6, 5, 4, 3, 2, 1, 0])
+}
+
+pub fn vrev64q_u8(a: uint8x16_t) -> uint8x16_t {
+    simd_shuffle(a, a, [7, 6, 5, 4, 3, 2, 1, 0, 15, 14, 13, 12, 11, 10, 9, 8])
+}
+
+pub fn vrev64_s16(a: int16x4_t) -> int16x4_t {
+    simd_shuffle(a, a, [3, 2, 1, 0])
+}
+
+pub fn vrev64q_s16(a: int16x8_t) -> int16x8_t {
+    simd_shuffle(a, a, [3, 2, 1, 0, 7, 6, 5, 4])
+}
+
+pub fn vrev64_u16(a: uint16x4_t) -> uint16x4_t {
+    simd_shuffle(a, a, [3, 2, 1, 0])
+}
+
+pub fn vrev64q_u16(a: uint16x8_t) -> uint16x8_t {
+    simd_shuffle(a, a, [3, 2, 1, 0, 7, 6, 5, 4])
+}
+
+pub fn vrev64_s32(a: int32x2_t) -> int32x2_t {
+    simd_shuffle(a, a, [1, 0])
+}
+
+pub fn vrev64q_s32(a: int32x4_t) -> int32x4_t {
+    simd_shuffle(a, a, [1, 0, 3, 2])
+}
+
+pub fn vrev64_u32(a: uint32x2_t) -> uint32x2_t {
+    simd_shuffle(a, a, [1, 0])
+}
+
+pub fn vrev64q_u32(a: uint32x4_t) -> uint32x4_t {
+    simd_shuffle(a, a, [1, 0, 3, 2])
+}
+
+pub fn vset_lane_s8<const LANE: i32>(a: i8, b: int8x8_t) -> int8x8_t {
+    static_assert_uimm_bits!(LANE, 3);
+    simd_insert(b, LANE as u32, a)
+}
+
+pub fn vsetq_lane_s8<const LANE: i32>(a: i8, b: int8x16_t) -> int8x16_t {
+    static_assert_uimm_bits!(LANE, 4);
+    simd_insert(b, LANE as u32, a)
+}
+
+pub fn vset_lane_s16<const LANE: i32>(a: i16, b: int16x4_t) -> int16x4_t {
+    static_assert_uimm_bits!(LANE, 2);
+    simd_insert(b, LANE as u32, a)
+}
+
+pub fn vsetq_lane_s16<const LANE: i32>(a: i16, b: int16x8_t) -> int16x8_t {
+    static_assert_uimm_bits!(LANE, 3);
+    simd_insert(b, LANE as u32, a)
+}
+
+pub fn vset_lane_s32<const LANE: i32>(a: i32, b: int32x2_t) -> int32x2_t {
+    static_assert_uimm_bits!(LANE, 1);
+    simd_insert(b, LANE as u32, a)
+}
+
+pub fn vsetq_lane_s32<const LANE: i32>(a: i32, b: int32x4_t) -> int32x4_t {
+    static_assert_uimm_bits!(LANE, 2);
+    simd_insert(b, LANE as u32, a)
+}
+
+pub fn vset_lane_s64<const LANE: i32>(a: i64, b: int64x1_t) -> int64x1_t {
+    static_assert_uimm_bits!(LANE, 0);
+    simd_insert(b, LANE as u32, a)
+}
+
+pub fn vsetq_lane_s64<const LANE: i32>(a: i64, b: int64x2_t) -> int64x2_t {
+    static_assert_uimm_bits!(LANE, 1);
+    simd_insert(b, LANE as u32, a)
+}
+
+pub fn vset_lane_u8<const LANE: i32>(a: u8, b: uint8x8_t) -> uint8x8_t {
+    static_assert_uimm_bits!(LANE, 3);
+    simd_insert(b, LANE as u32, a)
+}
+
+pub fn vsetq_lane_u8<const LANE: i32>(a: u8, b: uint8x16_t) -> uint8x16_t {
+    static_assert_uimm_bits!(LANE, 4);
+    simd_insert(b, LANE as u32, a)
+}
+
+pub fn vset_lane_u16<const LANE: i32>(a: u16, b: uint16x4_t) -> uint16x4_t {
+    static_assert_uimm_bits!(LANE, 2);
+    simd_insert(b, LANE as u32, a)
+}
+
+pub fn vsetq_lane_u16<const LANE: i32>(a: u16, b: uint16x8_t) -> uint16x8_t {
+    static_assert_uimm_bits!(LANE, 3);
+    simd_insert(b, LANE as u32, a)
+}
+
+pub fn vset_lane_u32<const LANE: i32>(a: u32, b: uint32x2_t) -> uint32x2_t {
+    static_assert_uimm_bits!(LANE, 1);
+    simd_insert(b, LANE as u32, a)
+}
+
+pub fn vsetq_lane_u32<const LANE: i32>(a: u32, b: uint32x4_t) -> uint32x4_t {
+    static_assert_uimm_bits!(LANE, 2);
+    simd_insert(b, LANE as u32, a)
+}
+
+pub fn vset_lane_u64<const LANE: i32>(a: u64, b: uint64x1_t) -> uint64x1_t {
+    static_assert_uimm_bits!(LANE, 0);
+    simd_insert(b, LANE as u32, a)
+}
+
+pub fn vsetq_lane_u64<const LANE: i32>(a: u64, b: uint64x2_t) -> uint64x2_t {
+    static_assert_uimm_bits!(LANE, 1);
+    simd_insert(b, LANE as u32, a)
+}
+
+pub fn vshl_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t {
+    shl(a, b)
+}
+
+pub fn vshlq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+    shl(a, b)
+}
+
+pub fn vshl_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t {
+    shl(a, b)
+}
+
+pub fn vshlq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+    shl(a, b)
+}
+
+pub fn vshl_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t {
+    shl(a, b)
+}
+
+pub fn vshlq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+    shl(a, b)
+}
+
+pub fn vshl_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t {
+    shl(a, b)
+}
+
+pub fn vshlq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t {
+    shl(a, b)
+}
+
+pub fn vshl_u8(a: uint8x8_t, b: int8x8_t) -> uint8x8_t {
+    shl(a, b)
+}
+
+pub fn vshlq_u8(a: uint8x16_t, b: int8x16_t) -> uint8x16_t {
+    shl(a, b)
+}
+
+pub fn vshl_u16(a: uint16x4_t, b: int16x4_t) -> uint16x4_t {
+    shl(a, b)
+}
+
+pub fn vshlq_u16(a: uint16x8_t, b: int16x8_t) -> uint16x8_t {
+    shl(a, b)
+}
+
+pub fn vshl_u32(a: uint32x2_t, b: int32x2_t) -> uint32x2_t {
+    shl(a, b)
+}
+
+pub fn vshlq_u32(a: uint32x4_t, b: int32x4_t) -> uint32x4_t {
+    shl(a, b)
+}
+
+pub fn vshl_u64(a: uint64x1_t, b: int64x1_t) -> uint64x1_t {
+    shl(a, b)
+}
+
+pub fn vshlq_u64(a: uint64x2_t, b: int64x2_t) -> uint64x2_t {
+    shl(a, b)
+}
+
+pub fn vshl_n_s8<const N: i32>(a: int8x8_t) -> int8x8_t {
+    static_assert_uimm_bits!(N, 3);
+    simd_shl(a, vdup_n_s8(N as _))
+}
+
+pub fn vshlq_n_s8<const N: i32>(a: int8x16_t) -> int8x16_t {
+    static_assert_uimm_bits!(N, 3);
+    simd_shl(a, vdupq_n_s8(N as _))
+}
+
+pub fn vshl_n_s16<const N: i32>(a: int16x4_t) -> int16x4_t {
+    static_assert_uimm_bits!(N, 4);
+    simd_shl(a, vdup_n_s16(N as _))
+}
+
+pub fn vshlq_n_s16<const N: i32>(a: int16x8_t) -> int16x8_t {
+    static_assert_uimm_bits!(N, 4);
+    simd_shl(a, vdupq_n_s16(N as _))
+}
+
+pub fn vshl_n_s32<const N: i32>(a: int32x2_t) -> int32x2_t {
+    static_assert_uimm_bits!(N, 5);
+    simd_shl(a, vdup_n_s32(N as _))
+}
+
+pub fn vshlq_n_s32<const N: i32>(a: int32x4_t) -> int32x4_t {
+    static_assert_uimm_bits!(N, 5);
+    simd_shl(a, vdupq_n_s32(N as _))
+}
+
+pub fn vshl_n_s64<const N: i32>(a: int64x1_t) -> int64x1_t {
+    static_assert_uimm_bits!(N, 6);
+    simd_shl(a, vdup_n_s64(N as _))
+}
+
+pub fn vshlq_n_s64<const N: i32>(a: int64x2_t) -> int64x2_t {
+    static_assert_uimm_bits!(N, 6);
+    simd_shl(a, vdupq_n_s64(N as _))
+}
+
+pub fn vshl_n_u8<const N: i32>(a: uint8x8_t) -> uint8x8_t {
+    static_assert_uimm_bits!(N, 3);
+    simd_shl(a, vdup_n_u8(N as _))
+}
+
+pub fn vshlq_n_u8<const N: i32>(a: uint8x16_t) -> uint8x16_t {
+    static_assert_uimm_bits!(N, 3);
+    simd_shl(a, vdupq_n_u8(N as _))
+}
+
+pub fn vshl_n_u16<const N: i32>(a: uint16x4_t) -> uint16x4_t {
+    static_assert_uimm_bits!(N, 4);
+    simd_shl(a, vdup_n_u16(N as _))
+}
+
+pub fn vshlq_n_u16<const N: i32>(a: uint16x8_t) -> uint16x8_t {
+    static_assert_uimm_bits!(N, 4);
+    simd_shl(a, vdupq_n_u16(N as _))
+}
+
+pub fn vshl_n_u32<const N: i32>(a: uint32x2_t) -> uint32x2_t {
+    static_assert_uimm_bits!(N, 5);
+    simd_shl(a, vdup_n_u32(N as _))
+}
+
+pub fn vshlq_n_u32<const N: i32>(a: uint32x4_t) -> uint32x4_t {
+    static_assert_uimm_bits!(N, 5);
+    simd_shl(a, vdupq_n_u32(N as _))
+}
+
+pub fn vshl_n_u64<const N: i32>(a: uint64x1_t) -> uint64x1_t {
+    static_assert_uimm_bits!(N, 6);
+    simd_shl(a, vdup_n_u64(N as _))
+}
+
+pub fn vshlq_n_u64<const N: i32>(a: uint64x2_t) -> uint64x2_t {
+    static_assert_uimm_bits!(N, 6);
+    simd_shl(a, vdupq_n_u64(N as _))
+}
+
+pub fn vshr_n_s8<const N: i32>(a: int8x8_t) -> int8x8_t {
+    static_assert!(N >= 1 && N <= 8);
+    let n: i32 = if N == 8 { 7 } else { N };
+    simd_shr(a, vdup_n_s8(n as _))
+}
+
+pub fn vshrq_n_s8<const N: i32>(a: int8x16_t) -> int8x16_t {
+    static_assert!(N >= 1 && N <= 8);
+    let n: i32 = if N == 8 { 7 } else { N };
+    simd_shr(a, vdupq_n_s8(n as _))
+}
+
+pub fn vshr_n_s16<const N: i32>(a: int16x4_t) -> int16x4_t {
+    static_assert!(N >= 1 && N <= 16);
+    let n: i32 = if N == 16 { 15 } else { N };
+    simd_shr(a, vdup_n_s16(n as _))
+}
+
+pub fn vshrq_n_s16<const N: i32>(a: int16x8_t) -> int16x8_t {
+    static_assert!(N >= 1 && N <= 16);
+    let n: i32 = if N == 16 { 15 } else { N };
+    simd_shr(a, vdupq_n_s16(n as _))
+}
+
+pub fn vshr_n_s32<const N: i32>(a: int32x2_t) -> int32x2_t {
+    static_assert!(N >= 1 && N <= 32);
+    let n: i32 = if N == 32 { 31 } else { N };
+    simd_shr(a, vdup_n_s32(n as _))
+}
+
+pub fn vshrq_n_s32<const N: i32>(a: int32x4_t) -> int32x4_t {
+    static_assert!(N >= 1 && N <= 32);
+    let n: i32 = if N == 32 { 31 } else { N };
+    simd_shr(a, vdupq_n_s32(n as _))
+}
+
+pub fn vshr_n_s64<const N: i32>(a: int64x1_t) -> int64x1_t {
+    static_assert!(N >= 1 && N <= 64);
+    let n: i32 = if N == 64 { 63 } else { N };
+    simd_shr(a, vdup_n_s64(n as _))
+}
+
+pub fn vshrq_n_s64<const N: i32>(a: int64x2_t) -> int64x2_t {
+    static_assert!(N >= 1 && N <= 64);
+    let n: i32 = if N == 64 { 63 } else { N };
+    simd_shr(a, vdupq_n_s64(n as _))
+}
+
+pub fn vshr_n_u8<const N: i32>(a: uint8x8_t) -> uint8x8_t {
+    static_assert!(N >= 1 && N <= 8);
+    let n: i32 = if N == 8 {
+        return vdup_n_u8(0);
+    } else {
+        N
+    };
+    simd_shr(a, vdup_n_u8(n as _))
+}
+
+pub fn vshrq_n_u8<const N: i32>(a: uint8x16_t) -> uint8x16_t {
+    static_assert!(N >= 1 && N <= 8);
+    let n: i32 = if N == 8 {
+        return vdupq_n_u8(0);
+    } else {
+        N
+    };
+    simd_shr(a, vdupq_n_u8(n as _))
+}
+
+pub fn vshr_n_u16<const N: i32>(a: uint16x4_t) -> uint16x4_t {
+    static_assert!(N >= 1 && N <= 16);
+    let n: i32 = if N == 16 {
+        return vdup_n_u16(0);
+    } else {
+        N
+    };
+    simd_shr(a, vdup_n_u16(n as _))
+}
+
+pub fn vshrq_n_u16<const N: i32>(a: uint16x8_t) -> uint16x8_t {
+    static_assert!(N >= 1 && N <= 16);
+    let n: i32 = if N == 16 {
+        return vdupq_n_u16(0);
+    } else {
+        N
+    };
+    simd_shr(a, vdupq_n_u16(n as _))
+}
+
+pub fn vshr_n_u32<const N: i32>(a: uint32x2_t) -> uint32x2_t {
+    static_assert!(N >= 1 && N <= 32);
+    let n: i32 = if N == 32 {
+        return vdup_n_u32(0);
+    } else {
+        N
+    };
+    simd_shr(a, vdup_n_u32(n as _))
+}
+
+pub fn vshrq_n_u32<const N: i32>(a: uint32x4_t) -> uint32x4_t {
+    static_assert!(N >= 1 && N <= 32);
+    let n: i32 = if N == 32 {
+        return vdupq_n_u32(0);
+    } else {
+        N
+    };
+    simd_shr(a, vdupq_n_u32(n as _))
+}
+
+pub fn vshr_n_u64<const N: i32>(a: uint64x1_t) -> uint64x1_t {
+    static_assert!(N >= 1 && N <= 64);
+    let n: i32 = if N == 64 {
+        return vdup_n_u64(0);
+    } else {
+        N
+    };
+    simd_shr(a, vdup_n_u64(n as _))
+}
+
+pub fn vshrq_n_u64<const N: i32>(a: uint64x2_t) -> uint64x2_t {
+    static_assert!(N >= 1 && N <= 64);
+    let n: i32 = if N == 64 {
+        return vdupq_n_u64(0);
+    } else {
+        N
+    };
+    simd_shr(a, vdupq_n_u64(n as _))
+}
+
+pub fn vshrn_n_s16<const N: i32>(a: int16x8_t) -> int8x8_t {
+    static_assert!(N >= 1 && N <= 8);
+    simd_cast(simd_shr(a, vdupq_n_s16(N as _)))
+}
+
+pub fn vshrn_n_s32<const N: i32>(a: int32x4_t) -> int16x4_t {
+    static_assert!(N >= 1 && N <= 16);
+    simd_cast(simd_shr(a, vdupq_n_s32(N as _)))
+}
+
+pub fn vshrn_n_s64<const N: i32>(a: int64x2_t) -> int32x2_t {
+    static_assert!(N >= 1 && N <= 32);
+    simd_cast(simd_shr(a, vdupq_n_s64(N as _)))
+}
+
+pub fn vshrn_n_u16<const N: i32>(a: uint16x8_t) -> uint8x8_t {
+    static_assert!(N >= 1 && N <= 8);
+    simd_cast(simd_shr(a, vdupq_n_u16(N as _)))
+}
+
+pub fn vshrn_n_u32<const N: i32>(a: uint32x4_t) -> uint16x4_t {
+    static_assert!(N >= 1 && N <= 16);
+    simd_cast(simd_shr(a, vdupq_n_u32(N as _)))
+}
+
+pub fn vshrn_n_u64<const N: i32>(a: uint64x2_t) -> uint32x2_t {
+    static_assert!(N >= 1 && N <= 32);
+    simd_cast(simd_shr(a, vdupq_n_u64(N as _)))
+}
+
+pub fn vst1q_s8(ptr: &mut [i8], a: int8x16_t) {
+    for (i, x) in a.into_iter().enumerate() {
+        ptr[i] = x;
+    }
+}
+
+pub fn vst1q_s16(ptr: &mut [i16], a: int16x8_t) {
+    for (i, x) in a.into_iter().enumerate() {
+        ptr[i] = x;
+    }
+}
+
+pub fn vst1q_s32(ptr: &mut [i32], a: int32x4_t) {
+    for (i, x) in a.into_iter().enumerate() {
+        ptr[i] = x;
+    }
+}
+
+pub fn vst1q_s64(ptr: &mut [i64], a: int64x2_t) {
+    for (i, x) in a.into_iter().enumerate() {
+        ptr[i] = x;
+    }
+}
+
+pub fn vst1q_u8(ptr: &mut [u8], a: uint8x16_t) {
+    for (i, x) in a.into_iter().enumerate() {
+        ptr[i] = x;
+    }
+}
+
+pub fn vst1q_u16(ptr: &mut [u16], a: uint16x8_t) {
+    for (i, x) in a.into_iter().enumerate() {
+        ptr[i] = x;
+    }
+}
+
+pub fn vst1q_u32(ptr: &mut [u32], a: uint32x4_t) {
+    for (i, x) in a.into_iter().enumerate() {
+        ptr[i] = x;
+    }
+}
+
+pub fn vst1q_u64(ptr: &mut [u64], a: uint64x2_t) {
+    for (i, x) in a.into_iter().enumerate() {
+        ptr[i] = x;
+    }
+}
+
+pub fn vst1q_f32(ptr: &mut [f32], a: float32x4_t) {
+    for (i, x) in a.into_iter().enumerate() {
+        ptr[i] = x;
+    }
+}
+
+pub fn vst2q_s8(ptr: &mut [i8], a: int8x16x2_t) {
+    interleave(ptr, &[a.0, a.1])
+}
+
+pub fn vst2q_s16(ptr: &mut [i16], a: int16x8x2_t) {
+    interleave(ptr, &[a.0, a.1])
+}
+
+pub fn vst2q_s32(ptr: &mut [i32], a: int32x4x2_t) {
+    interleave(ptr, &[a.0, a.1])
+}
+
+pub fn vst2q_u8(ptr: &mut [u8], a: uint8x16x2_t) {
+    interleave(ptr, &[a.0, a.1])
+}
+
+pub fn vst2q_u16(ptr: &mut [u16], a: uint16x8x2_t) {
+    interleave(ptr, &[a.0, a.1])
+}
+
+pub fn vst2q_u32(ptr: &mut [u32], a: uint32x4x2_t) {
+    interleave(ptr, &[a.0, a.1])
+}
+
+pub fn vst2q_f32(ptr: &mut [f32], a: float32x4x2_t) {
+    interleave(ptr, &[a.0, a.1])
+}
+
+pub fn vst3q_s8(ptr: &mut [i8], a: int8x16x3_t) {
+    interleave(ptr, &[a.0, a.1, a.2])
+}
+
+pub fn vst3q_s16(ptr: &mut [i16], a: int16x8x3_t) {
+    interleave(ptr, &[a.0, a.1, a.2])
+}
+
+pub fn vst3q_s32(ptr: &mut [i32], a: int32x4x3_t) {
+    interleave(ptr, &[a.0, a.1, a.2])
+}
+
+pub fn vst3q_u8(ptr: &mut [u8], a: uint8x16x3_t) {
+    interleave(ptr, &[a.0, a.1, a.2])
+}
+
+pub fn vst3q_u16(ptr: &mut [u16], a: uint16x8x3_t) {
+    interleave(ptr, &[a.0, a.1, a.2])
+}
+
+pub fn vst3q_u32(ptr: &mut [u32], a: uint32x4x3_t) {
+    interleave(ptr, &[a.0, a.1, a.2])
+}
+
+pub fn vst3q_f32(ptr: &mut [f32], a: float32x4x3_t) {
+    interleave(ptr, &[a.0, a.1, a.2])
+}
+
+pub fn vst4q_s8(ptr: &mut [i8], a: int8x16x4_t) {
+    interleave(ptr, &[a.0, a.1, a.2, a.3])
+}
+
+pub fn vst4q_s16(ptr: &mut [i16], a: int16x8x4_t) {
+    interleave(ptr, &[a.0, a.1, a.2, a.3])
+}
+
+pub fn vst4q_s32(ptr: &mut [i32], a: int32x4x4_t) {
+    interleave(ptr, &[a.0, a.1, a.2, a.3])
+}
+
+pub fn vst4q_u8(ptr: &mut [u8], a: uint8x16x4_t) {
+    interleave(ptr, &[a.0, a.1, a.2, a.3])
+}
+
+pub fn vst4q_u16(ptr: &mut [u16], a: uint16x8x4_t) {
+    interleave(ptr, &[a.0, a.1, a.2, a.3])
+}
+
+pub fn vst4q_u32(ptr: &mut [u32], a: uint32x4x4_t) {
+    interleave(ptr, &[a.0, a.1, a.2, a.3])
+}
+
+pub fn vst4q_f32(ptr: &mut [f32], a: float32x4x4_t) {
+    interleave(ptr, &[a.0, a.1, a.2, a.3])
+}
+
+pub fn vsub_s16(a: int16x4_t, b: int16x4_t) -> int16x4_t {
+    simd_sub(a, b)
+}
+
+pub fn vsub_s32(a: int32x2_t, b: int32x2_t) -> int32x2_t {
+    simd_sub(a, b)
+}
+
+pub fn vsub_s64(a: int64x1_t, b: int64x1_t) -> int64x1_t {
+    simd_sub(a, b)
+}
+
+pub fn vsub_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t {
+    simd_sub(a, b)
+}
+
+pub fn vsub_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4_t {
+    simd_sub(a, b)
+}
+
+pub fn vsub_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2_t {
+    simd_sub(a, b)
+}
+
+pub fn vsub_u64(a: uint64x1_t, b: uint64x1_t) -> uint64x1_t {
+    simd_sub(a, b)
+}
+
+pub fn vsub_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t {
+    simd_sub(a, b)
+}
+
+pub fn vsubq_s16(a: int16x8_t, b: int16x8_t) -> int16x8_t {
+    simd_sub(a, b)
+}
+
+pub fn vsubq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+    simd_sub(a, b)
+}
+
+pub fn vsubq_s64(a: int64x2_t, b: int64x2_t) -> int64x2_t {
+    simd_sub(a, b)
+}
+
+pub fn vsubq_s8(a: int8x16_t, b: int8x16_t) -> int8x16_t {
+    simd_sub(a, b)
+}
+
+pub fn vsubq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8_t {
+    simd_sub(a, b)
+}
+
+pub fn vsubq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+    simd_sub(a, b)
+}
+
+pub fn vsubq_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+    simd_sub(a, b)
+}
+
+pub fn vsubq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    simd_sub(a, b)
+}
+
+pub fn vtbl1_s8(a: int8x8_t, b: int8x8_t) -> int8x8_t {
+    tbl(a, b)
+}
+
+pub fn vtbl1_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8_t {
+    tbl(a, b)
+}
+
+pub fn vtbx1_s8(a: int8x8_t, b: int8x8_t, c: int8x8_t) -> int8x8_t {
+    tbx(a, b, c)
+}
+
+pub fn vtbx1_u8(a: uint8x8_t, b: uint8x8_t, c: uint8x8_t) -> uint8x8_t {
+    tbx(a, b, c)
+}
+
+pub fn vtrn_s8(a: int8x8_t, b: int8x8_t) -> int8x8x2_t {
+    let a0: int8x8_t = simd_shuffle(a, b, [0, 8, 2, 10, 4, 12, 6, 14]);
+    let b0: int8x8_t = simd_shuffle(a, b, [1, 9, 3, 11, 5, 13, 7, 15]);
+    (a0, b0)
+}
+
+pub fn vtrnq_s8(a: int8x16_t, b: int8x16_t) -> int8x16x2_t {
+    let a0: int8x16_t = simd_shuffle(
+        a,
+        b,
+        [0, 16, 2, 18, 4, 20, 6, 22, 8, 24, 10, 26, 12, 28, 14, 30],
+    );
+    let b0: int8x16_t = simd_shuffle(
+        a,
+        b,
+        [1, 17, 3, 19, 5, 21, 7, 23, 9, 25, 11, 27, 13, 29, 15, 31],
+    );
+    (a0, b0)
+}
+
+pub fn vtrn_s16(a: int16x4_t, b: int16x4_t) -> int16x4x2_t {
+    let a0: int16x4_t = simd_shuffle(a, b, [0, 4, 2, 6]);
+    let b0: int16x4_t = simd_shuffle(a, b, [1, 5, 3, 7]);
+    (a0, b0)
+}
+
+pub fn vtrnq_s16(a: int16x8_t, b: int16x8_t) -> int16x8x2_t {
+    let a0: int16x8_t = simd_shuffle(a, b, [0, 8, 2, 10, 4, 12, 6, 14]);
+    let b0: int16x8_t = simd_shuffle(a, b, [1, 9, 3, 11, 5, 13, 7, 15]);
+    (a0, b0)
+}
+
+pub fn vtrn_s32(a: int32x2_t, b: int32x2_t) -> int32x2x2_t {
+    let a0: int32x2_t = simd_shuffle(a, b, [0, 2]);
+    let b0: int32x2_t = simd_shuffle(a, b, [1, 3]);
+    (a0, b0)
+}
+
+pub fn vtrnq_s32(a: int32x4_t, b: int32x4_t) -> int32x4x2_t {
+    let a0: int32x4_t = simd_shuffle(a, b, [0, 4, 2, 6]);
+    let b0: int32x4_t = simd_shuffle(a, b, [1, 5, 3, 7]);
+    (a0, b0)
+}
+
+pub fn vtrn_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8x2_t {
+    let a0: uint8x8_t = simd_shuffle(a, b, [0, 8, 2, 10, 4, 12, 6, 14]);
+    let b0: uint8x8_t = simd_shuffle(a, b, [1, 9, 3, 11, 5, 13, 7, 15]);
+    (a0, b0)
+}
+
+pub fn vtrnq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16x2_t {
+    let a0: uint8x16_t = simd_shuffle(
+        a,
+        b,
+        [0, 16, 2, 18, 4, 20, 6, 22, 8, 24, 10, 26, 12, 28, 14, 30],
+    );
+    let b0: uint8x16_t = simd_shuffle(
+        a,
+        b,
+        [1, 17, 3, 19, 5, 21, 7, 23, 9, 25, 11, 27, 13, 29, 15, 31],
+    );
+    (a0, b0)
+}
+
+pub fn vtrn_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4x2_t {
+    let a0: uint16x4_t = simd_shuffle(a, b, [0, 4, 2, 6]);
+    let b0: uint16x4_t = simd_shuffle(a, b, [1, 5, 3, 7]);
+    (a0, b0)
+}
+
+pub fn vtrnq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8x2_t {
+    let a0: uint16x8_t = simd_shuffle(a, b, [0, 8, 2, 10, 4, 12, 6, 14]);
+    let b0: uint16x8_t = simd_shuffle(a, b, [1, 9, 3, 11, 5, 13, 7, 15]);
+    (a0, b0)
+}
+
+pub fn vtrn_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2x2_t {
+    let a0: uint32x2_t = simd_shuffle(a, b, [0, 2]);
+    let b0: uint32x2_t = simd_shuffle(a, b, [1, 3]);
+    (a0, b0)
+}
+
+pub fn vtrnq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4x2_t {
+    let a0: uint32x4_t = simd_shuffle(a, b, [0, 4, 2, 6]);
+    let b0: uint32x4_t = simd_shuffle(a, b, [1, 5, 3, 7]);
+    (a0, b0)
+}
+
+pub fn vuzp_s8(a: int8x8_t, b: int8x8_t) -> int8x8x2_t {
+    let a0: int8x8_t = simd_shuffle(a, b, [0, 2, 4, 6, 8, 10, 12, 14]);
+    let b0: int8x8_t = simd_shuffle(a, b, [1, 3, 5, 7, 9, 11, 13, 15]);
+    (a0, b0)
+}
+
+pub fn vuzpq_s8(a: int8x16_t, b: int8x16_t) -> int8x16x2_t {
+    let a0: int8x16_t = simd_shuffle(
+        a,
+        b,
+        [0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30],
+    );
+    let b0: int8x16_t = simd_shuffle(
+        a,
+        b,
+        [1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31],
+    );
+    (a0, b0)
+}
+
+pub fn vuzp_s16(a: int16x4_t, b: int16x4_t) -> int16x4x2_t {
+    let a0: int16x4_t = simd_shuffle(a, b, [0, 2, 4, 6]);
+    let b0: int16x4_t = simd_shuffle(a, b, [1, 3, 5, 7]);
+    (a0, b0)
+}
+
+pub fn vuzpq_s16(a: int16x8_t, b: int16x8_t) -> int16x8x2_t {
+    let a0: int16x8_t = simd_shuffle(a, b, [0, 2, 4, 6, 8, 10, 12, 14]);
+    let b0: int16x8_t = simd_shuffle(a, b, [1, 3, 5, 7, 9, 11, 13, 15]);
+    (a0, b0)
+}
+
+pub fn vuzp_s32(a: int32x2_t, b: int32x2_t) -> int32x2x2_t {
+    let a0: int32x2_t = simd_shuffle(a, b, [0, 2]);
+    let b0: int32x2_t = simd_shuffle(a, b, [1, 3]);
+    (a0, b0)
+}
+
+pub fn vuzpq_s32(a: int32x4_t, b: int32x4_t) -> int32x4x2_t {
+    let a0: int32x4_t = simd_shuffle(a, b, [0, 2, 4, 6]);
+    let b0: int32x4_t = simd_shuffle(a, b, [1, 3, 5, 7]);
+    (a0, b0)
+}
+
+pub fn vuzp_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8x2_t {
+    let a0: uint8x8_t = simd_shuffle(a, b, [0, 2, 4, 6, 8, 10, 12, 14]);
+    let b0: uint8x8_t = simd_shuffle(a, b, [1, 3, 5, 7, 9, 11, 13, 15]);
+    (a0, b0)
+}
+
+pub fn vuzpq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16x2_t {
+    let a0: uint8x16_t = simd_shuffle(
+        a,
+        b,
+        [0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30],
+    );
+    let b0: uint8x16_t = simd_shuffle(
+        a,
+        b,
+        [1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31],
+    );
+    (a0, b0)
+}
+
+pub fn vuzp_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4x2_t {
+    let a0: uint16x4_t = simd_shuffle(a, b, [0, 2, 4, 6]);
+    let b0: uint16x4_t = simd_shuffle(a, b, [1, 3, 5, 7]);
+    (a0, b0)
+}
+
+pub fn vuzpq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8x2_t {
+    let a0: uint16x8_t = simd_shuffle(a, b, [0, 2, 4, 6, 8, 10, 12, 14]);
+    let b0: uint16x8_t = simd_shuffle(a, b, [1, 3, 5, 7, 9, 11, 13, 15]);
+    (a0, b0)
+}
+
+pub fn vuzp_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2x2_t {
+    let a0: uint32x2_t = simd_shuffle(a, b, [0, 2]);
+    let b0: uint32x2_t = simd_shuffle(a, b, [1, 3]);
+    (a0, b0)
+}
+
+pub fn vuzpq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4x2_t {
+    let a0: uint32x4_t = simd_shuffle(a, b, [0, 2, 4, 6]);
+    let b0: uint32x4_t = simd_shuffle(a, b, [1, 3, 5, 7]);
+    (a0, b0)
+}
+
+pub fn vzip_s8(a: int8x8_t, b: int8x8_t) -> int8x8x2_t {
+    let a0: int8x8_t = simd_shuffle(a, b, [0, 8, 1, 9, 2, 10, 3, 11]);
+    let b0: int8x8_t = simd_shuffle(a, b, [4, 12, 5, 13, 6, 14, 7, 15]);
+    (a0, b0)
+}
+
+pub fn vzipq_s8(a: int8x16_t, b: int8x16_t) -> int8x16x2_t {
+    let a0: int8x16_t = simd_shuffle(
+        a,
+        b,
+        [0, 16, 1, 17, 2, 18, 3, 19, 4, 20, 5, 21, 6, 22, 7, 23],
+    );
+    let b0: int8x16_t = simd_shuffle(
+        a,
+        b,
+        [8, 24, 9, 25, 10, 26, 11, 27, 12, 28, 13, 29, 14, 30, 15, 31],
+    );
+    (a0, b0)
+}
+
+pub fn vzip_s16(a: int16x4_t, b: int16x4_t) -> int16x4x2_t {
+    let a0: int16x4_t = simd_shuffle(a, b, [0, 4, 1, 5]);
+    let b0: int16x4_t = simd_shuffle(a, b, [2, 6, 3, 7]);
+    (a0, b0)
+}
+
+pub fn vzipq_s16(a: int16x8_t, b: int16x8_t) -> int16x8x2_t {
+    let a0: int16x8_t = simd_shuffle(a, b, [0, 8, 1, 9, 2, 10, 3, 11]);
+    let b0: int16x8_t = simd_shuffle(a, b, [4, 12, 5, 13, 6, 14, 7, 15]);
+    (a0, b0)
+}
+
+pub fn vzip_s32(a: int32x2_t, b: int32x2_t) -> int32x2x2_t {
+    let a0: int32x2_t = simd_shuffle(a, b, [0, 2]);
+    let b0: int32x2_t = simd_shuffle(a, b, [1, 3]);
+    (a0, b0)
+}
+
+pub fn vzipq_s32(a: int32x4_t, b: int32x4_t) -> int32x4x2_t {
+    let a0: int32x4_t = simd_shuffle(a, b, [0, 4, 1, 5]);
+    let b0: int32x4_t = simd_shuffle(a, b, [2, 6, 3, 7]);
+    (a0, b0)
+}
+
+pub fn vzip_u8(a: uint8x8_t, b: uint8x8_t) -> uint8x8x2_t {
+    let a0: uint8x8_t = simd_shuffle(a, b, [0, 8, 1, 9, 2, 10, 3, 11]);
+    let b0: uint8x8_t = simd_shuffle(a, b, [4, 12, 5, 13, 6, 14, 7, 15]);
+    (a0, b0)
+}
+
+pub fn vzipq_u8(a: uint8x16_t, b: uint8x16_t) -> uint8x16x2_t {
+    let a0: uint8x16_t = simd_shuffle(
+        a,
+        b,
+        [0, 16, 1, 17, 2, 18, 3, 19, 4, 20, 5, 21, 6, 22, 7, 23],
+    );
+    let b0: uint8x16_t = simd_shuffle(
+        a,
+        b,
+        [8, 24, 9, 25, 10, 26, 11, 27, 12, 28, 13, 29, 14, 30, 15, 31],
+    );
+    (a0, b0)
+}
+
+pub fn vzip_u16(a: uint16x4_t, b: uint16x4_t) -> uint16x4x2_t {
+    let a0: uint16x4_t = simd_shuffle(a, b, [0, 4, 1, 5]);
+    let b0: uint16x4_t = simd_shuffle(a, b, [2, 6, 3, 7]);
+    (a0, b0)
+}
+
+pub fn vzipq_u16(a: uint16x8_t, b: uint16x8_t) -> uint16x8x2_t {
+    let a0: uint16x8_t = simd_shuffle(a, b, [0, 8, 1, 9, 2, 10, 3, 11]);
+    let b0: uint16x8_t = simd_shuffle(a, b, [4, 12, 5, 13, 6, 14, 7, 15]);
+    (a0, b0)
+}
+
+pub fn vzip_u32(a: uint32x2_t, b: uint32x2_t) -> uint32x2x2_t {
+    let a0: uint32x2_t = simd_shuffle(a, b, [0, 2]);
+    let b0: uint32x2_t = simd_shuffle(a, b, [1, 3]);
+    (a0, b0)
+}
+
+pub fn vzipq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4x2_t {
+    let a0: uint32x4_t = simd_shuffle(a, b, [0, 4, 1, 5]);
+    let b0: uint32x4_t = simd_shuffle(a, b, [2, 6, 3, 7]);
+    (a0, b0)
+}
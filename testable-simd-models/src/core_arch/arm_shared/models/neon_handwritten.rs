@@ -0,0 +1,134 @@
+use crate::abstractions::{
+    bit::{MachineInteger, MachineNumeric},
+    funarr::FunArray,
+};
+
+/// Combines adjacent pairs of lanes with `f`: the lower half of the result
+/// comes from the pairs `(0, 1), (2, 3), ...` of `a`, and the upper half from
+/// those of `b`.
+fn pairwise<const N: u32, T: Copy>(
+    a: FunArray<N, T>,
+    b: FunArray<N, T>,
+    f: impl Fn(T, T) -> T,
+) -> FunArray<N, T> {
+    FunArray::from_fn(|i| {
+        let (v, i) = if i < N / 2 { (a, i) } else { (b, i - N / 2) };
+        f(v[2 * i], v[2 * i + 1])
+    })
+}
+
+pub fn addp<const N: u32, T: MachineInteger + Copy>(
+    a: FunArray<N, T>,
+    b: FunArray<N, T>,
+) -> FunArray<N, T> {
+    pairwise(a, b, |x, y| x.wrapping_add(y))
+}
+
+pub fn minp<const N: u32, T: Ord + Copy>(a: FunArray<N, T>, b: FunArray<N, T>) -> FunArray<N, T> {
+    pairwise(a, b, |x, y| x.min(y))
+}
+
+pub fn maxp<const N: u32, T: Ord + Copy>(a: FunArray<N, T>, b: FunArray<N, T>) -> FunArray<N, T> {
+    pairwise(a, b, |x, y| x.max(y))
+}
+
+/// Shifts each lane of `a` by the signed amount held in the lowest byte of
+/// the matching lane of `b`: left for non-negative amounts, and right (in sign
+/// bits if `T` is signed) for negative ones. `f` gets the lane and the amount
+/// of a left shift, and takes care of the bits shifted out.
+fn shift_by_lane<const N: u32, T: MachineNumeric + Copy, S: MachineNumeric + Copy>(
+    a: FunArray<N, T>,
+    b: FunArray<N, S>,
+    f: impl Fn(i128, u32) -> T,
+) -> FunArray<N, T> {
+    FunArray::from_fn(|i| {
+        let x = a[i].to_u128() as i128;
+        match b[i].to_u128() as i8 {
+            n @ 0.. => f(x, n as u32),
+            n => T::from_u128((x >> n.unsigned_abs().min(127)) as u128),
+        }
+    })
+}
+
+pub fn shl<const N: u32, T: MachineNumeric + Copy, S: MachineNumeric + Copy>(
+    a: FunArray<N, T>,
+    b: FunArray<N, S>,
+) -> FunArray<N, T> {
+    shift_by_lane(a, b, |x, n| {
+        if n < T::BITS {
+            T::from_u128((x << n) as u128)
+        } else {
+            T::ZEROS
+        }
+    })
+}
+
+pub fn qshl<const N: u32, T: MachineNumeric + Copy, S: MachineNumeric + Copy>(
+    a: FunArray<N, T>,
+    b: FunArray<N, S>,
+) -> FunArray<N, T> {
+    shift_by_lane(a, b, |x, n| {
+        let (min, max) = (T::MIN.to_u128() as i128, T::MAX.to_u128() as i128);
+        match x {
+            0 => T::ZEROS,
+            _ if n >= T::BITS => {
+                if x < 0 {
+                    T::MIN
+                } else {
+                    T::MAX
+                }
+            }
+            _ => T::from_u128((x << n).clamp(min, max) as u128),
+        }
+    })
+}
+
+/// Narrows each lane of `a` to `U`, saturating the values that do not fit,
+/// like the `packs*`/`packus*` intrinsics of x86 do.
+pub fn qmovn<const N: u32, T: MachineNumeric + Copy, U: MachineNumeric + Copy>(
+    a: FunArray<N, T>,
+) -> FunArray<N, U> {
+    FunArray::from_fn(|i| {
+        let (min, max) = (U::MIN.to_u128() as i128, U::MAX.to_u128() as i128);
+        U::from_u128((a[i].to_u128() as i128).clamp(min, max) as u128)
+    })
+}
+
+/// Looks up each lane of `t` selected by `idx`, falling back to the matching
+/// lane of `a` for the indices past the end of the table.
+pub fn tbx<const N: u32, const M: u32, T: Copy, I: MachineNumeric + Copy>(
+    a: FunArray<N, T>,
+    t: FunArray<M, T>,
+    idx: FunArray<N, I>,
+) -> FunArray<N, T> {
+    FunArray::from_fn(|i| match idx[i].to_u128() {
+        j if j < M as u128 => t[j as u32],
+        _ => a[i],
+    })
+}
+
+/// Like `tbx`, but the indices past the end of the table give 0.
+pub fn tbl<const N: u32, const M: u32, T: MachineNumeric + Copy, I: MachineNumeric + Copy>(
+    t: FunArray<M, T>,
+    idx: FunArray<N, I>,
+) -> FunArray<N, T> {
+    tbx(FunArray::from_fn(|_| T::ZEROS), t, idx)
+}
+
+/// Reads channel `c` of the `k` channels interleaved in `ptr`: lane `i` of the
+/// result is element `k * i + c`.
+pub fn deinterleave<const N: u32, T: Copy>(ptr: &[T], k: u32, c: u32) -> FunArray<N, T> {
+    FunArray::from_fn(|i| ptr[(k * i + c) as usize])
+}
+
+/// Writes the vectors of `channels` interleaved to `ptr`, the inverse of
+/// [`deinterleave`]: element `k * i + c` is lane `i` of `channels[c]`, with `k`
+/// the number of channels.
+pub fn interleave<const N: u32, T: Copy>(ptr: &mut [T], channels: &[FunArray<N, T>]) {
+    let k = channels.len() as u32;
+    for (c, v) in channels.iter().enumerate() {
+        for i in 0..N {
+            ptr[(k * i + c as u32) as usize] = v[i];
+        }
+    }
+}
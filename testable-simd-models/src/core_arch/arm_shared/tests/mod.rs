@@ -22,16 +22,65 @@
 //! The const values are necessary if the function has constant arguments, but should be discarded if not.
 //! The function name and the function arguments are necessary in all cases.
 //!
-//! Note: This only works if the function returns a bit-vector or funarray. If it returns an integer, the
-//! test has to be written manually. It is recommended that the manually defined test follows
+//! Intrinsics returning a plain scalar declare its type after the arguments, in which case
+//! the outputs are compared directly instead of going through a bit-vector or funarray, e.g.
+//!
+//! `mk!(vgetq_lane_u32{<0>,<1>,<2>,<3>}(a: uint32x4_t) -> u32);`
+//!
+//! Intrinsics reading their inputs from memory take references instead, written as `&<type>` in the
+//! argument list. A random value of the referenced type is generated, and a reference to that same
+//! value is passed to both the model and the upstream intrinsic, e.g.
+//!
+//! `mk!(<function name>(a: &<type>));`
+//!
+//! Either all or none of the arguments have to be references.
+//!
+//! Note: This only works if the function returns a bit-vector, a funarray or a scalar. Anything
+//! else has to be tested manually. It is recommended that the manually defined test follows
 //! the pattern of tests defined via the `mk!` invocation. It is also recommended that, in the
 //! case that the intrinsic takes constant arguments, each and every possible constant value
 //! (upto a maximum of 255) that can be passed to the function be used for testing. The number
 //! of constant values passed depends on if the Rust intrinsics statically asserts that the
 //! length of the constant argument be less than or equal to a certain number of bits.
+//! Rather than listing all of those values by hand, use `mk_const_sweep!`, which takes that number
+//! of bits instead of the const values, e.g. `mk_const_sweep!([100]vshl_n_s8{bits: 3}(a: int8x8_t));`.
+//!
+//! Intrinsics on floating-point lanes can mark their tests with a `#[float(<lane type>)]` flag,
+//! placed right before the function name, e.g.
+//!
+//! `mk!(#[float(f32)]vfmaq_f32(a: float32x4_t, b: float32x4_t, c: float32x4_t));`
+//! `mk!([100]#[float(f32)]vneg_f32(a: float32x2_t));`
+//!
+//! With the flag, outputs are compared lane by lane using `eq_modulo_nan` instead of `assert_eq!`:
+//! any two NaNs of the lane's width are taken to be equal (the payload of a NaN produced by the hardware
+//! is not something the models try to reproduce), while every other bit pattern has to match exactly.
+//!
+//! The random inputs are drawn from a generator seeded with `crate::helpers::test::DEFAULT_SEED`,
+//! so every run of a test sees the same inputs. A different seed can be picked by setting the
+//! `SIMD_TEST_SEED` environment variable, e.g. `SIMD_TEST_SEED=42 cargo test`. The seed is printed
+//! at the start of each test, and is part of the failure message along with the inputs, so a
+//! failing case can be replayed by re-running the test with that seed.
 
 pub mod neon;
 
+use crate::abstractions::funarr::FunArray;
+use crate::abstractions::simd::Float;
+
+/// Compares two arrays lane by lane. Two NaN lanes are considered equal
+/// regardless of their sign and payload, all other lanes have to be bitwise
+/// equal.
+#[allow(unused)]
+pub(crate) fn eq_modulo_nan<T: Float, const N: u32>(
+    model: FunArray<N, T>,
+    upstream: FunArray<N, T>,
+) -> bool {
+    model
+        .as_vec()
+        .into_iter()
+        .zip(upstream.as_vec())
+        .all(|(x, y)| (x.is_nan() && y.is_nan()) || x.to_u128() == y.to_u128())
+}
+
 #[allow(non_camel_case_types)]
 mod types {
     use crate::abstractions::simd::*;
@@ -51,6 +100,8 @@ mod types {
     pub type uint32x2_t = u32x2;
     pub type uint16x4_t = u16x4;
     pub type uint8x8_t = u8x8;
+    pub type float32x2_t = f32x2;
+    pub type float32x4_t = f32x4;
 }
 
 pub(crate) mod upstream {
@@ -107,6 +158,8 @@ pub mod conversions {
     int8x8_t [i8; 8],
     uint32x2_t [u32; 2],
     uint16x4_t [u16; 4],
-    uint8x8_t [u8; 8]
+    uint8x8_t [u8; 8],
+    float32x2_t [f32; 2],
+    float32x4_t [f32; 4]
     );
 }
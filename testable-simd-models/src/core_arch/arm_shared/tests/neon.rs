@@ -1,10 +1,16 @@
 #[cfg(test)]
 use super::upstream;
 use crate::abstractions::funarr::FunArray;
-use crate::helpers::test::HasRandom;
+use crate::helpers::test::{mk_const_sweep, HasRandom};
 /// Derives tests for a given intrinsics. Test that a given intrinsics and its model compute the same thing over random values (1000 by default).
 macro_rules! mk {
-    ($([$N:literal])?$name:ident$({$(<$($c:literal),*>),*})?($($x:ident : $ty:ident),*)) => {
+    ($([$N:literal])?$(#[float($fty:ty)])?$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
+        mk!(@test[$($N)?, val$(, $fty)?]$name$({$(<$($c),*>),*})?($($x : $ty),*)$(-> $ret)?);
+    };
+    ($([$N:literal])?$(#[float($fty:ty)])?$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : &$ty:ident),+)$(-> $ret:ty)?) => {
+        mk!(@test[$($N)?, ref$(, $fty)?]$name$({$(<$($c),*>),*})?($($x : $ty),*)$(-> $ret)?);
+    };
+    (@test[$($N:literal)?, $pass:ident$(, $fty:ty)?]$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
         #[test]
         fn $name() {
             #[allow(unused)]
@@ -13,25 +19,87 @@ macro_rules! mk {
                 $(let n: usize = $N;)?
                     n
             };
-            mk!(@[N]$name$($(<$($c),*>)*)?($($x : $ty),*));
+            mk!(@[N, $pass$(, $fty)?]$name$($(<$($c),*>)*)?($($x : $ty),*)$(-> $ret)?);
+        }
+    };
+    (@val $x:ident) => {
+        $x.into()
+    };
+    (@ref $x:ident) => {
+        &$x.into()
+    };
+    (@cmp[$inputs:expr] $model:expr, $upstream:expr) => {
+        assert_eq!($model, $upstream, "Failed with input values: {:?} (seed: {})", $inputs, crate::helpers::test::seed())
+    };
+    (@cmp[$inputs:expr] $model:expr, $upstream:expr, $fty:ty) => {{
+        let (model, upstream) = ($model, $upstream);
+        assert!(
+            super::eq_modulo_nan::<$fty, _>(model, upstream),
+            "{model:?} != {upstream:?}\nFailed with input values: {:?} (seed: {})", $inputs, crate::helpers::test::seed()
+        )
+    }};
+    (@[$N:ident, $pass:ident]$name:ident$(<$($c:tt),*>)?($($x:ident : $ty:ident),*) -> $ret:ty) => {
+        for _ in 0..$N {
+            $(let $x = $ty::random();)*
+                let inputs: &[&dyn std::fmt::Debug] = &[$(&$x),*];
+                let model: $ret = super::super::models::neon::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*);
+                let upstream: $ret = unsafe { upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*) };
+                mk!(@cmp[inputs] model, upstream);
         }
     };
-    (@[$N:ident]$name:ident$(<$($c:literal),*>)?($($x:ident : $ty:ident),*)) => {
+    (@[$N:ident, $pass:ident$(, $fty:ty)?]$name:ident$(<$($c:tt),*>)?($($x:ident : $ty:ident),*)) => {
         for _ in 0..$N {
             $(let $x = $ty::random();)*
-                assert_eq!(super::super::models::neon::$name$(::<$($c,)*>)?($($x.into(),)*), unsafe {
-                    FunArray::from(upstream::$name$(::<$($c,)*>)?($($x.into(),)*)).into()
-                });
+                let inputs: &[&dyn std::fmt::Debug] = &[$(&$x),*];
+                mk!(@cmp[inputs] super::super::models::neon::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*), unsafe {
+                    FunArray::from(upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*)).into()
+                }$(, $fty)?);
         }
     };
-    (@[$N:ident]$name:ident<$($c1:literal),*>$(<$($c:literal),*>)*($($x:ident : $ty:ident),*)) => {
+    (@[$N:ident, $pass:ident$(, $fty:ty)?]$name:ident<$($c1:tt),*>$(<$($c:tt),*>)*($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
         let one = || {
-            mk!(@[$N]$name<$($c1),*>($($x : $ty),*));
+            mk!(@[$N, $pass$(, $fty)?]$name<$($c1),*>($($x : $ty),*)$(-> $ret)?);
         };
         one();
-        mk!(@[$N]$name$(<$($c),*>)*($($x : $ty),*));
+        mk!(@[$N, $pass$(, $fty)?]$name$(<$($c),*>)*($($x : $ty),*)$(-> $ret)?);
     }
+}
+
+/// Derives a test checking an intrinsic on the given inputs against both the
+/// expected output and upstream.
+macro_rules! mk_directed {
+    ($test:ident: $name:ident$(::<$c:tt>)?($($x:ident : $ty:ident = $val:expr),*) == $ret:ident $expected:expr) => {
+        #[test]
+        fn $test() {
+            $(let $x = $ty::from_fn(|i| $val[i as usize]);)*
+            let expected = $ret::from_fn(|i| $expected[i as usize]);
+            let model = super::super::models::neon::$name$(::<$c>)?($($x),*);
+            let upstream: $ret = unsafe { FunArray::from(upstream::$name$(::<$c>)?($($x.into()),*)) };
+            assert_eq!(model, expected);
+            assert_eq!(model, upstream);
+        }
+    };
+}
 
+/// Derives tests for an intrinsic returning a pair of vectors, comparing both
+/// halves of the result over random values (1000 by default).
+macro_rules! mk_pair {
+    ($([$N:literal])?$name:ident($($x:ident : $ty:ident),*)) => {
+        #[test]
+        fn $name() {
+            #[allow(unused)]
+            let n: usize = 1000;
+            $(let n: usize = $N;)?
+            for _ in 0..n {
+                $(let $x = $ty::random();)*
+                let inputs: &[&dyn std::fmt::Debug] = &[$(&$x),*];
+                let model = super::super::models::neon::$name($($x),*);
+                let upstream = unsafe { upstream::$name($($x.into()),*) };
+                let upstream = (FunArray::from(upstream.0), FunArray::from(upstream.1));
+                assert_eq!(model, upstream, "Failed with input values: {:?} (seed: {})", inputs, crate::helpers::test::seed());
+            }
+        }
+    };
 }
 
 use super::types::*;
@@ -71,11 +139,33 @@ mk!(vabs_s16(a: int16x4_t));
 mk!(vabsq_s16(a: int16x8_t));
 mk!(vabs_s32(a: int32x2_t));
 mk!(vabsq_s32(a: int32x4_t));
+mk!(vabs_s64(a: int64x1_t));
+mk!(vabsq_s64(a: int64x2_t));
+
+// The absolute value and the negation of `MIN` wrap around to `MIN`.
+mk_directed!(vabsq_s32_min: vabsq_s32(
+    a: int32x4_t = [i32::MIN, -1, 0, i32::MAX]
+) == int32x4_t [i32::MIN, 1, 0, i32::MAX]);
+mk_directed!(vnegq_s16_min: vnegq_s16(
+    a: int16x8_t = [i16::MIN, -1, 0, 1, i16::MAX, 2, -2, 100]
+) == int16x8_t [i16::MIN, 1, 0, -1, -i16::MAX, -2, 2, -100]);
+// The absolute difference is the larger element minus the smaller one, which
+// wraps around for signed lanes.
+mk_directed!(vabdq_u8_larger_minus_smaller: vabdq_u8(
+    a: uint8x16_t = [3, 250, 0, 255, 1, 2, 128, 127, 0, 10, 20, 30, 40, 50, 60, 70],
+    b: uint8x16_t = [250, 3, 255, 0, 2, 1, 127, 128, 0, 20, 10, 60, 10, 0, 80, 70]
+) == uint8x16_t [247, 247, 255, 255, 1, 1, 1, 1, 0, 10, 10, 30, 30, 50, 20, 0]);
+mk_directed!(vabdq_s8_wrapping: vabdq_s8(
+    a: int8x16_t = [-128, 127, -1, 5, 0, 0, -100, 100, 1, 2, 3, 4, 5, 6, 7, 8],
+    b: int8x16_t = [127, -128, 1, -5, -128, 127, 100, -100, 1, 1, 1, 1, 1, 1, 1, 1]
+) == int8x16_t [-1, -1, 2, 10, -128, 127, -56, -56, 0, 1, 2, 3, 4, 5, 6, 7]);
 mk!(vadd_s16(a: int16x4_t, b: int16x4_t));
 mk!(vadd_s32(a: int32x2_t, b: int32x2_t));
+mk!(vadd_s64(a: int64x1_t, b: int64x1_t));
 mk!(vadd_s8(a: int8x8_t, b: int8x8_t));
 mk!(vadd_u16(a: uint16x4_t, b: uint16x4_t));
 mk!(vadd_u32(a: uint32x2_t, b: uint32x2_t));
+mk!(vadd_u64(a: uint64x1_t, b: uint64x1_t));
 mk!(vadd_u8(a: uint8x8_t, b: uint8x8_t));
 mk!(vaddq_s16(a: int16x8_t, b: int16x8_t));
 mk!(vaddq_s32(a: int32x4_t, b: int32x4_t));
@@ -115,6 +205,37 @@ mk!(vaddw_high_s8(a: int16x8_t, b: int8x16_t));
 mk!(vaddw_high_u16(a: uint32x4_t, b: uint16x8_t));
 mk!(vaddw_high_u32(a: uint64x2_t, b: uint32x4_t));
 mk!(vaddw_high_u8(a: uint16x8_t, b: uint8x16_t));
+mk!(vaddv_s8(a: int8x8_t) -> i8);
+mk!(vaddvq_s8(a: int8x16_t) -> i8);
+mk!(vaddv_s16(a: int16x4_t) -> i16);
+mk!(vaddvq_s16(a: int16x8_t) -> i16);
+mk!(vaddv_s32(a: int32x2_t) -> i32);
+mk!(vaddvq_s32(a: int32x4_t) -> i32);
+mk!(vaddv_u8(a: uint8x8_t) -> u8);
+mk!(vaddvq_u8(a: uint8x16_t) -> u8);
+mk!(vaddv_u16(a: uint16x4_t) -> u16);
+mk!(vaddvq_u16(a: uint16x8_t) -> u16);
+mk!(vaddv_u32(a: uint32x2_t) -> u32);
+mk!(vaddvq_u32(a: uint32x4_t) -> u32);
+mk!(vaddvq_s64(a: int64x2_t) -> i64);
+mk!(vaddvq_u64(a: uint64x2_t) -> u64);
+
+// The sum wraps around on overflow.
+#[test]
+fn vaddvq_u8_overflow() {
+    let a = uint8x16_t::splat(u8::MAX);
+    let model = super::super::models::neon::vaddvq_u8(a);
+    assert_eq!(model, 240);
+    assert_eq!(model, unsafe { upstream::vaddvq_u8(a.into()) });
+}
+#[test]
+fn vaddv_s8_overflow() {
+    let a = int8x8_t::splat(i8::MAX);
+    let model = super::super::models::neon::vaddv_s8(a);
+    assert_eq!(model, -8);
+    assert_eq!(model, unsafe { upstream::vaddv_s8(a.into()) });
+}
+
 mk!(vaddw_s16(a: int32x4_t, b: int16x4_t));
 mk!(vaddw_s32(a: int64x2_t, b: int32x2_t));
 mk!(vaddw_s8(a: int16x8_t, b: int8x8_t));
@@ -139,6 +260,7 @@ mk!(vand_u64(a: uint64x1_t, b: uint64x1_t));
 mk!(vandq_u64(a: uint64x2_t, b: uint64x2_t));
 mk!(vbic_s16(a: int16x4_t, b: int16x4_t));
 mk!(vbic_s32(a: int32x2_t, b: int32x2_t));
+mk!(vbic_s64(a: int64x1_t, b: int64x1_t));
 mk!(vbic_s8(a: int8x8_t, b: int8x8_t));
 mk!(vbicq_s16(a: int16x8_t, b: int16x8_t));
 mk!(vbicq_s32(a: int32x4_t, b: int32x4_t));
@@ -180,6 +302,10 @@ mk!(vceq_u16(a: uint16x4_t, b: uint16x4_t));
 mk!(vceqq_u16(a: uint16x8_t, b: uint16x8_t));
 mk!(vceq_u32(a: uint32x2_t, b: uint32x2_t));
 mk!(vceqq_u32(a: uint32x4_t, b: uint32x4_t));
+mk!(vceq_s64(a: int64x1_t, b: int64x1_t));
+mk!(vceqq_s64(a: int64x2_t, b: int64x2_t));
+mk!(vceq_u64(a: uint64x1_t, b: uint64x1_t));
+mk!(vceqq_u64(a: uint64x2_t, b: uint64x2_t));
 mk!(vcge_s8(a: int8x8_t, b: int8x8_t));
 mk!(vcgeq_s8(a: int8x16_t, b: int8x16_t));
 mk!(vcge_s16(a: int16x4_t, b: int16x4_t));
@@ -192,6 +318,10 @@ mk!(vcge_u16(a: uint16x4_t, b: uint16x4_t));
 mk!(vcgeq_u16(a: uint16x8_t, b: uint16x8_t));
 mk!(vcge_u32(a: uint32x2_t, b: uint32x2_t));
 mk!(vcgeq_u32(a: uint32x4_t, b: uint32x4_t));
+mk!(vcge_s64(a: int64x1_t, b: int64x1_t));
+mk!(vcgeq_s64(a: int64x2_t, b: int64x2_t));
+mk!(vcge_u64(a: uint64x1_t, b: uint64x1_t));
+mk!(vcgeq_u64(a: uint64x2_t, b: uint64x2_t));
 mk!(vcgt_s8(a: int8x8_t, b: int8x8_t));
 mk!(vcgtq_s8(a: int8x16_t, b: int8x16_t));
 mk!(vcgt_s16(a: int16x4_t, b: int16x4_t));
@@ -204,6 +334,10 @@ mk!(vcgt_u16(a: uint16x4_t, b: uint16x4_t));
 mk!(vcgtq_u16(a: uint16x8_t, b: uint16x8_t));
 mk!(vcgt_u32(a: uint32x2_t, b: uint32x2_t));
 mk!(vcgtq_u32(a: uint32x4_t, b: uint32x4_t));
+mk!(vcgt_s64(a: int64x1_t, b: int64x1_t));
+mk!(vcgtq_s64(a: int64x2_t, b: int64x2_t));
+mk!(vcgt_u64(a: uint64x1_t, b: uint64x1_t));
+mk!(vcgtq_u64(a: uint64x2_t, b: uint64x2_t));
 mk!(vcle_s8(a: int8x8_t, b: int8x8_t));
 mk!(vcleq_s8(a: int8x16_t, b: int8x16_t));
 mk!(vcle_s16(a: int16x4_t, b: int16x4_t));
@@ -216,3 +350,927 @@ mk!(vcle_u16(a: uint16x4_t, b: uint16x4_t));
 mk!(vcleq_u16(a: uint16x8_t, b: uint16x8_t));
 mk!(vcle_u32(a: uint32x2_t, b: uint32x2_t));
 mk!(vcleq_u32(a: uint32x4_t, b: uint32x4_t));
+mk!(vcle_s64(a: int64x1_t, b: int64x1_t));
+mk!(vcleq_s64(a: int64x2_t, b: int64x2_t));
+mk!(vcle_u64(a: uint64x1_t, b: uint64x1_t));
+mk!(vcleq_u64(a: uint64x2_t, b: uint64x2_t));
+mk!(vclt_s8(a: int8x8_t, b: int8x8_t));
+mk!(vcltq_s8(a: int8x16_t, b: int8x16_t));
+mk!(vclt_s16(a: int16x4_t, b: int16x4_t));
+mk!(vcltq_s16(a: int16x8_t, b: int16x8_t));
+mk!(vclt_s32(a: int32x2_t, b: int32x2_t));
+mk!(vcltq_s32(a: int32x4_t, b: int32x4_t));
+mk!(vclt_u8(a: uint8x8_t, b: uint8x8_t));
+mk!(vcltq_u8(a: uint8x16_t, b: uint8x16_t));
+mk!(vclt_u16(a: uint16x4_t, b: uint16x4_t));
+mk!(vcltq_u16(a: uint16x8_t, b: uint16x8_t));
+mk!(vclt_u32(a: uint32x2_t, b: uint32x2_t));
+mk!(vcltq_u32(a: uint32x4_t, b: uint32x4_t));
+mk!(vclt_s64(a: int64x1_t, b: int64x1_t));
+mk!(vcltq_s64(a: int64x2_t, b: int64x2_t));
+mk!(vclt_u64(a: uint64x1_t, b: uint64x1_t));
+mk!(vcltq_u64(a: uint64x2_t, b: uint64x2_t));
+
+// Unsigned comparisons have to order operands with their highest bit set
+// above the others, where signed comparisons put them below.
+mk_directed!(vcgtq_u8_high_bit: vcgtq_u8(
+    a: uint8x16_t = [0x80; 16],
+    b: uint8x16_t = [0x7f; 16]
+) == uint8x16_t [u8::MAX; 16]);
+mk_directed!(vcltq_u32_high_bit: vcltq_u32(
+    a: uint32x4_t = [1, 0x7fff_ffff, 0x8000_0000, u32::MAX],
+    b: uint32x4_t = [0x8000_0000, u32::MAX, 1, 0]
+) == uint32x4_t [u32::MAX, u32::MAX, 0, 0]);
+mk_directed!(vcgeq_s16_signed: vcgeq_s16(
+    a: int16x8_t = [-1, i16::MIN, 0, 1, -1, i16::MAX, 0, i16::MIN],
+    b: int16x8_t = [0, 0, i16::MIN, -1, -1, i16::MIN, i16::MAX, i16::MIN]
+) == uint16x8_t [0, 0, u16::MAX, u16::MAX, u16::MAX, u16::MAX, 0, u16::MAX]);
+mk!(vclz_s8(a: int8x8_t));
+mk!(vclzq_s8(a: int8x16_t));
+mk!(vclz_s16(a: int16x4_t));
+mk!(vclzq_s16(a: int16x8_t));
+mk!(vclz_s32(a: int32x2_t));
+mk!(vclzq_s32(a: int32x4_t));
+mk!(vclz_u8(a: uint8x8_t));
+mk!(vclzq_u8(a: uint8x16_t));
+mk!(vclz_u16(a: uint16x4_t));
+mk!(vclzq_u16(a: uint16x8_t));
+mk!(vclz_u32(a: uint32x2_t));
+mk!(vclzq_u32(a: uint32x4_t));
+mk_directed!(vclz_s8_bounds: vclz_s8(
+    a: int8x8_t = [0, -1, 1, i8::MIN, i8::MAX, 0x10, 0x0f, -0x10]
+) == int8x8_t [8, 0, 7, 0, 1, 3, 4, 0]);
+mk_directed!(vclzq_u32_bounds: vclzq_u32(
+    a: uint32x4_t = [0, u32::MAX, 1, 0x8000_0000]
+) == uint32x4_t [32, 0, 31, 0]);
+mk!(vcnt_s8(a: int8x8_t));
+mk!(vcntq_s8(a: int8x16_t));
+mk!(vcnt_u8(a: uint8x8_t));
+mk!(vcntq_u8(a: uint8x16_t));
+mk_directed!(vcnt_u8_bounds: vcnt_u8(
+    a: uint8x8_t = [0, u8::MAX, 1, 0x80, 0x55, 0xaa, 0x0f, 0xf0]
+) == uint8x8_t [0, 8, 1, 1, 4, 4, 4, 4]);
+mk_directed!(vcnt_s8_bounds: vcnt_s8(
+    a: int8x8_t = [0, -1, 1, i8::MIN, i8::MAX, -2, 0x0f, -0x10]
+) == int8x8_t [0, 8, 1, 1, 7, 7, 4, 4]);
+mk!(vcombine_s8(low: int8x8_t, high: int8x8_t));
+mk!(vcombine_s16(low: int16x4_t, high: int16x4_t));
+mk!(vcombine_s32(low: int32x2_t, high: int32x2_t));
+mk!(vcombine_s64(low: int64x1_t, high: int64x1_t));
+mk!(vcombine_u8(low: uint8x8_t, high: uint8x8_t));
+mk!(vcombine_u16(low: uint16x4_t, high: uint16x4_t));
+mk!(vcombine_u32(low: uint32x2_t, high: uint32x2_t));
+mk!(vcombine_u64(low: uint64x1_t, high: uint64x1_t));
+
+// The low half of a q register is made of its first lanes, the high half of
+// its last ones.
+mk_directed!(vcombine_s32_halves: vcombine_s32(
+    low: int32x2_t = [1, 2],
+    high: int32x2_t = [3, 4]
+) == int32x4_t [1, 2, 3, 4]);
+mk_directed!(vget_low_u16_half: vget_low_u16(
+    a: uint16x8_t = [1, 2, 3, 4, 5, 6, 7, 8]
+) == uint16x4_t [1, 2, 3, 4]);
+mk_directed!(vget_high_u16_half: vget_high_u16(
+    a: uint16x8_t = [1, 2, 3, 4, 5, 6, 7, 8]
+) == uint16x4_t [5, 6, 7, 8]);
+
+mk!(vdup_n_s8(value: i8));
+mk!(vdupq_n_s8(value: i8));
+mk!(vdup_n_s16(value: i16));
+mk!(vdupq_n_s16(value: i16));
+mk!(vdup_n_s32(value: i32));
+mk!(vdupq_n_s32(value: i32));
+mk!(vdup_n_s64(value: i64));
+mk!(vdupq_n_s64(value: i64));
+mk!(vdup_n_u8(value: u8));
+mk!(vdupq_n_u8(value: u8));
+mk!(vdup_n_u16(value: u16));
+mk!(vdupq_n_u16(value: u16));
+mk!(vdup_n_u32(value: u32));
+mk!(vdupq_n_u32(value: u32));
+mk!(vdup_n_u64(value: u64));
+mk!(vdupq_n_u64(value: u64));
+mk!(vdup_lane_s8{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>}(a: int8x8_t));
+mk!(vdupq_lane_s8{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>}(a: int8x8_t));
+mk!(vdup_lane_s16{<0>,<1>,<2>,<3>}(a: int16x4_t));
+mk!(vdupq_lane_s16{<0>,<1>,<2>,<3>}(a: int16x4_t));
+mk!(vdup_lane_s32{<0>,<1>}(a: int32x2_t));
+mk!(vdupq_lane_s32{<0>,<1>}(a: int32x2_t));
+mk!(vdup_lane_s64{<0>}(a: int64x1_t));
+mk!(vdupq_lane_s64{<0>}(a: int64x1_t));
+mk!(vdup_lane_u8{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>}(a: uint8x8_t));
+mk!(vdupq_lane_u8{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>}(a: uint8x8_t));
+mk!(vdup_lane_u16{<0>,<1>,<2>,<3>}(a: uint16x4_t));
+mk!(vdupq_lane_u16{<0>,<1>,<2>,<3>}(a: uint16x4_t));
+mk!(vdup_lane_u32{<0>,<1>}(a: uint32x2_t));
+mk!(vdupq_lane_u32{<0>,<1>}(a: uint32x2_t));
+mk!(vdup_lane_u64{<0>}(a: uint64x1_t));
+mk!(vdupq_lane_u64{<0>}(a: uint64x1_t));
+mk!(veor_s8(a: int8x8_t, b: int8x8_t));
+mk!(veorq_s8(a: int8x16_t, b: int8x16_t));
+mk!(veor_s16(a: int16x4_t, b: int16x4_t));
+mk!(veorq_s16(a: int16x8_t, b: int16x8_t));
+mk!(veor_s32(a: int32x2_t, b: int32x2_t));
+mk!(veorq_s32(a: int32x4_t, b: int32x4_t));
+mk!(veor_s64(a: int64x1_t, b: int64x1_t));
+mk!(veorq_s64(a: int64x2_t, b: int64x2_t));
+mk!(veor_u8(a: uint8x8_t, b: uint8x8_t));
+mk!(veorq_u8(a: uint8x16_t, b: uint8x16_t));
+mk!(veor_u16(a: uint16x4_t, b: uint16x4_t));
+mk!(veorq_u16(a: uint16x8_t, b: uint16x8_t));
+mk!(veor_u32(a: uint32x2_t, b: uint32x2_t));
+mk!(veorq_u32(a: uint32x4_t, b: uint32x4_t));
+mk!(veor_u64(a: uint64x1_t, b: uint64x1_t));
+mk!(veorq_u64(a: uint64x2_t, b: uint64x2_t));
+mk!(vext_s8{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>}(a: int8x8_t, b: int8x8_t));
+mk!(vextq_s8{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>}(a: int8x16_t, b: int8x16_t));
+mk!(vext_s16{<0>,<1>,<2>,<3>}(a: int16x4_t, b: int16x4_t));
+mk!(vextq_s16{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>}(a: int16x8_t, b: int16x8_t));
+mk!(vext_s32{<0>,<1>}(a: int32x2_t, b: int32x2_t));
+mk!(vextq_s32{<0>,<1>,<2>,<3>}(a: int32x4_t, b: int32x4_t));
+mk!(vext_u8{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>}(a: uint8x8_t, b: uint8x8_t));
+mk!(vextq_u8{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>}(a: uint8x16_t, b: uint8x16_t));
+mk!(vext_u16{<0>,<1>,<2>,<3>}(a: uint16x4_t, b: uint16x4_t));
+mk!(vextq_u16{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>}(a: uint16x8_t, b: uint16x8_t));
+mk!(vext_u32{<0>,<1>}(a: uint32x2_t, b: uint32x2_t));
+mk!(vextq_u32{<0>,<1>,<2>,<3>}(a: uint32x4_t, b: uint32x4_t));
+
+// The window starts at lane `N` of `a`: `N = 0` gives back `a`, and the largest
+// `N` leaves a single lane of `a` followed by the first lanes of `b`.
+mk_directed!(vextq_s32_first: vextq_s32::<0>(
+    a: int32x4_t = [1, 2, 3, 4],
+    b: int32x4_t = [5, 6, 7, 8]
+) == int32x4_t [1, 2, 3, 4]);
+mk_directed!(vextq_s32_last: vextq_s32::<3>(
+    a: int32x4_t = [1, 2, 3, 4],
+    b: int32x4_t = [5, 6, 7, 8]
+) == int32x4_t [4, 5, 6, 7]);
+mk_directed!(vext_u8_first: vext_u8::<0>(
+    a: uint8x8_t = [0, 1, 2, 3, 4, 5, 6, 7],
+    b: uint8x8_t = [8, 9, 10, 11, 12, 13, 14, 15]
+) == uint8x8_t [0, 1, 2, 3, 4, 5, 6, 7]);
+mk_directed!(vext_u8_last: vext_u8::<7>(
+    a: uint8x8_t = [0, 1, 2, 3, 4, 5, 6, 7],
+    b: uint8x8_t = [8, 9, 10, 11, 12, 13, 14, 15]
+) == uint8x8_t [7, 8, 9, 10, 11, 12, 13, 14]);
+
+mk!(#[float(f32)]vfma_f32(a: float32x2_t, b: float32x2_t, c: float32x2_t));
+mk!(#[float(f32)]vfmaq_f32(a: float32x4_t, b: float32x4_t, c: float32x4_t));
+// (1 + 2^-12)^2 = 1 + 2^-11 + 2^-24 rounds to 1 + 2^-11, so an unfused
+// multiply-add would cancel out to 0 in the first lane, and the product in the
+// last lane would overflow to infinity.
+mk_directed!(vfmaq_f32_single_rounding: vfmaq_f32(
+    a: float32x4_t = [-1.0 - f32::EPSILON * 4096.0, 1.0, -0.5, f32::MAX],
+    b: float32x4_t = [1.0 + f32::EPSILON * 2048.0, 2.0, 0.25, 2.0],
+    c: float32x4_t = [1.0 + f32::EPSILON * 2048.0, -3.0, 2.0, -f32::MAX]
+) == float32x4_t [f32::EPSILON / 2.0, -5.0, 0.0, -f32::MAX]);
+mk!(vget_high_s8(a: int8x16_t));
+mk!(vget_high_s16(a: int16x8_t));
+mk!(vget_high_s32(a: int32x4_t));
+mk!(vget_high_s64(a: int64x2_t));
+mk!(vget_high_u8(a: uint8x16_t));
+mk!(vget_high_u16(a: uint16x8_t));
+mk!(vget_high_u32(a: uint32x4_t));
+mk!(vget_high_u64(a: uint64x2_t));
+mk!(vget_lane_s8{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>}(v: int8x8_t) -> i8);
+mk!(vgetq_lane_s8{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>}(v: int8x16_t) -> i8);
+mk!(vget_lane_s16{<0>,<1>,<2>,<3>}(v: int16x4_t) -> i16);
+mk!(vgetq_lane_s16{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>}(v: int16x8_t) -> i16);
+mk!(vget_lane_s32{<0>,<1>}(v: int32x2_t) -> i32);
+mk!(vgetq_lane_s32{<0>,<1>,<2>,<3>}(v: int32x4_t) -> i32);
+mk!(vget_lane_s64{<0>}(v: int64x1_t) -> i64);
+mk!(vgetq_lane_s64{<0>,<1>}(v: int64x2_t) -> i64);
+mk!(vget_lane_u8{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>}(v: uint8x8_t) -> u8);
+mk!(vgetq_lane_u8{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>}(v: uint8x16_t) -> u8);
+mk!(vget_lane_u16{<0>,<1>,<2>,<3>}(v: uint16x4_t) -> u16);
+mk!(vgetq_lane_u16{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>}(v: uint16x8_t) -> u16);
+mk!(vget_lane_u32{<0>,<1>}(v: uint32x2_t) -> u32);
+mk!(vgetq_lane_u32{<0>,<1>,<2>,<3>}(v: uint32x4_t) -> u32);
+mk!(vget_lane_u64{<0>}(v: uint64x1_t) -> u64);
+mk!(vgetq_lane_u64{<0>,<1>}(v: uint64x2_t) -> u64);
+mk!(vget_low_s8(a: int8x16_t));
+mk!(vget_low_s16(a: int16x8_t));
+mk!(vget_low_s32(a: int32x4_t));
+mk!(vget_low_s64(a: int64x2_t));
+mk!(vget_low_u8(a: uint8x16_t));
+mk!(vget_low_u16(a: uint16x8_t));
+mk!(vget_low_u32(a: uint32x4_t));
+mk!(vget_low_u64(a: uint64x2_t));
+
+/// Derives tests for a load and the matching store, over slices one element
+/// longer than a vector. The load is compared against upstream and checked to
+/// read lane `i` from `ptr[i]`. The store is compared against upstream, must
+/// leave the element past the vector untouched, and must give back the stored
+/// vector when loaded again.
+macro_rules! mk_load_store {
+    ($load:ident, $store:ident: [$elem:ty; $lanes:literal] as $ty:ident) => {
+        #[test]
+        fn $load() {
+            for _ in 0..1000 {
+                let mem: [$elem; $lanes + 1] = core::array::from_fn(|_| <$elem>::random());
+                let model = super::super::models::neon::$load(&mem);
+                let upstream: $ty = unsafe { FunArray::from(upstream::$load(mem.as_ptr())) };
+                assert_eq!(model, upstream, "{mem:?}");
+                for i in 0..$lanes {
+                    assert_eq!(model[i as u32], mem[i], "lane {i}: {mem:?}");
+                }
+            }
+        }
+
+        #[test]
+        fn $store() {
+            for _ in 0..1000 {
+                let a = $ty::random();
+                let sentinel = <$elem>::random();
+                let mut model = [sentinel; $lanes + 1];
+                let mut upstream = [sentinel; $lanes + 1];
+                super::super::models::neon::$store(&mut model, a);
+                unsafe { upstream::$store(upstream.as_mut_ptr(), a.into()) };
+                assert_eq!(model, upstream, "{a:?}");
+                assert_eq!(model[$lanes], sentinel, "{a:?}");
+                assert_eq!(super::super::models::neon::$load(&model), a);
+            }
+        }
+    };
+}
+
+mk_load_store!(vld1q_s8, vst1q_s8: [i8; 16] as int8x16_t);
+mk_load_store!(vld1q_s16, vst1q_s16: [i16; 8] as int16x8_t);
+mk_load_store!(vld1q_s32, vst1q_s32: [i32; 4] as int32x4_t);
+mk_load_store!(vld1q_s64, vst1q_s64: [i64; 2] as int64x2_t);
+mk_load_store!(vld1q_u8, vst1q_u8: [u8; 16] as uint8x16_t);
+mk_load_store!(vld1q_u16, vst1q_u16: [u16; 8] as uint16x8_t);
+mk_load_store!(vld1q_u32, vst1q_u32: [u32; 4] as uint32x4_t);
+mk_load_store!(vld1q_u64, vst1q_u64: [u64; 2] as uint64x2_t);
+mk_load_store!(vld1q_f32, vst1q_f32: [f32; 4] as float32x4_t);
+
+/// Derives tests for an interleaved load and the matching store, comparing each
+/// vector of the result. The load is compared against upstream, and checked to
+/// give channel `c` the elements `c, k + c, 2 * k + c, ...` for `k` channels.
+/// The store is compared against upstream, must leave the element past the
+/// vectors untouched, and must give back the stored vectors when loaded again.
+macro_rules! mk_load_store_interleaved {
+    ($load:ident, $store:ident: [$elem:ty; $lanes:literal] as $ty:ident x $k:literal [$($c:tt)*] as $upstream:ident) => {
+        #[test]
+        fn $load() {
+            for _ in 0..1000 {
+                let mem: [$elem; $k * $lanes + 1] = core::array::from_fn(|_| <$elem>::random());
+                let model = super::super::models::neon::$load(&mem);
+                let upstream = unsafe { upstream::$load(mem.as_ptr()) };
+                $(
+                    let channel: $ty = model.$c;
+                    assert_eq!(channel, FunArray::from(upstream.$c), "channel {}: {mem:?}", $c);
+                    for i in 0..$lanes {
+                        assert_eq!(channel[i as u32], mem[$k * i + $c], "channel {}, lane {i}", $c);
+                    }
+                )*
+            }
+        }
+
+        #[test]
+        fn $store() {
+            for _ in 0..1000 {
+                let a = ($({ let _ = $c; $ty::random() }),*);
+                let sentinel = <$elem>::random();
+                let mut model = [sentinel; $k * $lanes + 1];
+                let mut upstream = [sentinel; $k * $lanes + 1];
+                super::super::models::neon::$store(&mut model, a);
+                unsafe { upstream::$store(upstream.as_mut_ptr(), upstream::$upstream($(a.$c.into()),*)) };
+                assert_eq!(model, upstream, "{a:?}");
+                assert_eq!(model[$k * $lanes], sentinel, "{a:?}");
+                assert_eq!(super::super::models::neon::$load(&model), a);
+            }
+        }
+    };
+}
+
+mk_load_store_interleaved!(vld2q_s8, vst2q_s8: [i8; 16] as int8x16_t x 2 [0 1] as int8x16x2_t);
+mk_load_store_interleaved!(vld2q_s16, vst2q_s16: [i16; 8] as int16x8_t x 2 [0 1] as int16x8x2_t);
+mk_load_store_interleaved!(vld2q_s32, vst2q_s32: [i32; 4] as int32x4_t x 2 [0 1] as int32x4x2_t);
+mk_load_store_interleaved!(vld2q_u8, vst2q_u8: [u8; 16] as uint8x16_t x 2 [0 1] as uint8x16x2_t);
+mk_load_store_interleaved!(vld2q_u16, vst2q_u16: [u16; 8] as uint16x8_t x 2 [0 1] as uint16x8x2_t);
+mk_load_store_interleaved!(vld2q_u32, vst2q_u32: [u32; 4] as uint32x4_t x 2 [0 1] as uint32x4x2_t);
+mk_load_store_interleaved!(vld2q_f32, vst2q_f32: [f32; 4] as float32x4_t x 2 [0 1] as float32x4x2_t);
+mk_load_store_interleaved!(vld3q_s8, vst3q_s8: [i8; 16] as int8x16_t x 3 [0 1 2] as int8x16x3_t);
+mk_load_store_interleaved!(vld3q_s16, vst3q_s16: [i16; 8] as int16x8_t x 3 [0 1 2] as int16x8x3_t);
+mk_load_store_interleaved!(vld3q_s32, vst3q_s32: [i32; 4] as int32x4_t x 3 [0 1 2] as int32x4x3_t);
+mk_load_store_interleaved!(vld3q_u8, vst3q_u8: [u8; 16] as uint8x16_t x 3 [0 1 2] as uint8x16x3_t);
+mk_load_store_interleaved!(vld3q_u16, vst3q_u16: [u16; 8] as uint16x8_t x 3 [0 1 2] as uint16x8x3_t);
+mk_load_store_interleaved!(vld3q_u32, vst3q_u32: [u32; 4] as uint32x4_t x 3 [0 1 2] as uint32x4x3_t);
+mk_load_store_interleaved!(vld3q_f32, vst3q_f32: [f32; 4] as float32x4_t x 3 [0 1 2] as float32x4x3_t);
+mk_load_store_interleaved!(vld4q_s8, vst4q_s8: [i8; 16] as int8x16_t x 4 [0 1 2 3] as int8x16x4_t);
+mk_load_store_interleaved!(vld4q_s16, vst4q_s16: [i16; 8] as int16x8_t x 4 [0 1 2 3] as int16x8x4_t);
+mk_load_store_interleaved!(vld4q_s32, vst4q_s32: [i32; 4] as int32x4_t x 4 [0 1 2 3] as int32x4x4_t);
+mk_load_store_interleaved!(vld4q_u8, vst4q_u8: [u8; 16] as uint8x16_t x 4 [0 1 2 3] as uint8x16x4_t);
+mk_load_store_interleaved!(vld4q_u16, vst4q_u16: [u16; 8] as uint16x8_t x 4 [0 1 2 3] as uint16x8x4_t);
+mk_load_store_interleaved!(vld4q_u32, vst4q_u32: [u32; 4] as uint32x4_t x 4 [0 1 2 3] as uint32x4x4_t);
+mk_load_store_interleaved!(vld4q_f32, vst4q_f32: [f32; 4] as float32x4_t x 4 [0 1 2 3] as float32x4x4_t);
+mk!(vmax_s8(a: int8x8_t, b: int8x8_t));
+mk!(vmaxq_s8(a: int8x16_t, b: int8x16_t));
+mk!(vmax_s16(a: int16x4_t, b: int16x4_t));
+mk!(vmaxq_s16(a: int16x8_t, b: int16x8_t));
+mk!(vmax_s32(a: int32x2_t, b: int32x2_t));
+mk!(vmaxq_s32(a: int32x4_t, b: int32x4_t));
+mk!(vmax_u8(a: uint8x8_t, b: uint8x8_t));
+mk!(vmaxq_u8(a: uint8x16_t, b: uint8x16_t));
+mk!(vmax_u16(a: uint16x4_t, b: uint16x4_t));
+mk!(vmaxq_u16(a: uint16x8_t, b: uint16x8_t));
+mk!(vmax_u32(a: uint32x2_t, b: uint32x2_t));
+mk!(vmaxq_u32(a: uint32x4_t, b: uint32x4_t));
+mk!(vmaxv_s8(a: int8x8_t) -> i8);
+mk!(vmaxvq_s8(a: int8x16_t) -> i8);
+mk!(vmaxv_s16(a: int16x4_t) -> i16);
+mk!(vmaxvq_s16(a: int16x8_t) -> i16);
+mk!(vmaxv_s32(a: int32x2_t) -> i32);
+mk!(vmaxvq_s32(a: int32x4_t) -> i32);
+mk!(vmaxv_u8(a: uint8x8_t) -> u8);
+mk!(vmaxvq_u8(a: uint8x16_t) -> u8);
+mk!(vmaxv_u16(a: uint16x4_t) -> u16);
+mk!(vmaxvq_u16(a: uint16x8_t) -> u16);
+mk!(vmaxv_u32(a: uint32x2_t) -> u32);
+mk!(vmaxvq_u32(a: uint32x4_t) -> u32);
+mk!(vmin_s8(a: int8x8_t, b: int8x8_t));
+mk!(vminq_s8(a: int8x16_t, b: int8x16_t));
+mk!(vmin_s16(a: int16x4_t, b: int16x4_t));
+mk!(vminq_s16(a: int16x8_t, b: int16x8_t));
+mk!(vmin_s32(a: int32x2_t, b: int32x2_t));
+mk!(vminq_s32(a: int32x4_t, b: int32x4_t));
+mk!(vmin_u8(a: uint8x8_t, b: uint8x8_t));
+mk!(vminq_u8(a: uint8x16_t, b: uint8x16_t));
+mk!(vmin_u16(a: uint16x4_t, b: uint16x4_t));
+mk!(vminq_u16(a: uint16x8_t, b: uint16x8_t));
+mk!(vmin_u32(a: uint32x2_t, b: uint32x2_t));
+mk!(vminq_u32(a: uint32x4_t, b: uint32x4_t));
+mk!(vminv_s8(a: int8x8_t) -> i8);
+mk!(vminvq_s8(a: int8x16_t) -> i8);
+mk!(vminv_s16(a: int16x4_t) -> i16);
+mk!(vminvq_s16(a: int16x8_t) -> i16);
+mk!(vminv_s32(a: int32x2_t) -> i32);
+mk!(vminvq_s32(a: int32x4_t) -> i32);
+mk!(vminv_u8(a: uint8x8_t) -> u8);
+mk!(vminvq_u8(a: uint8x16_t) -> u8);
+mk!(vminv_u16(a: uint16x4_t) -> u16);
+mk!(vminvq_u16(a: uint16x8_t) -> u16);
+mk!(vminv_u32(a: uint32x2_t) -> u32);
+mk!(vminvq_u32(a: uint32x4_t) -> u32);
+
+// Values straddling the sign boundary are ordered differently by signed and
+// unsigned comparisons.
+mk_directed!(vmaxq_s8_sign_boundary: vmaxq_s8(
+    a: int8x16_t = [-1, 0, i8::MIN, i8::MAX, -128, 127, -2, 1, 0, -1, 5, -5, 100, -100, 0, 0],
+    b: int8x16_t = [0, -1, i8::MAX, i8::MIN, 127, -128, 1, -2, 0, -1, -5, 5, -100, 100, i8::MIN, i8::MAX]
+) == int8x16_t [0, 0, i8::MAX, i8::MAX, 127, 127, 1, 1, 0, -1, 5, 5, 100, 100, 0, i8::MAX]);
+mk_directed!(vmaxq_u8_sign_boundary: vmaxq_u8(
+    a: uint8x16_t = [0xff, 0, 0x80, 0x7f, 0x80, 0x7f, 0xfe, 1, 0, 0xff, 5, 0xfb, 100, 0x9c, 0, 0],
+    b: uint8x16_t = [0, 0xff, 0x7f, 0x80, 0x7f, 0x80, 1, 0xfe, 0, 0xff, 0xfb, 5, 0x9c, 100, 0x80, 0x7f]
+) == uint8x16_t [0xff, 0xff, 0x80, 0x80, 0x80, 0x80, 0xfe, 0xfe, 0, 0xff, 0xfb, 0xfb, 0x9c, 0x9c, 0x80, 0x7f]);
+mk_directed!(vminq_s32_sign_boundary: vminq_s32(
+    a: int32x4_t = [-1, i32::MIN, 0, i32::MAX],
+    b: int32x4_t = [1, i32::MAX, -1, i32::MIN]
+) == int32x4_t [-1, i32::MIN, -1, i32::MIN]);
+mk_directed!(vminq_u32_sign_boundary: vminq_u32(
+    a: uint32x4_t = [u32::MAX, 0x8000_0000, 0, 0x7fff_ffff],
+    b: uint32x4_t = [1, 0x7fff_ffff, u32::MAX, 0x8000_0000]
+) == uint32x4_t [1, 0x7fff_ffff, 0, 0x7fff_ffff]);
+
+mk!(vmla_s8(a: int8x8_t, b: int8x8_t, c: int8x8_t));
+mk!(vmla_s16(a: int16x4_t, b: int16x4_t, c: int16x4_t));
+mk!(vmla_s32(a: int32x2_t, b: int32x2_t, c: int32x2_t));
+mk!(vmla_u8(a: uint8x8_t, b: uint8x8_t, c: uint8x8_t));
+mk!(vmla_u16(a: uint16x4_t, b: uint16x4_t, c: uint16x4_t));
+mk!(vmla_u32(a: uint32x2_t, b: uint32x2_t, c: uint32x2_t));
+mk!(vmlaq_s8(a: int8x16_t, b: int8x16_t, c: int8x16_t));
+mk!(vmlaq_s16(a: int16x8_t, b: int16x8_t, c: int16x8_t));
+mk!(vmlaq_s32(a: int32x4_t, b: int32x4_t, c: int32x4_t));
+mk!(vmlaq_u8(a: uint8x16_t, b: uint8x16_t, c: uint8x16_t));
+mk!(vmlaq_u16(a: uint16x8_t, b: uint16x8_t, c: uint16x8_t));
+mk!(vmlaq_u32(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t));
+mk!(vmls_s8(a: int8x8_t, b: int8x8_t, c: int8x8_t));
+mk!(vmls_s16(a: int16x4_t, b: int16x4_t, c: int16x4_t));
+mk!(vmls_s32(a: int32x2_t, b: int32x2_t, c: int32x2_t));
+mk!(vmls_u8(a: uint8x8_t, b: uint8x8_t, c: uint8x8_t));
+mk!(vmls_u16(a: uint16x4_t, b: uint16x4_t, c: uint16x4_t));
+mk!(vmls_u32(a: uint32x2_t, b: uint32x2_t, c: uint32x2_t));
+mk!(vmlsq_s8(a: int8x16_t, b: int8x16_t, c: int8x16_t));
+mk!(vmlsq_s16(a: int16x8_t, b: int16x8_t, c: int16x8_t));
+mk!(vmlsq_s32(a: int32x4_t, b: int32x4_t, c: int32x4_t));
+mk!(vmlsq_u8(a: uint8x16_t, b: uint8x16_t, c: uint8x16_t));
+mk!(vmlsq_u16(a: uint16x8_t, b: uint16x8_t, c: uint16x8_t));
+mk!(vmlsq_u32(a: uint32x4_t, b: uint32x4_t, c: uint32x4_t));
+mk_directed!(vmla_s8_wrapping: vmla_s8(
+    a: int8x8_t = [i8::MAX, i8::MIN, 0, 1, -1, 100, -100, 0],
+    b: int8x8_t = [1, -1, i8::MIN, 16, 16, 2, 2, -1],
+    c: int8x8_t = [1, 1, -1, 8, -8, 14, -14, i8::MIN]
+) == int8x8_t [i8::MIN, i8::MAX, i8::MIN, -127, 127, i8::MIN, i8::MIN, i8::MIN]);
+mk_directed!(vmls_u8_wrapping: vmls_u8(
+    a: uint8x8_t = [0, u8::MAX, 10, 0, 1, 200, 128, 0],
+    b: uint8x8_t = [1, u8::MAX, 2, 16, 1, 10, 16, u8::MAX],
+    c: uint8x8_t = [1, u8::MAX, 5, 16, 2, 20, 8, 1]
+) == uint8x8_t [u8::MAX, 254, 0, 0, u8::MAX, 0, 0, 1]);
+mk!(vmovn_s16(a: int16x8_t));
+mk!(vmovn_s32(a: int32x4_t));
+mk!(vmovn_s64(a: int64x2_t));
+mk!(vmovn_u16(a: uint16x8_t));
+mk!(vmovn_u32(a: uint32x4_t));
+mk!(vmovn_u64(a: uint64x2_t));
+mk_directed!(vmovn_s16_truncating: vmovn_s16(
+    a: int16x8_t = [i16::MAX, i16::MIN, 0x180, -0x180, 127, -128, 128, -129]
+) == int8x8_t [-1, 0, i8::MIN, i8::MIN, i8::MAX, i8::MIN, i8::MIN, i8::MAX]);
+mk!(vmull_s8(a: int8x8_t, b: int8x8_t));
+mk!(vmull_s16(a: int16x4_t, b: int16x4_t));
+mk!(vmull_s32(a: int32x2_t, b: int32x2_t));
+mk!(vmull_u8(a: uint8x8_t, b: uint8x8_t));
+mk!(vmull_u16(a: uint16x4_t, b: uint16x4_t));
+mk!(vmull_u32(a: uint32x2_t, b: uint32x2_t));
+
+// The products of the extreme values only fit in the widened lanes.
+mk_directed!(vmull_s16_extremes: vmull_s16(
+    a: int16x4_t = [i16::MIN, i16::MIN, i16::MAX, -1],
+    b: int16x4_t = [i16::MIN, i16::MAX, i16::MAX, i16::MIN]
+) == int32x4_t [1 << 30, -(1 << 30) + (1 << 15), (1 << 30) - (1 << 16) + 1, 1 << 15]);
+mk_directed!(vmull_s32_extremes: vmull_s32(
+    a: int32x2_t = [i32::MIN, i32::MAX],
+    b: int32x2_t = [i32::MIN, i32::MIN]
+) == int64x2_t [1 << 62, -(1 << 62) + (1 << 31)]);
+mk_directed!(vmull_u8_extremes: vmull_u8(
+    a: uint8x8_t = [u8::MAX, u8::MAX, 0, 1, 128, 2, 16, 255],
+    b: uint8x8_t = [u8::MAX, 1, u8::MAX, 1, 128, 128, 16, 2]
+) == uint16x8_t [65025, 255, 0, 1, 16384, 256, 256, 510]);
+
+mk!(vmvn_s8(a: int8x8_t));
+mk!(vmvnq_s8(a: int8x16_t));
+mk!(vmvn_s16(a: int16x4_t));
+mk!(vmvnq_s16(a: int16x8_t));
+mk!(vmvn_s32(a: int32x2_t));
+mk!(vmvnq_s32(a: int32x4_t));
+mk!(vmvn_u8(a: uint8x8_t));
+mk!(vmvnq_u8(a: uint8x16_t));
+mk!(vmvn_u16(a: uint16x4_t));
+mk!(vmvnq_u16(a: uint16x8_t));
+mk!(vmvn_u32(a: uint32x2_t));
+mk!(vmvnq_u32(a: uint32x4_t));
+mk!(vneg_s8(a: int8x8_t));
+mk!(vnegq_s8(a: int8x16_t));
+mk!(vneg_s16(a: int16x4_t));
+mk!(vnegq_s16(a: int16x8_t));
+mk!(vneg_s32(a: int32x2_t));
+mk!(vnegq_s32(a: int32x4_t));
+mk!(vneg_s64(a: int64x1_t));
+mk!(vnegq_s64(a: int64x2_t));
+mk!(#[float(f32)]vneg_f32(a: float32x2_t));
+mk!(#[float(f32)]vnegq_f32(a: float32x4_t));
+// `#[float]` takes any two NaNs to be equal, but negation is exact on them
+// too: only their sign bit changes, as for zeros.
+#[test]
+fn vnegq_f32_sign_bit() {
+    let a = float32x4_t::new(0.0, -0.0, f32::NAN, f32::from_bits(0xffc0_1234));
+    let model = super::super::models::neon::vnegq_f32(a);
+    let upstream: float32x4_t = unsafe { FunArray::from(upstream::vnegq_f32(a.into())) };
+    for i in 0..4 {
+        assert_eq!(model[i].to_bits(), a[i].to_bits() ^ (1 << 31), "lane {i}");
+        assert_eq!(model[i].to_bits(), upstream[i].to_bits(), "lane {i}");
+    }
+}
+mk!(vorn_s8(a: int8x8_t, b: int8x8_t));
+mk!(vornq_s8(a: int8x16_t, b: int8x16_t));
+mk!(vorn_s16(a: int16x4_t, b: int16x4_t));
+mk!(vornq_s16(a: int16x8_t, b: int16x8_t));
+mk!(vorn_s32(a: int32x2_t, b: int32x2_t));
+mk!(vornq_s32(a: int32x4_t, b: int32x4_t));
+mk!(vorn_s64(a: int64x1_t, b: int64x1_t));
+mk!(vornq_s64(a: int64x2_t, b: int64x2_t));
+mk!(vorn_u8(a: uint8x8_t, b: uint8x8_t));
+mk!(vornq_u8(a: uint8x16_t, b: uint8x16_t));
+mk!(vorn_u16(a: uint16x4_t, b: uint16x4_t));
+mk!(vornq_u16(a: uint16x8_t, b: uint16x8_t));
+mk!(vorn_u32(a: uint32x2_t, b: uint32x2_t));
+mk!(vornq_u32(a: uint32x4_t, b: uint32x4_t));
+mk!(vorn_u64(a: uint64x1_t, b: uint64x1_t));
+mk!(vornq_u64(a: uint64x2_t, b: uint64x2_t));
+mk!(vorr_s8(a: int8x8_t, b: int8x8_t));
+mk!(vorrq_s8(a: int8x16_t, b: int8x16_t));
+mk!(vorr_s16(a: int16x4_t, b: int16x4_t));
+mk!(vorrq_s16(a: int16x8_t, b: int16x8_t));
+mk!(vorr_s32(a: int32x2_t, b: int32x2_t));
+mk!(vorrq_s32(a: int32x4_t, b: int32x4_t));
+mk!(vorr_s64(a: int64x1_t, b: int64x1_t));
+mk!(vorrq_s64(a: int64x2_t, b: int64x2_t));
+mk!(vorr_u8(a: uint8x8_t, b: uint8x8_t));
+mk!(vorrq_u8(a: uint8x16_t, b: uint8x16_t));
+mk!(vorr_u16(a: uint16x4_t, b: uint16x4_t));
+mk!(vorrq_u16(a: uint16x8_t, b: uint16x8_t));
+mk!(vorr_u32(a: uint32x2_t, b: uint32x2_t));
+mk!(vorrq_u32(a: uint32x4_t, b: uint32x4_t));
+mk!(vorr_u64(a: uint64x1_t, b: uint64x1_t));
+mk!(vorrq_u64(a: uint64x2_t, b: uint64x2_t));
+
+// `vbic(a, b)` is `a & !b` and `vorn(a, b)` is `a | !b`: the operand to be
+// negated is the second one, unlike for x86's `andnot`.
+mk_directed!(vbicq_u8_operand_order: vbicq_u8(
+    a: uint8x16_t = [0xff; 16],
+    b: uint8x16_t = [0x0f; 16]
+) == uint8x16_t [0xf0; 16]);
+mk_directed!(vorn_s32_operand_order: vorn_s32(
+    a: int32x2_t = [0, 0],
+    b: int32x2_t = [-1, 0x0f0f]
+) == int32x2_t [0, !0x0f0f]);
+mk!(vpadd_s8(a: int8x8_t, b: int8x8_t));
+mk!(vpaddq_s8(a: int8x16_t, b: int8x16_t));
+mk!(vpadd_s16(a: int16x4_t, b: int16x4_t));
+mk!(vpaddq_s16(a: int16x8_t, b: int16x8_t));
+mk!(vpadd_s32(a: int32x2_t, b: int32x2_t));
+mk!(vpaddq_s32(a: int32x4_t, b: int32x4_t));
+mk!(vpadd_u8(a: uint8x8_t, b: uint8x8_t));
+mk!(vpaddq_u8(a: uint8x16_t, b: uint8x16_t));
+mk!(vpadd_u16(a: uint16x4_t, b: uint16x4_t));
+mk!(vpaddq_u16(a: uint16x8_t, b: uint16x8_t));
+mk!(vpadd_u32(a: uint32x2_t, b: uint32x2_t));
+mk!(vpaddq_u32(a: uint32x4_t, b: uint32x4_t));
+mk!(vpmax_s8(a: int8x8_t, b: int8x8_t));
+mk!(vpmaxq_s8(a: int8x16_t, b: int8x16_t));
+mk!(vpmax_s16(a: int16x4_t, b: int16x4_t));
+mk!(vpmaxq_s16(a: int16x8_t, b: int16x8_t));
+mk!(vpmax_s32(a: int32x2_t, b: int32x2_t));
+mk!(vpmaxq_s32(a: int32x4_t, b: int32x4_t));
+mk!(vpmax_u8(a: uint8x8_t, b: uint8x8_t));
+mk!(vpmaxq_u8(a: uint8x16_t, b: uint8x16_t));
+mk!(vpmax_u16(a: uint16x4_t, b: uint16x4_t));
+mk!(vpmaxq_u16(a: uint16x8_t, b: uint16x8_t));
+mk!(vpmax_u32(a: uint32x2_t, b: uint32x2_t));
+mk!(vpmaxq_u32(a: uint32x4_t, b: uint32x4_t));
+mk!(vpmin_s8(a: int8x8_t, b: int8x8_t));
+mk!(vpminq_s8(a: int8x16_t, b: int8x16_t));
+mk!(vpmin_s16(a: int16x4_t, b: int16x4_t));
+mk!(vpminq_s16(a: int16x8_t, b: int16x8_t));
+mk!(vpmin_s32(a: int32x2_t, b: int32x2_t));
+mk!(vpminq_s32(a: int32x4_t, b: int32x4_t));
+mk!(vpmin_u8(a: uint8x8_t, b: uint8x8_t));
+mk!(vpminq_u8(a: uint8x16_t, b: uint8x16_t));
+mk!(vpmin_u16(a: uint16x4_t, b: uint16x4_t));
+mk!(vpminq_u16(a: uint16x8_t, b: uint16x8_t));
+mk!(vpmin_u32(a: uint32x2_t, b: uint32x2_t));
+mk!(vpminq_u32(a: uint32x4_t, b: uint32x4_t));
+
+// The lower half of the result is made of the pairs of `a`, the upper half of
+// the pairs of `b`.
+mk_directed!(vpaddq_s16_halves: vpaddq_s16(
+    a: int16x8_t = [1, 2, 3, 4, 5, 6, 7, 8],
+    b: int16x8_t = [10, 20, 30, 40, 50, 60, 70, 80]
+) == int16x8_t [3, 7, 11, 15, 30, 70, 110, 150]);
+mk_directed!(vpmax_u8_halves: vpmax_u8(
+    a: uint8x8_t = [1, 200, 3, 2, 0, 0, 255, 254],
+    b: uint8x8_t = [9, 8, 7, 6, 5, 4, 3, 2]
+) == uint8x8_t [200, 3, 0, 255, 9, 7, 5, 3]);
+mk_directed!(vpminq_s32_halves: vpminq_s32(
+    a: int32x4_t = [-1, 1, i32::MIN, i32::MAX],
+    b: int32x4_t = [5, 4, 3, 2]
+) == int32x4_t [-1, i32::MIN, 4, 2]);
+
+mk!(vqadd_s8(a: int8x8_t, b: int8x8_t));
+mk!(vqaddq_s8(a: int8x16_t, b: int8x16_t));
+mk!(vqadd_s16(a: int16x4_t, b: int16x4_t));
+mk!(vqaddq_s16(a: int16x8_t, b: int16x8_t));
+mk!(vqadd_s32(a: int32x2_t, b: int32x2_t));
+mk!(vqaddq_s32(a: int32x4_t, b: int32x4_t));
+mk!(vqadd_s64(a: int64x1_t, b: int64x1_t));
+mk!(vqaddq_s64(a: int64x2_t, b: int64x2_t));
+mk!(vqadd_u8(a: uint8x8_t, b: uint8x8_t));
+mk!(vqaddq_u8(a: uint8x16_t, b: uint8x16_t));
+mk!(vqadd_u16(a: uint16x4_t, b: uint16x4_t));
+mk!(vqaddq_u16(a: uint16x8_t, b: uint16x8_t));
+mk!(vqadd_u32(a: uint32x2_t, b: uint32x2_t));
+mk!(vqaddq_u32(a: uint32x4_t, b: uint32x4_t));
+mk!(vqadd_u64(a: uint64x1_t, b: uint64x1_t));
+mk!(vqaddq_u64(a: uint64x2_t, b: uint64x2_t));
+mk!(vqmovn_s16(a: int16x8_t));
+mk!(vqmovn_s32(a: int32x4_t));
+mk!(vqmovn_s64(a: int64x2_t));
+mk!(vqmovn_u16(a: uint16x8_t));
+mk!(vqmovn_u32(a: uint32x4_t));
+mk!(vqmovn_u64(a: uint64x2_t));
+mk!(vqmovun_s16(a: int16x8_t));
+mk!(vqmovun_s32(a: int32x4_t));
+mk!(vqmovun_s64(a: int64x2_t));
+mk_directed!(vqmovn_s16_saturating: vqmovn_s16(
+    a: int16x8_t = [i16::MAX, i16::MIN, 0x180, -0x180, 127, -128, 128, -129]
+) == int8x8_t [i8::MAX, i8::MIN, i8::MAX, i8::MIN, i8::MAX, i8::MIN, i8::MAX, i8::MIN]);
+mk_directed!(vqmovn_u32_saturating: vqmovn_u32(
+    a: uint32x4_t = [u32::MAX, 0x10000, 0xffff, 0x1234]
+) == uint16x4_t [u16::MAX, u16::MAX, u16::MAX, 0x1234]);
+mk_directed!(vqmovun_s16_saturating: vqmovun_s16(
+    a: int16x8_t = [i16::MAX, i16::MIN, 0x180, -1, 255, 256, 0, 42]
+) == uint8x8_t [u8::MAX, 0, u8::MAX, 0, u8::MAX, u8::MAX, 0, 42]);
+mk!(vqshl_s8(a: int8x8_t, b: int8x8_t));
+mk!(vqshlq_s8(a: int8x16_t, b: int8x16_t));
+mk!(vqshl_s16(a: int16x4_t, b: int16x4_t));
+mk!(vqshlq_s16(a: int16x8_t, b: int16x8_t));
+mk!(vqshl_s32(a: int32x2_t, b: int32x2_t));
+mk!(vqshlq_s32(a: int32x4_t, b: int32x4_t));
+mk!(vqshl_s64(a: int64x1_t, b: int64x1_t));
+mk!(vqshlq_s64(a: int64x2_t, b: int64x2_t));
+mk!(vqshl_u8(a: uint8x8_t, b: int8x8_t));
+mk!(vqshlq_u8(a: uint8x16_t, b: int8x16_t));
+mk!(vqshl_u16(a: uint16x4_t, b: int16x4_t));
+mk!(vqshlq_u16(a: uint16x8_t, b: int16x8_t));
+mk!(vqshl_u32(a: uint32x2_t, b: int32x2_t));
+mk!(vqshlq_u32(a: uint32x4_t, b: int32x4_t));
+mk!(vqshl_u64(a: uint64x1_t, b: int64x1_t));
+mk!(vqshlq_u64(a: uint64x2_t, b: int64x2_t));
+mk_directed!(vqshl_s8_saturating: vqshl_s8(
+    a: int8x8_t = [1, 64, -64, -65, i8::MAX, -1, 0, -100],
+    b: int8x8_t = [6, 1, 1, 1, -1, 100, i8::MAX, -3]
+) == int8x8_t [64, i8::MAX, i8::MIN, i8::MIN, 63, i8::MIN, 0, -13]);
+mk_directed!(vqshl_u8_saturating: vqshl_u8(
+    a: uint8x8_t = [1, 128, u8::MAX, 3, 0, 200, 64, 1],
+    b: int8x8_t = [7, 1, -1, 6, i8::MAX, -8, 2, 8]
+) == uint8x8_t [128, u8::MAX, 127, 192, 0, 0, u8::MAX, u8::MAX]);
+mk_const_sweep!([100]vqshl_n_s8{bits: 3}(a: int8x8_t));
+mk_const_sweep!([100]vqshlq_n_s8{bits: 3}(a: int8x16_t));
+mk_const_sweep!([100]vqshl_n_s16{bits: 4}(a: int16x4_t));
+mk_const_sweep!([100]vqshlq_n_s16{bits: 4}(a: int16x8_t));
+mk_const_sweep!([100]vqshl_n_s32{bits: 5}(a: int32x2_t));
+mk_const_sweep!([100]vqshlq_n_s32{bits: 5}(a: int32x4_t));
+mk_const_sweep!([100]vqshl_n_s64{bits: 6}(a: int64x1_t));
+mk_const_sweep!([100]vqshlq_n_s64{bits: 6}(a: int64x2_t));
+mk_const_sweep!([100]vqshl_n_u8{bits: 3}(a: uint8x8_t));
+mk_const_sweep!([100]vqshlq_n_u8{bits: 3}(a: uint8x16_t));
+mk_const_sweep!([100]vqshl_n_u16{bits: 4}(a: uint16x4_t));
+mk_const_sweep!([100]vqshlq_n_u16{bits: 4}(a: uint16x8_t));
+mk_const_sweep!([100]vqshl_n_u32{bits: 5}(a: uint32x2_t));
+mk_const_sweep!([100]vqshlq_n_u32{bits: 5}(a: uint32x4_t));
+mk_const_sweep!([100]vqshl_n_u64{bits: 6}(a: uint64x1_t));
+mk_const_sweep!([100]vqshlq_n_u64{bits: 6}(a: uint64x2_t));
+mk!(vqsub_s8(a: int8x8_t, b: int8x8_t));
+mk!(vqsubq_s8(a: int8x16_t, b: int8x16_t));
+mk!(vqsub_s16(a: int16x4_t, b: int16x4_t));
+mk!(vqsubq_s16(a: int16x8_t, b: int16x8_t));
+mk!(vqsub_s32(a: int32x2_t, b: int32x2_t));
+mk!(vqsubq_s32(a: int32x4_t, b: int32x4_t));
+mk!(vqsub_s64(a: int64x1_t, b: int64x1_t));
+mk!(vqsubq_s64(a: int64x2_t, b: int64x2_t));
+mk!(vqsub_u8(a: uint8x8_t, b: uint8x8_t));
+mk!(vqsubq_u8(a: uint8x16_t, b: uint8x16_t));
+mk!(vqsub_u16(a: uint16x4_t, b: uint16x4_t));
+mk!(vqsubq_u16(a: uint16x8_t, b: uint16x8_t));
+mk!(vqsub_u32(a: uint32x2_t, b: uint32x2_t));
+mk!(vqsubq_u32(a: uint32x4_t, b: uint32x4_t));
+mk!(vqsub_u64(a: uint64x1_t, b: uint64x1_t));
+mk!(vqsubq_u64(a: uint64x2_t, b: uint64x2_t));
+
+// Signed saturation pins overflows to `MIN`/`MAX`, unsigned saturation clamps to `0`/`MAX`.
+mk_directed!(vqaddq_s16_bounds: vqaddq_s16(
+    a: int16x8_t = [i16::MAX, i16::MAX, i16::MIN, i16::MIN, 1, -1, 0, i16::MAX],
+    b: int16x8_t = [1, i16::MAX, -1, i16::MIN, i16::MAX, i16::MIN, 0, i16::MIN]
+) == int16x8_t [i16::MAX, i16::MAX, i16::MIN, i16::MIN, i16::MAX, i16::MIN, 0, -1]);
+mk_directed!(vqsubq_s16_bounds: vqsubq_s16(
+    a: int16x8_t = [i16::MIN, i16::MIN, i16::MAX, i16::MAX, 0, -1, -2, 5],
+    b: int16x8_t = [1, i16::MAX, -1, i16::MIN, i16::MIN, i16::MAX, i16::MAX, 3]
+) == int16x8_t [i16::MIN, i16::MIN, i16::MAX, i16::MAX, i16::MAX, i16::MIN, i16::MIN, 2]);
+mk_directed!(vqaddq_u8_bounds: vqaddq_u8(
+    a: uint8x16_t = [u8::MAX, u8::MAX, 255, 128, 128, 127, 1, 0, 0, 200, 100, 250, 5, 6, 7, 8],
+    b: uint8x16_t = [1, u8::MAX, 0, 128, 127, 128, 254, 0, 255, 100, 100, 10, 250, 249, 248, 247]
+) == uint8x16_t [u8::MAX, u8::MAX, 255, 255, 255, 255, 255, 0, 255, 255, 200, 255, 255, 255, 255, 255]);
+mk_directed!(vqsubq_u8_bounds: vqsubq_u8(
+    a: uint8x16_t = [0, 0, 1, 1, 128, 127, 255, 255, 0, 10, 100, 200, 5, 6, 7, 8],
+    b: uint8x16_t = [1, u8::MAX, 1, 2, 129, 128, 255, 0, 0, 11, 50, 201, 5, 7, 6, 9]
+) == uint8x16_t [0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 50, 0, 0, 0, 1, 0]);
+mk_directed!(vqadd_s64_bounds: vqadd_s64(
+    a: int64x1_t = [i64::MAX],
+    b: int64x1_t = [i64::MAX]
+) == int64x1_t [i64::MAX]);
+mk_directed!(vqsub_u64_bounds: vqsub_u64(
+    a: uint64x1_t = [0u64],
+    b: uint64x1_t = [u64::MAX]
+) == uint64x1_t [0u64]);
+
+mk!(vqtbl1_s8(a: int8x16_t, b: uint8x8_t));
+mk!(vqtbl1q_s8(a: int8x16_t, b: uint8x16_t));
+mk!(vqtbl1_u8(a: uint8x16_t, b: uint8x8_t));
+mk!(vqtbl1q_u8(a: uint8x16_t, b: uint8x16_t));
+mk!(vqtbx1_s8(a: int8x8_t, b: int8x16_t, c: uint8x8_t));
+mk!(vqtbx1q_s8(a: int8x16_t, b: int8x16_t, c: uint8x16_t));
+mk!(vqtbx1_u8(a: uint8x8_t, b: uint8x16_t, c: uint8x8_t));
+mk!(vqtbx1q_u8(a: uint8x16_t, b: uint8x16_t, c: uint8x16_t));
+mk_directed!(vqtbl1q_u8_out_of_range: vqtbl1q_u8(
+    a: uint8x16_t = [10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25],
+    b: uint8x16_t = [15, 0, 16, 1, 17, 2, 31, 3, 32, 4, 128, 5, u8::MAX, 6, 14, 7]
+) == uint8x16_t [25, 10, 0, 11, 0, 12, 0, 13, 0, 14, 0, 15, 0, 16, 24, 17]);
+mk_directed!(vqtbx1q_u8_out_of_range: vqtbx1q_u8(
+    a: uint8x16_t = [100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 115],
+    b: uint8x16_t = [10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25],
+    c: uint8x16_t = [15, 0, 16, 1, 17, 2, 31, 3, 32, 4, 128, 5, u8::MAX, 6, 14, 7]
+) == uint8x16_t [25, 10, 102, 11, 104, 12, 106, 13, 108, 14, 110, 15, 112, 16, 24, 17]);
+mk!(vrbit_s8(a: int8x8_t));
+mk!(vrbitq_s8(a: int8x16_t));
+mk!(vrbit_u8(a: uint8x8_t));
+mk!(vrbitq_u8(a: uint8x16_t));
+mk_directed!(vrbit_u8_bounds: vrbit_u8(
+    a: uint8x8_t = [0, u8::MAX, 1, 0x80, 0x0f, 0x12, 0xa0, 0x3c]
+) == uint8x8_t [0, u8::MAX, 0x80, 1, 0xf0, 0x48, 0x05, 0x3c]);
+mk!(vrev16_s8(a: int8x8_t));
+mk!(vrev16q_s8(a: int8x16_t));
+mk!(vrev16_u8(a: uint8x8_t));
+mk!(vrev16q_u8(a: uint8x16_t));
+mk!(vrev32_s8(a: int8x8_t));
+mk!(vrev32q_s8(a: int8x16_t));
+mk!(vrev32_u8(a: uint8x8_t));
+mk!(vrev32q_u8(a: uint8x16_t));
+mk!(vrev32_s16(a: int16x4_t));
+mk!(vrev32q_s16(a: int16x8_t));
+mk!(vrev32_u16(a: uint16x4_t));
+mk!(vrev32q_u16(a: uint16x8_t));
+mk!(vrev64_s8(a: int8x8_t));
+mk!(vrev64q_s8(a: int8x16_t));
+mk!(vrev64_u8(a: uint8x8_t));
+mk!(vrev64q_u8(a: uint8x16_t));
+mk!(vrev64_s16(a: int16x4_t));
+mk!(vrev64q_s16(a: int16x8_t));
+mk!(vrev64_u16(a: uint16x4_t));
+mk!(vrev64q_u16(a: uint16x8_t));
+mk!(vrev64_s32(a: int32x2_t));
+mk!(vrev64q_s32(a: int32x4_t));
+mk!(vrev64_u32(a: uint32x2_t));
+mk!(vrev64q_u32(a: uint32x4_t));
+
+// Lanes are reversed within each group of 16, 32 or 64 bits.
+mk_directed!(vrev16q_s8_groups: vrev16q_s8(
+    a: int8x16_t = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+) == int8x16_t [1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 11, 10, 13, 12, 15, 14]);
+mk_directed!(vrev32_u8_groups: vrev32_u8(
+    a: uint8x8_t = [0, 1, 2, 3, 4, 5, 6, 7]
+) == uint8x8_t [3, 2, 1, 0, 7, 6, 5, 4]);
+mk_directed!(vrev64q_u16_groups: vrev64q_u16(
+    a: uint16x8_t = [0, 1, 2, 3, 4, 5, 6, 7]
+) == uint16x8_t [3, 2, 1, 0, 7, 6, 5, 4]);
+mk_directed!(vrev64q_s32_groups: vrev64q_s32(
+    a: int32x4_t = [0, 1, 2, 3]
+) == int32x4_t [1, 0, 3, 2]);
+
+mk!(vset_lane_s8{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>}(a: i8, b: int8x8_t));
+mk!(vsetq_lane_s8{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>}(a: i8, b: int8x16_t));
+mk!(vset_lane_s16{<0>,<1>,<2>,<3>}(a: i16, b: int16x4_t));
+mk!(vsetq_lane_s16{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>}(a: i16, b: int16x8_t));
+mk!(vset_lane_s32{<0>,<1>}(a: i32, b: int32x2_t));
+mk!(vsetq_lane_s32{<0>,<1>,<2>,<3>}(a: i32, b: int32x4_t));
+mk!(vset_lane_s64{<0>}(a: i64, b: int64x1_t));
+mk!(vsetq_lane_s64{<0>,<1>}(a: i64, b: int64x2_t));
+mk!(vset_lane_u8{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>}(a: u8, b: uint8x8_t));
+mk!(vsetq_lane_u8{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>}(a: u8, b: uint8x16_t));
+mk!(vset_lane_u16{<0>,<1>,<2>,<3>}(a: u16, b: uint16x4_t));
+mk!(vsetq_lane_u16{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>}(a: u16, b: uint16x8_t));
+mk!(vset_lane_u32{<0>,<1>}(a: u32, b: uint32x2_t));
+mk!(vsetq_lane_u32{<0>,<1>,<2>,<3>}(a: u32, b: uint32x4_t));
+mk!(vset_lane_u64{<0>}(a: u64, b: uint64x1_t));
+mk!(vsetq_lane_u64{<0>,<1>}(a: u64, b: uint64x2_t));
+mk!(vshl_s8(a: int8x8_t, b: int8x8_t));
+mk!(vshlq_s8(a: int8x16_t, b: int8x16_t));
+mk!(vshl_s16(a: int16x4_t, b: int16x4_t));
+mk!(vshlq_s16(a: int16x8_t, b: int16x8_t));
+mk!(vshl_s32(a: int32x2_t, b: int32x2_t));
+mk!(vshlq_s32(a: int32x4_t, b: int32x4_t));
+mk!(vshl_s64(a: int64x1_t, b: int64x1_t));
+mk!(vshlq_s64(a: int64x2_t, b: int64x2_t));
+mk!(vshl_u8(a: uint8x8_t, b: int8x8_t));
+mk!(vshlq_u8(a: uint8x16_t, b: int8x16_t));
+mk!(vshl_u16(a: uint16x4_t, b: int16x4_t));
+mk!(vshlq_u16(a: uint16x8_t, b: int16x8_t));
+mk!(vshl_u32(a: uint32x2_t, b: int32x2_t));
+mk!(vshlq_u32(a: uint32x4_t, b: int32x4_t));
+mk!(vshl_u64(a: uint64x1_t, b: int64x1_t));
+mk!(vshlq_u64(a: uint64x2_t, b: int64x2_t));
+// Negative amounts shift right, and only the lowest byte of each lane of `b`
+// is used as the amount.
+mk_directed!(vshl_s8_negative: vshl_s8(
+    a: int8x8_t = [i8::MIN, -1, 64, 1, -100, i8::MAX, 5, -5],
+    b: int8x8_t = [-1, -8, -7, 7, i8::MIN, 8, -2, 1]
+) == int8x8_t [-64, -1, 0, i8::MIN, -1, 0, 1, -10]);
+mk_directed!(vshl_u8_negative: vshl_u8(
+    a: uint8x8_t = [128, u8::MAX, 64, 1, 200, u8::MAX, 5, 3],
+    b: int8x8_t = [-1, -8, -7, 7, i8::MIN, 8, -2, 1]
+) == uint8x8_t [64, 0, 0, 128, 0, 0, 1, 6]);
+mk_directed!(vshlq_s16_lowest_byte: vshlq_s16(
+    a: int16x8_t = [1, 1, -4, i16::MIN, 3, -1, 100, 0x4000],
+    b: int16x8_t = [0x101, -0x100, -1, -15, -16, 15, 16, 1]
+) == int16x8_t [2, 1, -2, -1, 0, i16::MIN, 0, i16::MIN]);
+mk_const_sweep!([100]vshl_n_s8{bits: 3}(a: int8x8_t));
+mk_const_sweep!([100]vshlq_n_s8{bits: 3}(a: int8x16_t));
+mk_const_sweep!([100]vshl_n_s16{bits: 4}(a: int16x4_t));
+mk_const_sweep!([100]vshlq_n_s16{bits: 4}(a: int16x8_t));
+mk_const_sweep!([100]vshl_n_s32{bits: 5}(a: int32x2_t));
+mk_const_sweep!([100]vshlq_n_s32{bits: 5}(a: int32x4_t));
+mk_const_sweep!([100]vshl_n_s64{bits: 6}(a: int64x1_t));
+mk_const_sweep!([100]vshlq_n_s64{bits: 6}(a: int64x2_t));
+mk_const_sweep!([100]vshl_n_u8{bits: 3}(a: uint8x8_t));
+mk_const_sweep!([100]vshlq_n_u8{bits: 3}(a: uint8x16_t));
+mk_const_sweep!([100]vshl_n_u16{bits: 4}(a: uint16x4_t));
+mk_const_sweep!([100]vshlq_n_u16{bits: 4}(a: uint16x8_t));
+mk_const_sweep!([100]vshl_n_u32{bits: 5}(a: uint32x2_t));
+mk_const_sweep!([100]vshlq_n_u32{bits: 5}(a: uint32x4_t));
+mk_const_sweep!([100]vshl_n_u64{bits: 6}(a: uint64x1_t));
+mk_const_sweep!([100]vshlq_n_u64{bits: 6}(a: uint64x2_t));
+mk!([100]vshr_n_s8{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>}(a: int8x8_t));
+mk!([100]vshrq_n_s8{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>}(a: int8x16_t));
+mk!([100]vshr_n_s16{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>}(a: int16x4_t));
+mk!([100]vshrq_n_s16{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>}(a: int16x8_t));
+mk!([100]vshr_n_s32{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>}(a: int32x2_t));
+mk!([100]vshrq_n_s32{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>}(a: int32x4_t));
+mk!([100]vshr_n_s64{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>,<33>,<34>,<35>,<36>,<37>,<38>,<39>,<40>,<41>,<42>,<43>,<44>,<45>,<46>,<47>,<48>,<49>,<50>,<51>,<52>,<53>,<54>,<55>,<56>,<57>,<58>,<59>,<60>,<61>,<62>,<63>,<64>}(a: int64x1_t));
+mk!([100]vshrq_n_s64{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>,<33>,<34>,<35>,<36>,<37>,<38>,<39>,<40>,<41>,<42>,<43>,<44>,<45>,<46>,<47>,<48>,<49>,<50>,<51>,<52>,<53>,<54>,<55>,<56>,<57>,<58>,<59>,<60>,<61>,<62>,<63>,<64>}(a: int64x2_t));
+mk!([100]vshr_n_u8{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>}(a: uint8x8_t));
+mk!([100]vshrq_n_u8{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>}(a: uint8x16_t));
+mk!([100]vshr_n_u16{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>}(a: uint16x4_t));
+mk!([100]vshrq_n_u16{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>}(a: uint16x8_t));
+mk!([100]vshr_n_u32{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>}(a: uint32x2_t));
+mk!([100]vshrq_n_u32{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>}(a: uint32x4_t));
+mk!([100]vshr_n_u64{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>,<33>,<34>,<35>,<36>,<37>,<38>,<39>,<40>,<41>,<42>,<43>,<44>,<45>,<46>,<47>,<48>,<49>,<50>,<51>,<52>,<53>,<54>,<55>,<56>,<57>,<58>,<59>,<60>,<61>,<62>,<63>,<64>}(a: uint64x1_t));
+mk!([100]vshrq_n_u64{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>,<33>,<34>,<35>,<36>,<37>,<38>,<39>,<40>,<41>,<42>,<43>,<44>,<45>,<46>,<47>,<48>,<49>,<50>,<51>,<52>,<53>,<54>,<55>,<56>,<57>,<58>,<59>,<60>,<61>,<62>,<63>,<64>}(a: uint64x2_t));
+mk!([100]vshrn_n_s16{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>}(a: int16x8_t));
+mk!([100]vshrn_n_s32{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>}(a: int32x4_t));
+mk!([100]vshrn_n_s64{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>}(a: int64x2_t));
+mk!([100]vshrn_n_u16{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>}(a: uint16x8_t));
+mk!([100]vshrn_n_u32{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>}(a: uint32x4_t));
+mk!([100]vshrn_n_u64{<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>}(a: uint64x2_t));
+mk!(vsub_s16(a: int16x4_t, b: int16x4_t));
+mk!(vsub_s32(a: int32x2_t, b: int32x2_t));
+mk!(vsub_s64(a: int64x1_t, b: int64x1_t));
+mk!(vsub_s8(a: int8x8_t, b: int8x8_t));
+mk!(vsub_u16(a: uint16x4_t, b: uint16x4_t));
+mk!(vsub_u32(a: uint32x2_t, b: uint32x2_t));
+mk!(vsub_u64(a: uint64x1_t, b: uint64x1_t));
+mk!(vsub_u8(a: uint8x8_t, b: uint8x8_t));
+mk!(vsubq_s16(a: int16x8_t, b: int16x8_t));
+mk!(vsubq_s32(a: int32x4_t, b: int32x4_t));
+mk!(vsubq_s64(a: int64x2_t, b: int64x2_t));
+mk!(vsubq_s8(a: int8x16_t, b: int8x16_t));
+mk!(vsubq_u16(a: uint16x8_t, b: uint16x8_t));
+mk!(vsubq_u32(a: uint32x4_t, b: uint32x4_t));
+mk!(vsubq_u64(a: uint64x2_t, b: uint64x2_t));
+mk!(vsubq_u8(a: uint8x16_t, b: uint8x16_t));
+mk!(vtbl1_s8(a: int8x8_t, b: int8x8_t));
+mk!(vtbl1_u8(a: uint8x8_t, b: uint8x8_t));
+mk!(vtbx1_s8(a: int8x8_t, b: int8x8_t, c: int8x8_t));
+mk!(vtbx1_u8(a: uint8x8_t, b: uint8x8_t, c: uint8x8_t));
+// With a single d-register table, the indices from 8 on are out of range, and
+// so are the negative ones of the signed forms.
+mk_directed!(vtbl1_s8_out_of_range: vtbl1_s8(
+    a: int8x8_t = [10, 11, 12, 13, 14, 15, 16, 17],
+    b: int8x8_t = [7, 8, 0, -1, 15, i8::MIN, 3, 16]
+) == int8x8_t [17, 0, 10, 0, 0, 0, 13, 0]);
+mk_directed!(vtbx1_s8_out_of_range: vtbx1_s8(
+    a: int8x8_t = [-1, -2, -3, -4, -5, -6, -7, -8],
+    b: int8x8_t = [10, 11, 12, 13, 14, 15, 16, 17],
+    c: int8x8_t = [7, 8, 0, -1, 15, i8::MIN, 3, 16]
+) == int8x8_t [17, -2, 10, -4, -5, -6, 13, -8]);
+mk_directed!(vtbx1_u8_out_of_range: vtbx1_u8(
+    a: uint8x8_t = [100, 101, 102, 103, 104, 105, 106, 107],
+    b: uint8x8_t = [10, 11, 12, 13, 14, 15, 16, 17],
+    c: uint8x8_t = [7, 8, 0, u8::MAX, 15, 128, 3, 16]
+) == uint8x8_t [17, 101, 10, 103, 104, 105, 13, 107]);
+mk_pair!(vtrn_s8(a: int8x8_t, b: int8x8_t));
+mk_pair!(vtrnq_s8(a: int8x16_t, b: int8x16_t));
+mk_pair!(vtrn_s16(a: int16x4_t, b: int16x4_t));
+mk_pair!(vtrnq_s16(a: int16x8_t, b: int16x8_t));
+mk_pair!(vtrn_s32(a: int32x2_t, b: int32x2_t));
+mk_pair!(vtrnq_s32(a: int32x4_t, b: int32x4_t));
+mk_pair!(vtrn_u8(a: uint8x8_t, b: uint8x8_t));
+mk_pair!(vtrnq_u8(a: uint8x16_t, b: uint8x16_t));
+mk_pair!(vtrn_u16(a: uint16x4_t, b: uint16x4_t));
+mk_pair!(vtrnq_u16(a: uint16x8_t, b: uint16x8_t));
+mk_pair!(vtrn_u32(a: uint32x2_t, b: uint32x2_t));
+mk_pair!(vtrnq_u32(a: uint32x4_t, b: uint32x4_t));
+mk_pair!(vuzp_s8(a: int8x8_t, b: int8x8_t));
+mk_pair!(vuzpq_s8(a: int8x16_t, b: int8x16_t));
+mk_pair!(vuzp_s16(a: int16x4_t, b: int16x4_t));
+mk_pair!(vuzpq_s16(a: int16x8_t, b: int16x8_t));
+mk_pair!(vuzp_s32(a: int32x2_t, b: int32x2_t));
+mk_pair!(vuzpq_s32(a: int32x4_t, b: int32x4_t));
+mk_pair!(vuzp_u8(a: uint8x8_t, b: uint8x8_t));
+mk_pair!(vuzpq_u8(a: uint8x16_t, b: uint8x16_t));
+mk_pair!(vuzp_u16(a: uint16x4_t, b: uint16x4_t));
+mk_pair!(vuzpq_u16(a: uint16x8_t, b: uint16x8_t));
+mk_pair!(vuzp_u32(a: uint32x2_t, b: uint32x2_t));
+mk_pair!(vuzpq_u32(a: uint32x4_t, b: uint32x4_t));
+mk_pair!(vzip_s8(a: int8x8_t, b: int8x8_t));
+mk_pair!(vzipq_s8(a: int8x16_t, b: int8x16_t));
+mk_pair!(vzip_s16(a: int16x4_t, b: int16x4_t));
+mk_pair!(vzipq_s16(a: int16x8_t, b: int16x8_t));
+mk_pair!(vzip_s32(a: int32x2_t, b: int32x2_t));
+mk_pair!(vzipq_s32(a: int32x4_t, b: int32x4_t));
+mk_pair!(vzip_u8(a: uint8x8_t, b: uint8x8_t));
+mk_pair!(vzipq_u8(a: uint8x16_t, b: uint8x16_t));
+mk_pair!(vzip_u16(a: uint16x4_t, b: uint16x4_t));
+mk_pair!(vzipq_u16(a: uint16x8_t, b: uint16x8_t));
+mk_pair!(vzip_u32(a: uint32x2_t, b: uint32x2_t));
+mk_pair!(vzipq_u32(a: uint32x4_t, b: uint32x4_t));
+
+// The first result interleaves the low halves of `a` and `b`, the second one
+// their high halves.
+#[test]
+fn vzip_u8_interleaving() {
+    let a = uint8x8_t::from_fn(|i| i as u8);
+    let b = uint8x8_t::from_fn(|i| 10 + i as u8);
+    let (lo, hi) = super::super::models::neon::vzip_u8(a, b);
+    assert_eq!(lo.as_vec(), [0, 10, 1, 11, 2, 12, 3, 13]);
+    assert_eq!(hi.as_vec(), [4, 14, 5, 15, 6, 16, 7, 17]);
+}
@@ -0,0 +1,174 @@
+//! A report of how many intrinsics are modeled in each of the model files.
+//!
+//! Intrinsics that are not modeled yet are kept in the model files as commented
+//! out functions, preceded by a `// NOTE: Not modeled yet` line. The test in
+//! this module counts, for each file, the public functions and the commented
+//! out ones, and prints a summary (run `cargo test coverage -- --nocapture` to
+//! see it), e.g.
+//!
+//! ```text
+//! avx: 100 modeled, 57 stubbed
+//! ```
+//!
+//! Each file also comes with the number of intrinsics that were modeled when
+//! the table below was last updated. The test fails if a file has fewer modeled
+//! intrinsics than that, which catches a model accidentally being commented
+//! back out. When modeling new intrinsics, bump the numbers accordingly.
+//!
+//! For the x86 model files, the intrinsics are additionally listed by name in
+//! `x86::models::REGISTRY`, with those not modeled yet in
+//! `x86::models::UNMODELED`. The `registry` test checks both lists against the
+//! model files, so they have to be updated along with them.
+
+/// The model files, along with the minimum number of intrinsics modeled in them.
+const MODELS: &[(&str, &str, usize)] = &[
+    ("sse", include_str!("x86/models/sse.rs"), 52),
+    ("sse2", include_str!("x86/models/sse2.rs"), 128),
+    ("ssse3", include_str!("x86/models/ssse3.rs"), 16),
+    ("sse41", include_str!("x86/models/sse41.rs"), 47),
+    ("sse42", include_str!("x86/models/sse42.rs"), 6),
+    ("avx", include_str!("x86/models/avx.rs"), 117),
+    ("avx2", include_str!("x86/models/avx2.rs"), 173),
+    ("avx512f", include_str!("x86/models/avx512f.rs"), 6),
+    ("neon", include_str!("arm_shared/models/neon.rs"), 819),
+];
+
+/// Counts the modeled and the stubbed intrinsics of a model file.
+fn count(source: &str) -> (usize, usize) {
+    let modeled = source
+        .lines()
+        .filter(|line| line.starts_with("pub fn "))
+        .count();
+    let stubbed = source
+        .lines()
+        .filter(|line| line.starts_with("// pub fn "))
+        .count();
+    (modeled, stubbed)
+}
+
+#[test]
+fn coverage() {
+    let mut regressions = vec![];
+    for &(name, source, expected) in MODELS {
+        let (modeled, stubbed) = count(source);
+        println!("{name}: {modeled} modeled, {stubbed} stubbed");
+        if modeled < expected {
+            regressions.push(format!(
+                "{name}: {modeled} modeled, expected at least {expected}"
+            ));
+        }
+    }
+    assert!(
+        regressions.is_empty(),
+        "Fewer intrinsics are modeled than before:\n{}",
+        regressions.join("\n")
+    );
+}
+
+/// Lists, for every 128-bit integer min/max width, the model file it is
+/// modeled in, or that it is missing, and checks that against the table below.
+/// Run `cargo test min_max_inventory -- --nocapture` to see the list. SSE2 only
+/// has `epi16` and `epu8`, the other widths up to 32 bits come with SSE4.1, and
+/// the 64-bit ones with AVX-512, whose 128-bit forms are not modeled yet.
+#[test]
+fn min_max_inventory() {
+    let expected = [
+        ("epi8", Some("sse41")),
+        ("epu8", Some("sse2")),
+        ("epi16", Some("sse2")),
+        ("epu16", Some("sse41")),
+        ("epi32", Some("sse41")),
+        ("epu32", Some("sse41")),
+        ("epi64", None),
+        ("epu64", None),
+    ];
+    let (mut missing, mut mismatches) = (vec![], vec![]);
+    for op in ["min", "max"] {
+        for (width, file) in expected {
+            let intrinsic = format!("_mm_{op}_{width}");
+            let signature = format!("pub fn {intrinsic}(");
+            let modeled_in = MODELS
+                .iter()
+                .find(|(_, source, _)| source.lines().any(|line| line.starts_with(&signature)))
+                .map(|(name, _, _)| *name);
+            match modeled_in {
+                Some(name) => println!("{intrinsic}: modeled in {name}"),
+                None => missing.push(intrinsic.clone()),
+            }
+            if modeled_in != file {
+                mismatches.push(format!(
+                    "{intrinsic}: expected {file:?}, found {modeled_in:?}"
+                ));
+            }
+        }
+    }
+    println!("missing: {}", missing.join(", "));
+    assert!(
+        mismatches.is_empty(),
+        "The min/max inventory changed:\n{}",
+        mismatches.join("\n")
+    );
+}
+
+/// Returns the names of the modeled and of the stubbed intrinsics of a model
+/// file.
+fn intrinsics(source: &str) -> (Vec<&str>, Vec<&str>) {
+    fn name(line: &str) -> &str {
+        line.split(['(', '<']).next().unwrap()
+    }
+    let (mut modeled, mut stubbed) = (vec![], vec![]);
+    for line in source.lines() {
+        if let Some(line) = line.strip_prefix("pub fn ") {
+            modeled.push(name(line));
+        } else if let Some(line) = line.strip_prefix("// pub fn ") {
+            stubbed.push(name(line));
+        }
+    }
+    (modeled, stubbed)
+}
+
+/// Cross-checks the x86 model files against `x86::models::REGISTRY` and
+/// `x86::models::UNMODELED`, so that an intrinsic cannot be dropped from a model
+/// file, or be modeled or stubbed without being registered.
+#[test]
+fn registry() {
+    use super::x86::models::{REGISTRY, UNMODELED};
+    let mut errors = vec![];
+    for &(isa, registered) in REGISTRY {
+        let Some(&(_, source, _)) = MODELS.iter().find(|(name, _, _)| *name == isa) else {
+            errors.push(format!("{isa}: no such model file"));
+            continue;
+        };
+        let unmodeled = UNMODELED
+            .iter()
+            .find(|(name, _)| *name == isa)
+            .map_or(&[][..], |&(_, unmodeled)| unmodeled);
+        let (modeled, stubbed) = intrinsics(source);
+        for &intrinsic in registered {
+            let is_modeled = modeled.contains(&intrinsic);
+            let is_unmodeled = unmodeled.contains(&intrinsic);
+            match (is_modeled, is_unmodeled) {
+                (false, false) => errors.push(format!(
+                    "{isa}: {intrinsic} is neither modeled nor listed as unmodeled"
+                )),
+                (true, true) => errors.push(format!(
+                    "{isa}: {intrinsic} is modeled but still listed as unmodeled"
+                )),
+                _ => {}
+            }
+        }
+        for intrinsic in modeled.iter().chain(&stubbed) {
+            if !registered.contains(intrinsic) {
+                errors.push(format!("{isa}: {intrinsic} is not in the registry"));
+            }
+        }
+        for &intrinsic in unmodeled {
+            if !stubbed.contains(&intrinsic) {
+                errors.push(format!(
+                    "{isa}: {intrinsic} is listed as unmodeled but has no stub"
+                ));
+            }
+        }
+    }
+    assert!(errors.is_empty(), "{}", errors.join("\n"));
+}
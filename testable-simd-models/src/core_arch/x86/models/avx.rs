@@ -24,19 +24,17 @@ use crate::abstractions::utilities::*;
 /// in `a` and `b`.
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm256_add_pd)
-// NOTE: Not modeled yet
-// pub fn _mm256_add_pd(a: __m256d, b: __m256d) -> __m256d {
-//     { transmute(simd_add(a.as_f64x4(), b.as_f64x4())) }
-// }
+pub fn _mm256_add_pd(a: __m256d, b: __m256d) -> __m256d {
+    transmute(simd_fadd(a.as_f64x4(), b.as_f64x4()))
+}
 
 /// Adds packed single-precision (32-bit) floating-point elements in `a` and
 /// `b`.
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm256_add_ps)
-// NOTE: Not modeled yet
-// pub fn _mm256_add_ps(a: __m256, b: __m256) -> __m256 {
-//     { transmute(simd_add(a.as_f32x8(), b.as_f32x8())) }
-// }
+pub fn _mm256_add_ps(a: __m256, b: __m256) -> __m256 {
+    transmute(simd_fadd(a.as_f32x8(), b.as_f32x8()))
+}
 
 /// Computes the bitwise AND of a packed double-precision (64-bit)
 /// floating-point elements in `a` and `b`.
@@ -151,37 +149,33 @@ pub fn _mm256_andnot_ps(a: __m256, b: __m256) -> __m256 {
 /// in `a` and `b`, and returns packed maximum values
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm256_max_pd)
-// NOTE: Not modeled yet
-// pub fn _mm256_max_pd(a: __m256d, b: __m256d) -> __m256d {
-//     { vmaxpd(a, b) }
-// }
+pub fn _mm256_max_pd(a: __m256d, b: __m256d) -> __m256d {
+    transmute(vmaxpd(a.as_f64x4(), b.as_f64x4()))
+}
 
 /// Compares packed single-precision (32-bit) floating-point elements in `a`
 /// and `b`, and returns packed maximum values
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm256_max_ps)
-// NOTE: Not modeled yet
-// pub fn _mm256_max_ps(a: __m256, b: __m256) -> __m256 {
-//     { vmaxps(a, b) }
-// }
+pub fn _mm256_max_ps(a: __m256, b: __m256) -> __m256 {
+    transmute(vmaxps(a.as_f32x8(), b.as_f32x8()))
+}
 
 /// Compares packed double-precision (64-bit) floating-point elements
 /// in `a` and `b`, and returns packed minimum values
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm256_min_pd)
-// NOTE: Not modeled yet
-// pub fn _mm256_min_pd(a: __m256d, b: __m256d) -> __m256d {
-//     { vminpd(a, b) }
-// }
+pub fn _mm256_min_pd(a: __m256d, b: __m256d) -> __m256d {
+    transmute(vminpd(a.as_f64x4(), b.as_f64x4()))
+}
 
 /// Compares packed single-precision (32-bit) floating-point elements in `a`
 /// and `b`, and returns packed minimum values
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm256_min_ps)
-// NOTE: Not modeled yet
-// pub fn _mm256_min_ps(a: __m256, b: __m256) -> __m256 {
-//     { vminps(a, b) }
-// }
+pub fn _mm256_min_ps(a: __m256, b: __m256) -> __m256 {
+    transmute(vminps(a.as_f32x8(), b.as_f32x8()))
+}
 
 /// Multiplies packed double-precision (64-bit) floating-point elements
 /// in `a` and `b`.
@@ -235,19 +229,17 @@ pub fn _mm256_andnot_ps(a: __m256, b: __m256) -> __m256 {
 /// from packed elements in `a`.
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm256_sub_pd)
-// NOTE: Not modeled yet
-// pub fn _mm256_sub_pd(a: __m256d, b: __m256d) -> __m256d {
-//     { simd_sub(a, b) }
-// }
+pub fn _mm256_sub_pd(a: __m256d, b: __m256d) -> __m256d {
+    transmute(simd_fsub(a.as_f64x4(), b.as_f64x4()))
+}
 
 /// Subtracts packed single-precision (32-bit) floating-point elements in `b`
 /// from packed elements in `a`.
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm256_sub_ps)
-// NOTE: Not modeled yet
-// pub fn _mm256_sub_ps(a: __m256, b: __m256) -> __m256 {
-//     { simd_sub(a, b) }
-// }
+pub fn _mm256_sub_ps(a: __m256, b: __m256) -> __m256 {
+    transmute(simd_fsub(a.as_f32x8(), b.as_f32x8()))
+}
 
 /// Computes the division of each of the 8 packed 32-bit floating-point elements
 /// in `a` by the corresponding packed elements in `b`.
@@ -749,6 +741,13 @@ pub fn _mm256_extract_epi32<const INDEX: i32>(a: __m256i) -> i32 {
     static_assert_uimm_bits!(INDEX, 3);
     simd_extract(a.as_i32x8(), INDEX as u32)
 }
+/// Extracts a 64-bit integer from `a`, selected with `INDEX`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm256_extract_epi64)
+pub fn _mm256_extract_epi64<const INDEX: i32>(a: __m256i) -> i64 {
+    static_assert_uimm_bits!(INDEX, 2);
+    simd_extract(a.as_i64x4(), INDEX as u32)
+}
 /// Returns the first element of the input vector of `[8 x i32]`.
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm256_cvtsi256_si32)
@@ -921,10 +920,9 @@ pub fn _mm256_broadcast_ss(f: &f32) -> __m256 {
 /// to all elements of the returned vector.
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm_broadcast_ss)
-// NOTE: Not modeled yet
-// pub fn _mm_broadcast_ss(f: &f32) -> __m128 {
-//     _mm_set1_ps(*f)
-// }
+pub fn _mm_broadcast_ss(f: &f32) -> __m128 {
+    transmute(f32x4::splat(*f))
+}
 /// Broadcasts a double-precision (64-bit) floating-point element from memory
 /// to all elements of the returned vector.
 ///
@@ -1026,6 +1024,119 @@ pub fn _mm256_insert_epi32<const INDEX: i32>(a: __m256i, i: i32) -> __m256i {
     static_assert_uimm_bits!(INDEX, 3);
     transmute(simd_insert(a.as_i32x8(), INDEX as u32, i))
 }
+/// Copies `a` to result, and inserts the 64-bit integer `i` into result
+/// at the location specified by `index`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm256_insert_epi64)
+pub fn _mm256_insert_epi64<const INDEX: i32>(a: __m256i, i: i64) -> __m256i {
+    static_assert_uimm_bits!(INDEX, 2);
+    transmute(simd_insert(a.as_i64x4(), INDEX as u32, i))
+}
+/// Loads 256-bits of integer data from memory into result.
+///
+/// The memory is modeled as the slice `mem_addr`, which has to hold at least
+/// 32 bytes. Since a slice has no alignment requirement, neither does `mem_addr`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm256_loadu_si256)
+pub fn _mm256_loadu_si256(mem_addr: &[u8]) -> __m256i {
+    transmute(u8x32::from_fn(|i| mem_addr[i as usize]))
+}
+/// Stores 256-bits of integer data from `a` into memory.
+///
+/// The memory is modeled as the slice `mem_addr`, which has to hold at least
+/// 32 bytes. Only its first 32 bytes are written to.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm256_storeu_si256)
+pub fn _mm256_storeu_si256(mem_addr: &mut [u8], a: __m256i) {
+    let a = a.as_u8x32();
+    for i in 0..32 {
+        mem_addr[i as usize] = a[i];
+    }
+}
+/// Loads packed double-precision (64-bit) floating-point elements from memory
+/// into result using `mask` (elements are zeroed out when the high bit of the
+/// corresponding element is not set).
+///
+/// The memory is modeled as the slice `mem_addr`. Masked off elements are not
+/// read, so the slice only needs to extend up to the last element selected.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm256_maskload_pd)
+pub fn _mm256_maskload_pd(mem_addr: &[f64], mask: __m256i) -> __m256d {
+    transmute(maskloadpd256(mem_addr, mask.as_i64x4()))
+}
+/// Stores packed double-precision (64-bit) floating-point elements from `a`
+/// into memory using `mask`.
+///
+/// The memory is modeled as the slice `mem_addr`. Masked off elements are left
+/// untouched.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm256_maskstore_pd)
+pub fn _mm256_maskstore_pd(mem_addr: &mut [f64], mask: __m256i, a: __m256d) {
+    maskstorepd256(mem_addr, mask.as_i64x4(), a.as_f64x4())
+}
+/// Loads packed double-precision (64-bit) floating-point elements from memory
+/// into result using `mask` (elements are zeroed out when the high bit of the
+/// corresponding element is not set).
+///
+/// The memory is modeled as the slice `mem_addr`. Masked off elements are not
+/// read, so the slice only needs to extend up to the last element selected.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm_maskload_pd)
+pub fn _mm_maskload_pd(mem_addr: &[f64], mask: __m128i) -> __m128d {
+    transmute(maskloadpd(mem_addr, mask.as_i64x2()))
+}
+/// Stores packed double-precision (64-bit) floating-point elements from `a`
+/// into memory using `mask`.
+///
+/// The memory is modeled as the slice `mem_addr`. Masked off elements are left
+/// untouched.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm_maskstore_pd)
+pub fn _mm_maskstore_pd(mem_addr: &mut [f64], mask: __m128i, a: __m128d) {
+    maskstorepd(mem_addr, mask.as_i64x2(), a.as_f64x2())
+}
+/// Loads packed single-precision (32-bit) floating-point elements from memory
+/// into result using `mask` (elements are zeroed out when the high bit of the
+/// corresponding element is not set).
+///
+/// The memory is modeled as the slice `mem_addr`. Masked off elements are not
+/// read, so the slice only needs to extend up to the last element selected.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm256_maskload_ps)
+pub fn _mm256_maskload_ps(mem_addr: &[f32], mask: __m256i) -> __m256 {
+    transmute(maskloadps256(mem_addr, mask.as_i32x8()))
+}
+/// Stores packed single-precision (32-bit) floating-point elements from `a`
+/// into memory using `mask`.
+///
+/// The memory is modeled as the slice `mem_addr`. Masked off elements are left
+/// untouched.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm256_maskstore_ps)
+pub fn _mm256_maskstore_ps(mem_addr: &mut [f32], mask: __m256i, a: __m256) {
+    maskstoreps256(mem_addr, mask.as_i32x8(), a.as_f32x8())
+}
+/// Loads packed single-precision (32-bit) floating-point elements from memory
+/// into result using `mask` (elements are zeroed out when the high bit of the
+/// corresponding element is not set).
+///
+/// The memory is modeled as the slice `mem_addr`. Masked off elements are not
+/// read, so the slice only needs to extend up to the last element selected.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm_maskload_ps)
+pub fn _mm_maskload_ps(mem_addr: &[f32], mask: __m128i) -> __m128 {
+    transmute(maskloadps(mem_addr, mask.as_i32x4()))
+}
+/// Stores packed single-precision (32-bit) floating-point elements from `a`
+/// into memory using `mask`.
+///
+/// The memory is modeled as the slice `mem_addr`. Masked off elements are left
+/// untouched.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm_maskstore_ps)
+pub fn _mm_maskstore_ps(mem_addr: &mut [f32], mask: __m128i, a: __m128) {
+    maskstoreps(mem_addr, mask.as_i32x4(), a.as_f32x4())
+}
 /// Duplicate odd-indexed single-precision (32-bit) floating-point elements
 /// from `a`, and returns the results.
 ///
@@ -1055,6 +1166,19 @@ pub fn _mm256_moveldup_ps(a: __m256) -> __m256 {
 pub fn _mm256_movedup_pd(a: __m256d) -> __m256d {
     transmute(simd_shuffle(a.as_f64x4(), a.as_f64x4(), [0, 0, 2, 2]))
 }
+/// Moves integer data from a 256-bit integer vector to a 32-byte
+/// aligned memory location. To minimize caching, the data is flagged as
+/// non-temporal (unlikely to be used again soon)
+///
+/// The non-temporal hint only affects caching, not the stored value, so this
+/// is modeled as `_mm256_storeu_si256`: the memory is the slice `mem_addr`,
+/// which has to hold at least 32 bytes, and of which only the first 32 bytes
+/// are written to. The 32-byte alignment required upstream is not modeled.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm256_stream_si256)
+pub fn _mm256_stream_si256(mem_addr: &mut [u8], a: __m256i) {
+    _mm256_storeu_si256(mem_addr, a)
+}
 /// Computes the approximate reciprocal of packed single-precision (32-bit)
 /// floating-point elements in `a`, and returns the results. The maximum
 /// relative error for this approximation is less than 1.5*2^-12.
@@ -1761,7 +1885,7 @@ pub fn _mm256_undefined_ps() -> __m256 {
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.htmlext=_mm256_undefined_pd)
 pub fn _mm256_undefined_pd() -> __m256d {
-    transmute(f32x8::ZERO())
+    transmute(f64x4::ZERO())
 }
 /// Returns vector of type __m256i with with indeterminate elements.
 /// Despite using the word "undefined" (following Intel's naming scheme), this non-deterministically
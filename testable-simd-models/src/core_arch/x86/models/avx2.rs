@@ -714,6 +714,264 @@ pub fn _mm256_hsubs_epi16(a: __m256i, b: __m256i) -> __m256i {
         transmute(phsubsw(a.as_i16x16(), b.as_i16x16()))
     }
 }
+/// Returns values from `slice` at offsets determined by `offsets * scale`,
+/// where `scale` should be 1, 2, 4 or 8.
+///
+/// The memory pointed to upstream is modeled as the slice `slice`; offsets
+/// are in bytes from its start, and reading outside of it panics.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_i32gather_epi32)
+pub fn _mm_i32gather_epi32<const SCALE: i32>(slice: &[i32], offsets: __m128i) -> __m128i {
+    static_assert_imm8_scale!(SCALE);
+    {
+        let zero = i32x4::ZERO();
+        let neg_one = _mm_set1_epi32(-1).as_i32x4();
+        let offsets = offsets.as_i32x4();
+        let r = pgatherdd(zero, &slice_as_bytes(slice), offsets, neg_one, SCALE as i8);
+        transmute(r)
+    }
+}
+/// Returns values from `slice` at offsets determined by `offsets * scale`,
+/// where `scale` should be 1, 2, 4 or 8.
+///
+/// The memory pointed to upstream is modeled as the slice `slice`; offsets
+/// are in bytes from its start, and reading outside of it panics.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_i32gather_epi32)
+pub fn _mm256_i32gather_epi32<const SCALE: i32>(slice: &[i32], offsets: __m256i) -> __m256i {
+    static_assert_imm8_scale!(SCALE);
+    {
+        let zero = i32x8::ZERO();
+        let neg_one = _mm256_set1_epi32(-1).as_i32x8();
+        let offsets = offsets.as_i32x8();
+        let r = vpgatherdd(zero, &slice_as_bytes(slice), offsets, neg_one, SCALE as i8);
+        transmute(r)
+    }
+}
+/// Returns values from `slice` at offsets determined by `offsets * scale`,
+/// where `scale` should be 1, 2, 4 or 8.
+///
+/// The memory pointed to upstream is modeled as the slice `slice`; offsets
+/// are in bytes from its start, and reading outside of it panics.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_i64gather_epi64)
+pub fn _mm256_i64gather_epi64<const SCALE: i32>(slice: &[i64], offsets: __m256i) -> __m256i {
+    static_assert_imm8_scale!(SCALE);
+    {
+        let zero = i64x4::ZERO();
+        let neg_one = _mm256_set1_epi64x(-1).as_i64x4();
+        let offsets = offsets.as_i64x4();
+        let r = vpgatherqq(zero, &slice_as_bytes(slice), offsets, neg_one, SCALE as i8);
+        transmute(r)
+    }
+}
+/// Returns values from `slice` at offsets determined by `offsets * scale`,
+/// where `scale` should be 1, 2, 4 or 8.
+///
+/// The memory pointed to upstream is modeled as the slice `slice`; offsets
+/// are in bytes from its start, and reading outside of it panics.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_i32gather_ps)
+pub fn _mm_i32gather_ps<const SCALE: i32>(slice: &[f32], offsets: __m128i) -> __m128 {
+    static_assert_imm8_scale!(SCALE);
+    {
+        let zero = f32x4::ZERO();
+        let neg_one = _mm_set1_ps(-1.0).as_f32x4();
+        let offsets = offsets.as_i32x4();
+        let r = pgatherdps(zero, &slice_as_bytes(slice), offsets, neg_one, SCALE as i8);
+        transmute(r)
+    }
+}
+/// Returns values from `slice` at offsets determined by `offsets * scale`,
+/// where `scale` should be 1, 2, 4 or 8.
+///
+/// The memory pointed to upstream is modeled as the slice `slice`; offsets
+/// are in bytes from its start, and reading outside of it panics.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_i32gather_ps)
+pub fn _mm256_i32gather_ps<const SCALE: i32>(slice: &[f32], offsets: __m256i) -> __m256 {
+    static_assert_imm8_scale!(SCALE);
+    {
+        let zero = f32x8::ZERO();
+        let neg_one = _mm256_set1_ps(-1.0).as_f32x8();
+        let offsets = offsets.as_i32x8();
+        let r = vpgatherdps(zero, &slice_as_bytes(slice), offsets, neg_one, SCALE as i8);
+        transmute(r)
+    }
+}
+/// Returns values from `slice` at offsets determined by `offsets * scale`,
+/// where `scale` should be 1, 2, 4 or 8.
+///
+/// The memory pointed to upstream is modeled as the slice `slice`; offsets
+/// are in bytes from its start, and reading outside of it panics.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_i64gather_pd)
+pub fn _mm256_i64gather_pd<const SCALE: i32>(slice: &[f64], offsets: __m256i) -> __m256d {
+    static_assert_imm8_scale!(SCALE);
+    {
+        let zero = f64x4::ZERO();
+        let neg_one = _mm256_set1_pd(-1.0).as_f64x4();
+        let offsets = offsets.as_i64x4();
+        let r = vpgatherqpd(zero, &slice_as_bytes(slice), offsets, neg_one, SCALE as i8);
+        transmute(r)
+    }
+}
+/// Returns values from `slice` at offsets determined by `offsets * scale`,
+/// where `scale` should be 1, 2, 4 or 8. If the highest bit of a lane of
+/// `mask` is cleared, the value from `src` in that position is used instead.
+///
+/// Those lanes are not read from `slice` at all, so their offsets may point
+/// outside of it.
+///
+/// The memory pointed to upstream is modeled as the slice `slice`; offsets
+/// are in bytes from its start, and reading outside of it panics.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_i32gather_epi32)
+pub fn _mm_mask_i32gather_epi32<const SCALE: i32>(
+    src: __m128i,
+    slice: &[i32],
+    offsets: __m128i,
+    mask: __m128i,
+) -> __m128i {
+    static_assert_imm8_scale!(SCALE);
+    {
+        let src = src.as_i32x4();
+        let mask = mask.as_i32x4();
+        let offsets = offsets.as_i32x4();
+        let r = pgatherdd(src, &slice_as_bytes(slice), offsets, mask, SCALE as i8);
+        transmute(r)
+    }
+}
+/// Returns values from `slice` at offsets determined by `offsets * scale`,
+/// where `scale` should be 1, 2, 4 or 8. If the highest bit of a lane of
+/// `mask` is cleared, the value from `src` in that position is used instead.
+///
+/// Those lanes are not read from `slice` at all, so their offsets may point
+/// outside of it.
+///
+/// The memory pointed to upstream is modeled as the slice `slice`; offsets
+/// are in bytes from its start, and reading outside of it panics.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_i32gather_epi32)
+pub fn _mm256_mask_i32gather_epi32<const SCALE: i32>(
+    src: __m256i,
+    slice: &[i32],
+    offsets: __m256i,
+    mask: __m256i,
+) -> __m256i {
+    static_assert_imm8_scale!(SCALE);
+    {
+        let src = src.as_i32x8();
+        let mask = mask.as_i32x8();
+        let offsets = offsets.as_i32x8();
+        let r = vpgatherdd(src, &slice_as_bytes(slice), offsets, mask, SCALE as i8);
+        transmute(r)
+    }
+}
+/// Returns values from `slice` at offsets determined by `offsets * scale`,
+/// where `scale` should be 1, 2, 4 or 8. If the highest bit of a lane of
+/// `mask` is cleared, the value from `src` in that position is used instead.
+///
+/// Those lanes are not read from `slice` at all, so their offsets may point
+/// outside of it.
+///
+/// The memory pointed to upstream is modeled as the slice `slice`; offsets
+/// are in bytes from its start, and reading outside of it panics.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_i64gather_epi64)
+pub fn _mm256_mask_i64gather_epi64<const SCALE: i32>(
+    src: __m256i,
+    slice: &[i64],
+    offsets: __m256i,
+    mask: __m256i,
+) -> __m256i {
+    static_assert_imm8_scale!(SCALE);
+    {
+        let src = src.as_i64x4();
+        let mask = mask.as_i64x4();
+        let offsets = offsets.as_i64x4();
+        let r = vpgatherqq(src, &slice_as_bytes(slice), offsets, mask, SCALE as i8);
+        transmute(r)
+    }
+}
+/// Returns values from `slice` at offsets determined by `offsets * scale`,
+/// where `scale` should be 1, 2, 4 or 8. If the highest bit of a lane of
+/// `mask` is cleared, the value from `src` in that position is used instead.
+///
+/// Those lanes are not read from `slice` at all, so their offsets may point
+/// outside of it.
+///
+/// The memory pointed to upstream is modeled as the slice `slice`; offsets
+/// are in bytes from its start, and reading outside of it panics.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_i32gather_ps)
+pub fn _mm_mask_i32gather_ps<const SCALE: i32>(
+    src: __m128,
+    slice: &[f32],
+    offsets: __m128i,
+    mask: __m128,
+) -> __m128 {
+    static_assert_imm8_scale!(SCALE);
+    {
+        let src = src.as_f32x4();
+        let mask = mask.as_f32x4();
+        let offsets = offsets.as_i32x4();
+        let r = pgatherdps(src, &slice_as_bytes(slice), offsets, mask, SCALE as i8);
+        transmute(r)
+    }
+}
+/// Returns values from `slice` at offsets determined by `offsets * scale`,
+/// where `scale` should be 1, 2, 4 or 8. If the highest bit of a lane of
+/// `mask` is cleared, the value from `src` in that position is used instead.
+///
+/// Those lanes are not read from `slice` at all, so their offsets may point
+/// outside of it.
+///
+/// The memory pointed to upstream is modeled as the slice `slice`; offsets
+/// are in bytes from its start, and reading outside of it panics.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_i32gather_ps)
+pub fn _mm256_mask_i32gather_ps<const SCALE: i32>(
+    src: __m256,
+    slice: &[f32],
+    offsets: __m256i,
+    mask: __m256,
+) -> __m256 {
+    static_assert_imm8_scale!(SCALE);
+    {
+        let src = src.as_f32x8();
+        let mask = mask.as_f32x8();
+        let offsets = offsets.as_i32x8();
+        let r = vpgatherdps(src, &slice_as_bytes(slice), offsets, mask, SCALE as i8);
+        transmute(r)
+    }
+}
+/// Returns values from `slice` at offsets determined by `offsets * scale`,
+/// where `scale` should be 1, 2, 4 or 8. If the highest bit of a lane of
+/// `mask` is cleared, the value from `src` in that position is used instead.
+///
+/// Those lanes are not read from `slice` at all, so their offsets may point
+/// outside of it.
+///
+/// The memory pointed to upstream is modeled as the slice `slice`; offsets
+/// are in bytes from its start, and reading outside of it panics.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_i64gather_pd)
+pub fn _mm256_mask_i64gather_pd<const SCALE: i32>(
+    src: __m256d,
+    slice: &[f64],
+    offsets: __m256i,
+    mask: __m256d,
+) -> __m256d {
+    static_assert_imm8_scale!(SCALE);
+    {
+        let src = src.as_f64x4();
+        let mask = mask.as_f64x4();
+        let offsets = offsets.as_i64x4();
+        let r = vpgatherqpd(src, &slice_as_bytes(slice), offsets, mask, SCALE as i8);
+        transmute(r)
+    }
+}
 /// Copies `a` to `dst`, then insert 128 bits (of integer data) from `b` at the
 /// location specified by `IMM1`.
 ///
@@ -748,6 +1006,94 @@ pub fn _mm256_maddubs_epi16(a: __m256i, b: __m256i) -> __m256i {
         transmute(pmaddubsw(a.as_u8x32(), b.as_u8x32()))
     }
 }
+/// Loads packed 32-bit integers from memory pointed by `mem_addr` using `mask`
+/// (elements are zeroed out when the highest bit is not set in the
+/// corresponding element).
+///
+/// The memory is modeled as the slice `mem_addr`. Masked off elements are not
+/// read, so the slice only needs to extend up to the last element selected.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskload_epi32)
+pub fn _mm_maskload_epi32(mem_addr: &[i32], mask: __m128i) -> __m128i {
+    transmute(maskloadd(mem_addr, mask.as_i32x4()))
+}
+/// Loads packed 32-bit integers from memory pointed by `mem_addr` using `mask`
+/// (elements are zeroed out when the highest bit is not set in the
+/// corresponding element).
+///
+/// The memory is modeled as the slice `mem_addr`. Masked off elements are not
+/// read, so the slice only needs to extend up to the last element selected.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskload_epi32)
+pub fn _mm256_maskload_epi32(mem_addr: &[i32], mask: __m256i) -> __m256i {
+    transmute(maskloadd256(mem_addr, mask.as_i32x8()))
+}
+/// Loads packed 64-bit integers from memory pointed by `mem_addr` using `mask`
+/// (elements are zeroed out when the highest bit is not set in the
+/// corresponding element).
+///
+/// The memory is modeled as the slice `mem_addr`. Masked off elements are not
+/// read, so the slice only needs to extend up to the last element selected.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskload_epi64)
+pub fn _mm_maskload_epi64(mem_addr: &[i64], mask: __m128i) -> __m128i {
+    transmute(maskloadq(mem_addr, mask.as_i64x2()))
+}
+/// Loads packed 64-bit integers from memory pointed by `mem_addr` using `mask`
+/// (elements are zeroed out when the highest bit is not set in the
+/// corresponding element).
+///
+/// The memory is modeled as the slice `mem_addr`. Masked off elements are not
+/// read, so the slice only needs to extend up to the last element selected.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskload_epi64)
+pub fn _mm256_maskload_epi64(mem_addr: &[i64], mask: __m256i) -> __m256i {
+    transmute(maskloadq256(mem_addr, mask.as_i64x4()))
+}
+/// Stores packed 32-bit integers from `a` into memory pointed by `mem_addr`
+/// using `mask` (elements are not stored when the highest bit is not set
+/// in the corresponding element).
+///
+/// The memory is modeled as the slice `mem_addr`. Masked off elements are left
+/// untouched.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskstore_epi32)
+pub fn _mm_maskstore_epi32(mem_addr: &mut [i32], mask: __m128i, a: __m128i) {
+    maskstored(mem_addr, mask.as_i32x4(), a.as_i32x4())
+}
+/// Stores packed 32-bit integers from `a` into memory pointed by `mem_addr`
+/// using `mask` (elements are not stored when the highest bit is not set
+/// in the corresponding element).
+///
+/// The memory is modeled as the slice `mem_addr`. Masked off elements are left
+/// untouched.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskstore_epi32)
+pub fn _mm256_maskstore_epi32(mem_addr: &mut [i32], mask: __m256i, a: __m256i) {
+    maskstored256(mem_addr, mask.as_i32x8(), a.as_i32x8())
+}
+/// Stores packed 64-bit integers from `a` into memory pointed by `mem_addr`
+/// using `mask` (elements are not stored when the highest bit is not set
+/// in the corresponding element).
+///
+/// The memory is modeled as the slice `mem_addr`. Masked off elements are left
+/// untouched.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskstore_epi64)
+pub fn _mm_maskstore_epi64(mem_addr: &mut [i64], mask: __m128i, a: __m128i) {
+    maskstoreq(mem_addr, mask.as_i64x2(), a.as_i64x2())
+}
+/// Stores packed 64-bit integers from `a` into memory pointed by `mem_addr`
+/// using `mask` (elements are not stored when the highest bit is not set
+/// in the corresponding element).
+///
+/// The memory is modeled as the slice `mem_addr`. Masked off elements are left
+/// untouched.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskstore_epi64)
+pub fn _mm256_maskstore_epi64(mem_addr: &mut [i64], mask: __m256i, a: __m256i) {
+    maskstoreq256(mem_addr, mask.as_i64x4(), a.as_i64x4())
+}
 /// Compares packed 16-bit integers in `a` and `b`, and returns the packed
 /// maximum values.
 ///
@@ -1079,25 +1425,32 @@ pub fn _mm256_permute2x128_si256<const IMM8: i32>(a: __m256i, b: __m256i) -> __m
 /// control in `imm8`.
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_permute4x64_pd)
-// NOTE: Not modeled yet
-// pub fn _mm256_permute4x64_pd<const IMM8: i32>(a: __m256d) -> __m256d {
-//     static_assert_uimm_bits!(IMM8, 8);
-//     {
-//         transmute(simd_shuffle(
-//             a, _mm256_undefined_pd(), [IMM8 as u32 & 0b11, (IMM8 as u32 >> 2) & 0b11,
-//             (IMM8 as u32 >> 4) & 0b11, (IMM8 as u32 >> 6) & 0b11,],
-//         ))
-//     }
-// }
+pub fn _mm256_permute4x64_pd<const IMM8: i32>(a: __m256d) -> __m256d {
+    static_assert_uimm_bits!(IMM8, 8);
+    {
+        let r: f64x4 = simd_shuffle(
+            a.as_f64x4(),
+            _mm256_undefined_pd().as_f64x4(),
+            [
+                IMM8 as u32 & 0b11,
+                (IMM8 as u32 >> 2) & 0b11,
+                (IMM8 as u32 >> 4) & 0b11,
+                (IMM8 as u32 >> 6) & 0b11,
+            ],
+        );
+        transmute(r)
+    }
+}
 
 /// Shuffles eight 32-bit floating-point elements in `a` across lanes using
 /// the corresponding 32-bit integer index in `idx`.
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_permutevar8x32_ps)
-// NOTE: Not modeled yet
-// pub fn _mm256_permutevar8x32_ps(a: __m256, idx: __m256i) -> __m256 {
-//     { permps(a, idx.as_i32x8()) }
-// }
+pub fn _mm256_permutevar8x32_ps(a: __m256, idx: __m256i) -> __m256 {
+    {
+        transmute(permps(a.as_f32x8(), idx.as_i32x8()))
+    }
+}
 
 /// Computes the absolute differences of packed unsigned 8-bit integers in `a`
 /// and `b`, then horizontally sum each consecutive 8 differences to
@@ -1665,6 +2018,18 @@ pub fn _mm256_srlv_epi64(a: __m256i, count: __m256i) -> __m256i {
         transmute(psrlvq256(a.as_i64x4(), count.as_i64x4()))
     }
 }
+/// Load 256-bits of integer data from memory into dst using a non-temporal memory hint. mem_addr
+/// must be aligned on a 32-byte boundary or a general-protection exception may be generated. To
+/// minimize caching, the data is flagged as non-temporal (unlikely to be used again soon)
+///
+/// The non-temporal hint only affects caching, not the loaded value, so this is
+/// modeled as `_mm256_loadu_si256`: the memory is the slice `mem_addr`, which
+/// has to hold at least 32 bytes. The alignment requirement is not modeled.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_stream_load_si256)
+pub fn _mm256_stream_load_si256(mem_addr: &[u8]) -> __m256i {
+    _mm256_loadu_si256(mem_addr)
+}
 /// Subtract packed 16-bit integers in `b` from packed 16-bit integers in `a`
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_sub_epi16)
@@ -1,4 +1,11 @@
-use crate::abstractions::{bit::MachineInteger, simd::*};
+use crate::abstractions::{
+    bit::{MachineInteger, MachineNumeric},
+    funarr::FunArray,
+    simd::*,
+};
+
+use super::avx_handwritten::{maskload, maskstore};
+use super::sse41_handwritten;
 pub fn phaddw(a: i16x16, b: i16x16) -> i16x16 {
     i16x16::from_fn(|i| {
         if i < 4 {
@@ -82,9 +89,11 @@ pub fn phsubsw(a: i16x16, b: i16x16) -> i16x16 {
         }
     })
 }
+/// Same wrapping as `sse2_handwritten::pmaddwd`, over twice as many lanes.
 pub fn pmaddwd(a: i16x16, b: i16x16) -> i32x8 {
     i32x8::from_fn(|i| {
-        (a[2 * i] as i32) * (b[2 * i] as i32) + (a[2 * i + 1] as i32) * (b[2 * i + 1] as i32)
+        ((a[2 * i] as i32) * (b[2 * i] as i32))
+            .wrapping_add((a[2 * i + 1] as i32) * (b[2 * i + 1] as i32))
     })
 }
 
@@ -247,30 +256,22 @@ pub fn packusdw(a: i32x8, b: i32x8) -> u16x16 {
 }
 
 pub fn psignb(a: i8x32, b: i8x32) -> i8x32 {
-    i8x32::from_fn(|i| {
-        if b[i] < 0 {
-            if a[i] == i8::MIN {
-                a[i]
-            } else {
-                -a[i]
-            }
-        } else if b[i] > 0 {
-            a[i]
+    a.zip_with(b, |a, b| {
+        if b < 0 {
+            a.wrapping_neg()
+        } else if b > 0 {
+            a
         } else {
             0
         }
     })
 }
 pub fn psignw(a: i16x16, b: i16x16) -> i16x16 {
-    i16x16::from_fn(|i| {
-        if b[i] < 0 {
-            if a[i] == i16::MIN {
-                a[i]
-            } else {
-                -a[i]
-            }
-        } else if b[i] > 0 {
-            a[i]
+    a.zip_with(b, |a, b| {
+        if b < 0 {
+            a.wrapping_neg()
+        } else if b > 0 {
+            a
         } else {
             0
         }
@@ -278,15 +279,11 @@ pub fn psignw(a: i16x16, b: i16x16) -> i16x16 {
 }
 
 pub fn psignd(a: i32x8, b: i32x8) -> i32x8 {
-    i32x8::from_fn(|i| {
-        if b[i] < 0 {
-            if a[i] == i32::MIN {
-                a[i]
-            } else {
-                -a[i]
-            }
-        } else if b[i] > 0 {
-            a[i]
+    a.zip_with(b, |a, b| {
+        if b < 0 {
+            a.wrapping_neg()
+        } else if b > 0 {
+            a
         } else {
             0
         }
@@ -320,13 +317,13 @@ pub fn pslld(a: i32x8, count: i32x4) -> i32x8 {
     })
 }
 pub fn psllq(a: i64x4, count: i64x2) -> i64x4 {
-    let count = count[0] as u32;
+    let count = count[0] as u64;
 
     i64x4::from_fn(|i| {
         if count > 63 {
             0
         } else {
-            ((a[i] as u32) << count) as i64
+            ((a[i] as u64) << count) as i64
         }
     })
 }
@@ -355,7 +352,7 @@ pub fn psllvq(a: i64x2, count: i64x2) -> i64x2 {
         if count[i] > 63 || count[i] < 0 {
             0
         } else {
-            ((a[i] as u32) << count[i]) as i64
+            ((a[i] as u64) << count[i]) as i64
         }
     })
 }
@@ -364,44 +361,28 @@ pub fn psllvq256(a: i64x4, count: i64x4) -> i64x4 {
         if count[i] > 63 || count[i] < 0 {
             0
         } else {
-            ((a[i] as u32) << count[i]) as i64
+            ((a[i] as u64) << count[i]) as i64
         }
     })
 }
 
+/// Shifts right by the lower 64 bits of `count`. Unlike the logical shifts,
+/// counts past the lane width are clamped to it, filling the lanes with their
+/// sign bit.
 pub fn psraw(a: i16x16, count: i16x8) -> i16x16 {
     let count = ((count[3] as u16) as u64) * 281474976710656
         + ((count[2] as u16) as u64) * 4294967296
         + ((count[1] as u16) as u64) * 65536
         + ((count[0] as u16) as u64);
 
-    i16x16::from_fn(|i| {
-        if count > 15 {
-            if a[i] < 0 {
-                -1
-            } else {
-                0
-            }
-        } else {
-            a[i] >> count
-        }
-    })
+    i16x16::from_fn(|i| a[i] >> count.min(15))
 }
 
+/// Same as `psraw`, on 32-bit lanes.
 pub fn psrad(a: i32x8, count: i32x4) -> i32x8 {
     let count = ((count[1] as u32) as u64) * 4294967296 + ((count[0] as u32) as u64);
 
-    i32x8::from_fn(|i| {
-        if count > 31 {
-            if a[i] < 0 {
-                -1
-            } else {
-                0
-            }
-        } else {
-            a[i] << count
-        }
-    })
+    i32x8::from_fn(|i| a[i] >> count.min(31))
 }
 
 pub fn psravd(a: i32x4, count: i32x4) -> i32x4 {
@@ -466,7 +447,7 @@ pub fn psrlq(a: i64x4, count: i64x2) -> i64x4 {
         if count > 63 {
             0
         } else {
-            ((a[i] as u32) >> count) as i64
+            ((a[i] as u64) >> count) as i64
         }
     })
 }
@@ -496,7 +477,7 @@ pub fn psrlvq(a: i64x2, count: i64x2) -> i64x2 {
         if count[i] > 63 || count[i] < 0 {
             0
         } else {
-            ((a[i] as u32) >> count[i]) as i64
+            ((a[i] as u64) >> count[i]) as i64
         }
     })
 }
@@ -505,7 +486,7 @@ pub fn psrlvq256(a: i64x4, count: i64x4) -> i64x4 {
         if count[i] > 63 || count[i] < 0 {
             0
         } else {
-            ((a[i] as u32) >> count[i]) as i64
+            ((a[i] as u64) >> count[i]) as i64
         }
     })
 }
@@ -537,32 +518,21 @@ pub fn permd(a: u32x8, b: u32x8) -> u32x8 {
     })
 }
 
-pub fn mpsadbw(a: u8x32, b: u8x32, imm8: i8) -> u16x16 {
-    u16x16::from_fn(|i| {
-        if i < 8 {
-            let a_offset = (((imm8 & 4) >> 2) * 4) as u32;
-            let b_offset = ((imm8 & 3) * 4) as u32;
-            let k = a_offset + i;
-            let l = b_offset;
-            ((a[k].wrapping_abs_diff(b[l]) as i8) as u8 as u16)
-                + ((a[k + 1].wrapping_abs_diff(b[l + 1]) as i8) as u8 as u16)
-                + ((a[k + 2].wrapping_abs_diff(b[l + 2]) as i8) as u8 as u16)
-                + ((a[k + 3].wrapping_abs_diff(b[l + 3]) as i8) as u8 as u16)
-        } else {
-            let i = i - 8;
-            let imm8 = imm8 >> 3;
-            let a_offset = (((imm8 & 4) >> 2) * 4) as u32;
-            let b_offset = ((imm8 & 3) * 4) as u32;
-            let k = a_offset + i;
-            let l = b_offset;
-            ((a[16 + k].wrapping_abs_diff(b[16 + l]) as i8) as u8 as u16)
-                + ((a[16 + k + 1].wrapping_abs_diff(b[16 + l + 1]) as i8) as u8 as u16)
-                + ((a[16 + k + 2].wrapping_abs_diff(b[16 + l + 2]) as i8) as u8 as u16)
-                + ((a[16 + k + 3].wrapping_abs_diff(b[16 + l + 3]) as i8) as u8 as u16)
-        }
+pub fn permps(a: f32x8, b: i32x8) -> f32x8 {
+    f32x8::from_fn(|i| {
+        let id = (b[i] & 0b111) as u32;
+        a[id]
     })
 }
 
+pub fn mpsadbw(a: u8x32, b: u8x32, imm8: i8) -> u16x16 {
+    let imm8 = imm8 as u8;
+    let lane = |v: u8x32, l: u32| u8x16::from_fn(|i| v[16 * l + i]);
+    let lo = sse41_handwritten::mpsadbw(lane(a, 0), lane(b, 0), imm8);
+    let hi = sse41_handwritten::mpsadbw(lane(a, 1), lane(b, 1), imm8 >> 3);
+    u16x16::from_fn(|i| if i < 8 { lo[i] } else { hi[i - 8] })
+}
+
 pub fn vperm2i128(a: i64x4, b: i64x4, imm8: i8) -> i64x4 {
     let a = i128x2::from_fn(|i| {
         ((a[2 * i] as u64 as u128) + ((a[2 * i + 1] as u64 as u128) << 64)) as i128
@@ -597,8 +567,8 @@ pub fn vperm2i128(a: i64x4, b: i64x4, imm8: i8) -> i64x4 {
     })
 }
 pub fn pmulhrsw(a: i16x16, b: i16x16) -> i16x16 {
-    i16x16::from_fn(|i| {
-        let temp = (a[i] as i32) * (b[i] as i32);
+    a.zip_with(b, |a, b| {
+        let temp = (a as i32) * (b as i32);
         let temp = (temp >> 14).wrapping_add(1) >> 1;
         temp as i16
     })
@@ -617,3 +587,89 @@ pub fn psadbw(a: u8x32, b: u8x32) -> u64x4 {
             .wrapping_add(tmp[i * 8 + 7] as u16) as u64
     })
 }
+
+/// Gathers elements of type `T` from `slice`, seen as bytes. Lane `i` is read
+/// at byte offset `offsets[i] * scale` if the highest bit of `mask[i]` is set,
+/// and is taken from `src` otherwise. As in hardware, masked off lanes are not
+/// read at all. Reads outside of `slice` panic.
+fn gather<const N: u32, T: MachineNumeric + Copy, I: Into<i64> + Copy>(
+    src: FunArray<N, T>,
+    slice: &[i8],
+    offsets: FunArray<N, I>,
+    mask: FunArray<N, T>,
+    scale: i8,
+) -> FunArray<N, T> {
+    FunArray::from_fn(|i| {
+        if (mask[i].to_u128() >> (T::BITS - 1)) & 1 == 0 {
+            return src[i];
+        }
+        let bytes = offsets[i]
+            .into()
+            .checked_mul(scale as i64)
+            .and_then(|offset| usize::try_from(offset).ok())
+            .and_then(|offset| slice.get(offset..offset + (T::BITS / 8) as usize))
+            .expect("gather reads outside of the slice");
+        T::from_u128(
+            bytes
+                .iter()
+                .rev()
+                .fold(0, |acc, &byte| (acc << 8) | byte as u8 as u128),
+        )
+    })
+}
+
+pub fn pgatherdd(src: i32x4, slice: &[i8], offsets: i32x4, mask: i32x4, scale: i8) -> i32x4 {
+    gather(src, slice, offsets, mask, scale)
+}
+
+pub fn vpgatherdd(src: i32x8, slice: &[i8], offsets: i32x8, mask: i32x8, scale: i8) -> i32x8 {
+    gather(src, slice, offsets, mask, scale)
+}
+
+pub fn vpgatherqq(src: i64x4, slice: &[i8], offsets: i64x4, mask: i64x4, scale: i8) -> i64x4 {
+    gather(src, slice, offsets, mask, scale)
+}
+
+pub fn pgatherdps(src: f32x4, slice: &[i8], offsets: i32x4, mask: f32x4, scale: i8) -> f32x4 {
+    gather(src, slice, offsets, mask, scale)
+}
+
+pub fn vpgatherdps(src: f32x8, slice: &[i8], offsets: i32x8, mask: f32x8, scale: i8) -> f32x8 {
+    gather(src, slice, offsets, mask, scale)
+}
+
+pub fn vpgatherqpd(src: f64x4, slice: &[i8], offsets: i64x4, mask: f64x4, scale: i8) -> f64x4 {
+    gather(src, slice, offsets, mask, scale)
+}
+
+pub fn maskloadd(slice: &[i32], mask: i32x4) -> i32x4 {
+    maskload(slice, mask)
+}
+
+pub fn maskloadd256(slice: &[i32], mask: i32x8) -> i32x8 {
+    maskload(slice, mask)
+}
+
+pub fn maskloadq(slice: &[i64], mask: i64x2) -> i64x2 {
+    maskload(slice, mask)
+}
+
+pub fn maskloadq256(slice: &[i64], mask: i64x4) -> i64x4 {
+    maskload(slice, mask)
+}
+
+pub fn maskstored(slice: &mut [i32], mask: i32x4, a: i32x4) {
+    maskstore(slice, mask, a)
+}
+
+pub fn maskstored256(slice: &mut [i32], mask: i32x8, a: i32x8) {
+    maskstore(slice, mask, a)
+}
+
+pub fn maskstoreq(slice: &mut [i64], mask: i64x2, a: i64x2) {
+    maskstore(slice, mask, a)
+}
+
+pub fn maskstoreq256(slice: &mut [i64], mask: i64x4, a: i64x4) {
+    maskstore(slice, mask, a)
+}
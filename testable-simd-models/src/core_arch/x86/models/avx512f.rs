@@ -0,0 +1,70 @@
+//! AVX-512 Foundation (AVX-512F)
+//!
+//! This module contains models for AVX-512F intrinsics.
+//! AVX-512F extends AVX2 to 512-bit wide vector registers.
+//!
+//! The references are:
+//!
+//! - [Intel 64 and IA-32 Architectures Software Developer's Manual Volume 2:
+//!   Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! Wikipedia's [AVX-512][wiki_avx512] page provides a quick overview of the
+//! instructions available.
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+//! [wiki_avx512]: https://en.wikipedia.org/wiki/AVX-512
+use crate::abstractions::simd::*;
+use crate::abstractions::utilities::*;
+
+use super::types::*;
+
+/// Adds packed 32-bit integers in `a` and `b`, and returns the results.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_add_epi32)
+pub fn _mm512_add_epi32(a: __m512i, b: __m512i) -> __m512i {
+    {
+        transmute(simd_add(a.as_i32x16(), b.as_i32x16()))
+    }
+}
+/// Computes the bitwise AND of 512 bits (representing integer data) in `a`
+/// and `b`, and returns the result.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_and_si512)
+pub fn _mm512_and_si512(a: __m512i, b: __m512i) -> __m512i {
+    {
+        transmute(simd_and(a.as_i32x16(), b.as_i32x16()))
+    }
+}
+/// Blends packed 32-bit integers from `a` and `b` using control mask `k`, and
+/// returns the results: lane `i` is taken from `b` if bit `i` of `k` is set,
+/// and from `a` otherwise.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_blend_epi32)
+pub fn _mm512_mask_blend_epi32(k: __mmask16, a: __m512i, b: __m512i) -> __m512i {
+    {
+        transmute(simd_select_bitmask(k, b.as_i32x16(), a.as_i32x16()))
+    }
+}
+/// Compares packed 32-bit integers in `a` and `b` for equality, and returns
+/// the results in mask vector `k`: bit `i` is set if lane `i` of `a` and `b`
+/// are equal.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cmpeq_epi32_mask)
+pub fn _mm512_cmpeq_epi32_mask(a: __m512i, b: __m512i) -> __mmask16 {
+    {
+        let m = simd_eq(a.as_i32x16(), b.as_i32x16());
+        simd_bitmask_little!(15, m, u16)
+    }
+}
+/// Broadcasts 32-bit integer `a` to all elements of the returned vector.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_set1_epi32)
+pub fn _mm512_set1_epi32(a: i32) -> __m512i {
+    transmute(i32x16::splat(a))
+}
+/// Returns vector of type `__m512i` with all elements set to zero.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_setzero_si512)
+pub fn _mm512_setzero_si512() -> __m512i {
+    transmute(i32x16::ZERO())
+}
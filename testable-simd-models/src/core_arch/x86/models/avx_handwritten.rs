@@ -1,15 +1,19 @@
-use crate::abstractions::simd::*;
+use crate::abstractions::{bit::MachineNumeric, bitvec::BitVec, funarr::FunArray, simd::*};
 
-pub fn vperm2f128si256(a: i32x8, b: i32x8, imm8: i8) -> i32x8 {
-    let temp = i128x2::from_fn(|i| match (imm8 as u8) >> (i * 4) {
-        0 => (a[4 * i] as i128) + 16 * (a[4 * i + 1] as i128),
-        1 => (a[4 * i + 2] as i128) + 16 * (a[4 * i + 3] as i128),
-        2 => (b[4 * i] as i128) + 16 * (b[4 * i + 1] as i128),
-        3 => (b[4 * i + 2] as i128) + 16 * (b[4 * i + 3] as i128),
-        _ => unreachable!(),
-    });
+use super::sse_handwritten::{max, min};
 
-    i32x8::from_fn(|i| (temp[if i < 4 { 0 } else { 1 }] >> (i % 4)) as i32)
+pub fn vperm2f128si256(a: i32x8, b: i32x8, imm8: i8) -> i32x8 {
+    let (a_lo, a_hi): (BitVec<128>, BitVec<128>) = BitVec::from(a).split_at();
+    let (b_lo, b_hi): (BitVec<128>, BitVec<128>) = BitVec::from(b).split_at();
+    let select = |control: u8| {
+        if control & 0b1000 != 0 {
+            BitVec::ZERO()
+        } else {
+            [a_lo, a_hi, b_lo, b_hi][(control & 0b11) as usize]
+        }
+    };
+    let r: BitVec<256> = select(imm8 as u8).concat(select(imm8 as u8 >> 4));
+    r.into()
 }
 
 pub fn ptestz256(a: i64x4, b: i64x4) -> i32 {
@@ -29,3 +33,81 @@ pub fn ptestc256(a: i64x4, b: i64x4) -> i32 {
         0
     }
 }
+
+pub fn vmaxpd(a: f64x4, b: f64x4) -> f64x4 {
+    max(a, b)
+}
+
+pub fn vmaxps(a: f32x8, b: f32x8) -> f32x8 {
+    max(a, b)
+}
+
+pub fn vminpd(a: f64x4, b: f64x4) -> f64x4 {
+    min(a, b)
+}
+
+pub fn vminps(a: f32x8, b: f32x8) -> f32x8 {
+    min(a, b)
+}
+
+/// Loads lane `i` from `slice[i]` if the highest bit of `mask[i]` is set, and
+/// zeroes it otherwise. Masked off lanes are not read, so `slice` may be shorter
+/// than `N`, as long as the lanes past its end are masked off.
+pub fn maskload<const N: u32, T: MachineNumeric + Copy, M: MachineNumeric + Copy>(
+    slice: &[T],
+    mask: FunArray<N, M>,
+) -> FunArray<N, T> {
+    FunArray::from_fn(|i| {
+        if (mask[i].to_u128() >> (M::BITS - 1)) & 1 == 1 {
+            slice[i as usize]
+        } else {
+            T::from_u128(0)
+        }
+    })
+}
+
+/// Stores lane `i` of `a` to `slice[i]` if the highest bit of `mask[i]` is set.
+/// Masked off lanes are left untouched.
+pub fn maskstore<const N: u32, T: MachineNumeric + Copy, M: MachineNumeric + Copy>(
+    slice: &mut [T],
+    mask: FunArray<N, M>,
+    a: FunArray<N, T>,
+) {
+    for i in 0..N {
+        if (mask[i].to_u128() >> (M::BITS - 1)) & 1 == 1 {
+            slice[i as usize] = a[i];
+        }
+    }
+}
+
+pub fn maskloadpd256(slice: &[f64], mask: i64x4) -> f64x4 {
+    maskload(slice, mask)
+}
+
+pub fn maskstorepd256(slice: &mut [f64], mask: i64x4, a: f64x4) {
+    maskstore(slice, mask, a)
+}
+
+pub fn maskloadpd(slice: &[f64], mask: i64x2) -> f64x2 {
+    maskload(slice, mask)
+}
+
+pub fn maskstorepd(slice: &mut [f64], mask: i64x2, a: f64x2) {
+    maskstore(slice, mask, a)
+}
+
+pub fn maskloadps256(slice: &[f32], mask: i32x8) -> f32x8 {
+    maskload(slice, mask)
+}
+
+pub fn maskstoreps256(slice: &mut [f32], mask: i32x8, a: f32x8) {
+    maskstore(slice, mask, a)
+}
+
+pub fn maskloadps(slice: &[f32], mask: i32x4) -> f32x4 {
+    maskload(slice, mask)
+}
+
+pub fn maskstoreps(slice: &mut [f32], mask: i32x4, a: f32x4) {
+    maskstore(slice, mask, a)
+}
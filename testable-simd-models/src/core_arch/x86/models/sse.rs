@@ -1,8 +1,408 @@
 //! Streaming SIMD Extensions (SSE)
+use super::sse_handwritten::*;
 use super::types::*;
 use crate::abstractions::simd::*;
 use crate::abstractions::utilities::*;
 
+/// Adds the first component of `a` and `b`, the other components are copied
+/// from `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_add_ss)
+pub fn _mm_add_ss(a: __m128, b: __m128) -> __m128 {
+    transmute(simd_insert(
+        a.as_f32x4(),
+        0,
+        _mm_cvtss_f32(a) + _mm_cvtss_f32(b),
+    ))
+}
+
+/// Adds packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_add_ps)
+pub fn _mm_add_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(simd_fadd(a.as_f32x4(), b.as_f32x4()))
+}
+
+/// Subtracts the first component of `b` from `a`, the other components are
+/// copied from `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_sub_ss)
+pub fn _mm_sub_ss(a: __m128, b: __m128) -> __m128 {
+    transmute(simd_insert(
+        a.as_f32x4(),
+        0,
+        _mm_cvtss_f32(a) - _mm_cvtss_f32(b),
+    ))
+}
+
+/// Subtracts packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_sub_ps)
+pub fn _mm_sub_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(simd_fsub(a.as_f32x4(), b.as_f32x4()))
+}
+
+/// Multiplies the first component of `a` and `b`, the other components are
+/// copied from `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mul_ss)
+pub fn _mm_mul_ss(a: __m128, b: __m128) -> __m128 {
+    transmute(simd_insert(
+        a.as_f32x4(),
+        0,
+        _mm_cvtss_f32(a) * _mm_cvtss_f32(b),
+    ))
+}
+
+/// Multiplies packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mul_ps)
+pub fn _mm_mul_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(simd_fmul(a.as_f32x4(), b.as_f32x4()))
+}
+
+/// Divides the first component of `b` by `a`, the other components are
+/// copied from `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_div_ss)
+pub fn _mm_div_ss(a: __m128, b: __m128) -> __m128 {
+    transmute(simd_insert(
+        a.as_f32x4(),
+        0,
+        _mm_cvtss_f32(a) / _mm_cvtss_f32(b),
+    ))
+}
+
+/// Divides packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_div_ps)
+pub fn _mm_div_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(simd_fdiv(a.as_f32x4(), b.as_f32x4()))
+}
+
+/// Returns the approximate reciprocal of the first single-precision
+/// (32-bit) floating-point element in `a`, the other elements are unchanged.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_rcp_ss)
+pub fn _mm_rcp_ss(a: __m128) -> __m128 {
+    transmute(rcpss(a.as_f32x4()))
+}
+
+/// Returns the approximate reciprocal of packed single-precision (32-bit)
+/// floating-point elements in `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_rcp_ps)
+pub fn _mm_rcp_ps(a: __m128) -> __m128 {
+    transmute(rcpps(a.as_f32x4()))
+}
+
+/// Returns the approximate reciprocal square root of the first single-precision
+/// (32-bit) floating-point element in `a`, the other elements are unchanged.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_rsqrt_ss)
+pub fn _mm_rsqrt_ss(a: __m128) -> __m128 {
+    transmute(rsqrtss(a.as_f32x4()))
+}
+
+/// Returns the approximate reciprocal square root of packed single-precision
+/// (32-bit) floating-point elements in `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_rsqrt_ps)
+pub fn _mm_rsqrt_ps(a: __m128) -> __m128 {
+    transmute(rsqrtps(a.as_f32x4()))
+}
+
+/// Compares the first single-precision (32-bit) floating-point element of `a`
+/// and `b`, and return the minimum value in the first element of the return
+/// value, the other elements are copied from `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_min_ss)
+pub fn _mm_min_ss(a: __m128, b: __m128) -> __m128 {
+    transmute(minss(a.as_f32x4(), b.as_f32x4()))
+}
+
+/// Compares packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`, and return the corresponding minimum values.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_min_ps)
+pub fn _mm_min_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(minps(a.as_f32x4(), b.as_f32x4()))
+}
+
+/// Compares the first single-precision (32-bit) floating-point element of `a`
+/// and `b`, and return the maximum value in the first element of the return
+/// value, the other elements are copied from `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_max_ss)
+pub fn _mm_max_ss(a: __m128, b: __m128) -> __m128 {
+    transmute(maxss(a.as_f32x4(), b.as_f32x4()))
+}
+
+/// Compares packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`, and return the corresponding maximum values.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_max_ps)
+pub fn _mm_max_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(maxps(a.as_f32x4(), b.as_f32x4()))
+}
+
+/// Compares the lowest `f32` of both inputs for equal. The lowest 32 bits of
+/// the result will be `0xffffffff` if `a.extract(0)` is equal
+/// `b.extract(0)`, or `0` otherwise. The upper 96 bits of the result are the
+/// upper 96 bits of `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmpeq_ss)
+pub fn _mm_cmpeq_ss(a: __m128, b: __m128) -> __m128 {
+    transmute(cmpss(a.as_f32x4(), b.as_f32x4(), 0))
+}
+
+/// Compares the lowest `f32` of both inputs for less than. The lowest 32 bits of
+/// the result will be `0xffffffff` if `a.extract(0)` is less than
+/// `b.extract(0)`, or `0` otherwise. The upper 96 bits of the result are the
+/// upper 96 bits of `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmplt_ss)
+pub fn _mm_cmplt_ss(a: __m128, b: __m128) -> __m128 {
+    transmute(cmpss(a.as_f32x4(), b.as_f32x4(), 1))
+}
+
+/// Compares the lowest `f32` of both inputs for less than or equal. The lowest 32 bits of
+/// the result will be `0xffffffff` if `a.extract(0)` is less than or equal
+/// `b.extract(0)`, or `0` otherwise. The upper 96 bits of the result are the
+/// upper 96 bits of `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmple_ss)
+pub fn _mm_cmple_ss(a: __m128, b: __m128) -> __m128 {
+    transmute(cmpss(a.as_f32x4(), b.as_f32x4(), 2))
+}
+
+/// Compares the lowest `f32` of both inputs for greater than. The lowest 32 bits of
+/// the result will be `0xffffffff` if `a.extract(0)` is greater than
+/// `b.extract(0)`, or `0` otherwise. The upper 96 bits of the result are the
+/// upper 96 bits of `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmpgt_ss)
+pub fn _mm_cmpgt_ss(a: __m128, b: __m128) -> __m128 {
+    transmute(simd_shuffle(
+        a.as_i32x4(),
+        cmpss(b.as_f32x4(), a.as_f32x4(), 1),
+        [4, 1, 2, 3],
+    ))
+}
+
+/// Compares the lowest `f32` of both inputs for greater than or equal. The lowest 32 bits of
+/// the result will be `0xffffffff` if `a.extract(0)` is greater than or equal
+/// `b.extract(0)`, or `0` otherwise. The upper 96 bits of the result are the
+/// upper 96 bits of `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmpge_ss)
+pub fn _mm_cmpge_ss(a: __m128, b: __m128) -> __m128 {
+    transmute(simd_shuffle(
+        a.as_i32x4(),
+        cmpss(b.as_f32x4(), a.as_f32x4(), 2),
+        [4, 1, 2, 3],
+    ))
+}
+
+/// Compares the lowest `f32` of both inputs for not equal. The lowest 32 bits of
+/// the result will be `0xffffffff` if `a.extract(0)` is not equal
+/// `b.extract(0)`, or `0` otherwise. The upper 96 bits of the result are the
+/// upper 96 bits of `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmpneq_ss)
+pub fn _mm_cmpneq_ss(a: __m128, b: __m128) -> __m128 {
+    transmute(cmpss(a.as_f32x4(), b.as_f32x4(), 4))
+}
+
+/// Compares the lowest `f32` of both inputs for not less than. The lowest 32 bits of
+/// the result will be `0xffffffff` if `a.extract(0)` is not less than
+/// `b.extract(0)`, or `0` otherwise. The upper 96 bits of the result are the
+/// upper 96 bits of `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmpnlt_ss)
+pub fn _mm_cmpnlt_ss(a: __m128, b: __m128) -> __m128 {
+    transmute(cmpss(a.as_f32x4(), b.as_f32x4(), 5))
+}
+
+/// Compares the lowest `f32` of both inputs for not less than or equal. The lowest 32 bits of
+/// the result will be `0xffffffff` if `a.extract(0)` is not less than or equal
+/// `b.extract(0)`, or `0` otherwise. The upper 96 bits of the result are the
+/// upper 96 bits of `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmpnle_ss)
+pub fn _mm_cmpnle_ss(a: __m128, b: __m128) -> __m128 {
+    transmute(cmpss(a.as_f32x4(), b.as_f32x4(), 6))
+}
+
+/// Compares the lowest `f32` of both inputs for not greater than. The lowest 32 bits of
+/// the result will be `0xffffffff` if `a.extract(0)` is not greater than
+/// `b.extract(0)`, or `0` otherwise. The upper 96 bits of the result are the
+/// upper 96 bits of `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmpngt_ss)
+pub fn _mm_cmpngt_ss(a: __m128, b: __m128) -> __m128 {
+    transmute(simd_shuffle(
+        a.as_i32x4(),
+        cmpss(b.as_f32x4(), a.as_f32x4(), 5),
+        [4, 1, 2, 3],
+    ))
+}
+
+/// Compares the lowest `f32` of both inputs for not greater than or equal. The lowest 32 bits of
+/// the result will be `0xffffffff` if `a.extract(0)` is not greater than or equal
+/// `b.extract(0)`, or `0` otherwise. The upper 96 bits of the result are the
+/// upper 96 bits of `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmpnge_ss)
+pub fn _mm_cmpnge_ss(a: __m128, b: __m128) -> __m128 {
+    transmute(simd_shuffle(
+        a.as_i32x4(),
+        cmpss(b.as_f32x4(), a.as_f32x4(), 6),
+        [4, 1, 2, 3],
+    ))
+}
+
+/// Checks if the lowest `f32` of both inputs are ordered. The lowest 32 bits of
+/// the result will be `0xffffffff` if neither of `a.extract(0)` or
+/// `b.extract(0)` is a NaN, or `0` otherwise. The upper 96 bits of the result
+/// are the upper bits of `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmpord_ss)
+pub fn _mm_cmpord_ss(a: __m128, b: __m128) -> __m128 {
+    transmute(cmpss(a.as_f32x4(), b.as_f32x4(), 7))
+}
+
+/// Checks if the lowest `f32` of both inputs are unordered. The lowest 32 bits
+/// of the result will be `0xffffffff` if any of `a.extract(0)` or
+/// `b.extract(0)` is a NaN, or `0` otherwise. The upper 96 bits of the result
+/// are the upper bits of `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmpunord_ss)
+pub fn _mm_cmpunord_ss(a: __m128, b: __m128) -> __m128 {
+    transmute(cmpss(a.as_f32x4(), b.as_f32x4(), 3))
+}
+
+/// Compares each of the four floats in `a` to the corresponding element in `b`.
+/// The result in the output vector will be `0xffffffff` if the input elements
+/// are equal, or `0` otherwise.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmpeq_ps)
+pub fn _mm_cmpeq_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(cmpps(a.as_f32x4(), b.as_f32x4(), 0))
+}
+
+/// Compares each of the four floats in `a` to the corresponding element in `b`.
+/// The result in the output vector will be `0xffffffff` if the input elements
+/// are less than, or `0` otherwise.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmplt_ps)
+pub fn _mm_cmplt_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(cmpps(a.as_f32x4(), b.as_f32x4(), 1))
+}
+
+/// Compares each of the four floats in `a` to the corresponding element in `b`.
+/// The result in the output vector will be `0xffffffff` if the input elements
+/// are less than or equal, or `0` otherwise.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmple_ps)
+pub fn _mm_cmple_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(cmpps(a.as_f32x4(), b.as_f32x4(), 2))
+}
+
+/// Compares each of the four floats in `a` to the corresponding element in `b`.
+/// The result in the output vector will be `0xffffffff` if the input elements
+/// are greater than, or `0` otherwise.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmpgt_ps)
+pub fn _mm_cmpgt_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(cmpps(b.as_f32x4(), a.as_f32x4(), 1))
+}
+
+/// Compares each of the four floats in `a` to the corresponding element in `b`.
+/// The result in the output vector will be `0xffffffff` if the input elements
+/// are greater than or equal, or `0` otherwise.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmpge_ps)
+pub fn _mm_cmpge_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(cmpps(b.as_f32x4(), a.as_f32x4(), 2))
+}
+
+/// Compares each of the four floats in `a` to the corresponding element in `b`.
+/// The result in the output vector will be `0xffffffff` if the input elements
+/// are not equal, or `0` otherwise.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmpneq_ps)
+pub fn _mm_cmpneq_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(cmpps(a.as_f32x4(), b.as_f32x4(), 4))
+}
+
+/// Compares each of the four floats in `a` to the corresponding element in `b`.
+/// The result in the output vector will be `0xffffffff` if the input elements
+/// are not less than, or `0` otherwise.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmpnlt_ps)
+pub fn _mm_cmpnlt_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(cmpps(a.as_f32x4(), b.as_f32x4(), 5))
+}
+
+/// Compares each of the four floats in `a` to the corresponding element in `b`.
+/// The result in the output vector will be `0xffffffff` if the input elements
+/// are not less than or equal, or `0` otherwise.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmpnle_ps)
+pub fn _mm_cmpnle_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(cmpps(a.as_f32x4(), b.as_f32x4(), 6))
+}
+
+/// Compares each of the four floats in `a` to the corresponding element in `b`.
+/// The result in the output vector will be `0xffffffff` if the input elements
+/// are not greater than, or `0` otherwise.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmpngt_ps)
+pub fn _mm_cmpngt_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(cmpps(b.as_f32x4(), a.as_f32x4(), 5))
+}
+
+/// Compares each of the four floats in `a` to the corresponding element in `b`.
+/// The result in the output vector will be `0xffffffff` if the input elements
+/// are not greater than or equal, or `0` otherwise.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmpnge_ps)
+pub fn _mm_cmpnge_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(cmpps(b.as_f32x4(), a.as_f32x4(), 6))
+}
+
+/// Compares each of the four floats in `a` to the corresponding element in `b`.
+/// Returns four floats that have one of two possible bit patterns. The element
+/// in the output vector will be `0xffffffff` if the input elements in `a` and
+/// `b` are ordered (i.e., neither of them is a NaN), or 0 otherwise.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmpord_ps)
+pub fn _mm_cmpord_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(cmpps(b.as_f32x4(), a.as_f32x4(), 7))
+}
+
+/// Compares each of the four floats in `a` to the corresponding element in `b`.
+/// Returns four floats that have one of two possible bit patterns. The element
+/// in the output vector will be `0xffffffff` if the input elements in `a` and
+/// `b` are unordered (i.e., at least on of them is a NaN), or 0 otherwise.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cmpunord_ps)
+pub fn _mm_cmpunord_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(cmpps(b.as_f32x4(), a.as_f32x4(), 3))
+}
+
+/// Extracts the lowest 32 bit float from the input vector.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cvtss_f32)
+pub fn _mm_cvtss_f32(a: __m128) -> f32 {
+    simd_extract(a.as_f32x4(), 0)
+}
+
 /// Returns vector of type __m128 with indeterminate elements.with indetermination elements.
 /// Despite using the word "undefined" (following Intel's naming scheme), this non-deterministically
 /// picks some valid value and is not equivalent to [`mem::MaybeUninit`].
@@ -13,9 +413,123 @@ pub fn _mm_undefined_ps() -> __m128 {
     transmute(f32x4::ZERO())
 }
 
+/// Construct a `__m128` with all element set to `a`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_set1_ps)
+pub fn _mm_set1_ps(a: f32) -> __m128 {
+    _mm_set_ps(a, a, a, a)
+}
+
+/// Construct a `__m128` from four floating point values highest to lowest.
+///
+/// Note that `a` will be the highest 32 bits of the result, and `d` the
+/// lowest. This matches the standard way of writing bit patterns on x86:
+///
+/// ```text
+///  bit    127 .. 96  95 .. 64  63 .. 32  31 .. 0
+///        +---------+---------+---------+---------+
+///        |    a    |    b    |    c    |    d    |   result
+///        +---------+---------+---------+---------+
+/// ```
+///
+/// Alternatively:
+///
+/// ```text
+/// let v = _mm_set_ps(d, c, b, a);
+/// ```
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_set_ps)
+pub fn _mm_set_ps(a: f32, b: f32, c: f32, d: f32) -> __m128 {
+    transmute(f32x4::new(d, c, b, a))
+}
+
+/// Construct a `__m128` from four floating point values lowest to highest.
+///
+/// This matches the memory order of `__m128`, i.e., `a` will be the lowest 32
+/// bits of the result, and `d` the highest.
+///
+/// ```text
+/// assert_eq!(__m128::new(a, b, c, d), _mm_setr_ps(a, b, c, d));
+/// ```
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_setr_ps)
+pub fn _mm_setr_ps(a: f32, b: f32, c: f32, d: f32) -> __m128 {
+    transmute(f32x4::new(a, b, c, d))
+}
+
 /// Construct a `__m128` with all elements initialized to zero.
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_setzero_ps)
 pub fn _mm_setzero_ps() -> __m128 {
     transmute(f32x4::ZERO())
 }
+
+/// Shuffles packed single-precision (32-bit) floating-point elements in `a` and
+/// `b` using `MASK`.
+///
+/// The lower half of result takes values from `a` and the higher half from
+/// `b`. Mask is split to 2 control bits each to index the element from inputs.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_shuffle_ps)
+///
+/// Note that there appears to be a mistake within Intel's Intrinsics Guide.
+/// `_mm_shuffle_ps` is supposed to take an `i32` instead of a `u32`
+/// as is the case for [other shuffle intrinsics](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_shuffle_).
+/// Performing an implicit type conversion between an unsigned integer and a signed integer
+/// does not cause a problem in C, however Rust's commitment to strong typing does not allow this.
+pub fn _mm_shuffle_ps<const MASK: i32>(a: __m128, b: __m128) -> __m128 {
+    static_assert_uimm_bits!(MASK, 8);
+    transmute(simd_shuffle(
+        a.as_f32x4(),
+        b.as_f32x4(),
+        [
+            MASK as u32 & 0b11,
+            (MASK as u32 >> 2) & 0b11,
+            ((MASK as u32 >> 4) & 0b11) + 4,
+            ((MASK as u32 >> 6) & 0b11) + 4,
+        ],
+    ))
+}
+
+/// Unpacks and interleave single-precision (32-bit) floating-point elements
+/// from the higher half of `a` and `b`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_unpackhi_ps)
+pub fn _mm_unpackhi_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(simd_shuffle(a.as_f32x4(), b.as_f32x4(), [2, 6, 3, 7]))
+}
+
+/// Unpacks and interleave single-precision (32-bit) floating-point elements
+/// from the lower half of `a` and `b`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_unpacklo_ps)
+pub fn _mm_unpacklo_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(simd_shuffle(a.as_f32x4(), b.as_f32x4(), [0, 4, 1, 5]))
+}
+
+/// Combine higher half of `a` and `b`. The higher half of `b` occupies the
+/// lower half of result.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_movehl_ps)
+pub fn _mm_movehl_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(simd_shuffle(a.as_f32x4(), b.as_f32x4(), [6, 7, 2, 3]))
+}
+
+/// Combine lower half of `a` and `b`. The lower half of `b` occupies the
+/// higher half of result.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_movelh_ps)
+pub fn _mm_movelh_ps(a: __m128, b: __m128) -> __m128 {
+    transmute(simd_shuffle(a.as_f32x4(), b.as_f32x4(), [0, 1, 4, 5]))
+}
+
+/// Returns a mask of the most significant bit of each element in `a`.
+///
+/// The mask is stored in the 4 least significant bits of the return value.
+/// All other bits are set to `0`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_movemask_ps)
+pub fn _mm_movemask_ps(a: __m128) -> i32 {
+    let mask: i32x4 = simd_lt(transmute(a), i32x4::ZERO());
+    simd_bitmask_little!(3, mask, u8) as i32
+}
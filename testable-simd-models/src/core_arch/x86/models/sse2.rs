@@ -755,6 +755,27 @@ pub fn _mm_setr_epi8(
 pub fn _mm_setzero_si128() -> __m128i {
     transmute(i32x4::ZERO())
 }
+/// Loads 128-bits of integer data from memory into a new vector.
+///
+/// The memory is modeled as the slice `mem_addr`, which has to hold at least
+/// 16 bytes. Since a slice has no alignment requirement, neither does `mem_addr`.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_si128)
+pub fn _mm_loadu_si128(mem_addr: &[u8]) -> __m128i {
+    transmute(u8x16::from_fn(|i| mem_addr[i as usize]))
+}
+/// Stores 128-bits of integer data from `a` into memory.
+///
+/// The memory is modeled as the slice `mem_addr`, which has to hold at least
+/// 16 bytes. Only its first 16 bytes are written to.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_si128)
+pub fn _mm_storeu_si128(mem_addr: &mut [u8], a: __m128i) {
+    let a = a.as_u8x16();
+    for i in 0..16 {
+        mem_addr[i as usize] = a[i];
+    }
+}
 /// Returns a vector where the low element is extracted from `a` and its upper
 /// element is zero.
 ///
@@ -964,10 +985,9 @@ pub fn _mm_unpacklo_epi64(a: __m128i, b: __m128i) -> __m128i {
 /// `b`.
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_add_pd)
-// NOTE: Not modeled yet
-// pub fn _mm_add_pd(a: __m128d, b: __m128d) -> __m128d {
-//     { simd_add(a, b) }
-// }
+pub fn _mm_add_pd(a: __m128d, b: __m128d) -> __m128d {
+    transmute(simd_fadd(a.as_f64x2(), b.as_f64x2()))
+}
 /// Returns a new vector with the low element of `a` replaced by the result of
 /// diving the lower element of `a` by the lower element of `b`.
 ///
@@ -1059,10 +1079,9 @@ pub fn _mm_unpacklo_epi64(a: __m128i, b: __m128i) -> __m128i {
 /// from `a`.
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_sub_pd)
-// NOTE: Not modeled yet
-// pub fn _mm_sub_pd(a: __m128d, b: __m128d) -> __m128d {
-//     { simd_sub(a, b) }
-// }
+pub fn _mm_sub_pd(a: __m128d, b: __m128d) -> __m128d {
+    transmute(simd_fsub(a.as_f64x2(), b.as_f64x2()))
+}
 /// Computes the bitwise AND of packed double-precision (64-bit) floating-point
 /// elements in `a` and `b`.
 ///
@@ -1416,27 +1435,30 @@ pub fn _mm_cvtps_pd(a: __m128) -> __m128d {
 /// to the upper element the return value.
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cvtsd_ss)
-// NOTE: Not modeled yet
-// pub fn _mm_cvtsd_ss(a: __m128, b: __m128d) -> __m128 {
-//     { cvtsd2ss(a, b) }
-// }
+pub fn _mm_cvtsd_ss(a: __m128, b: __m128d) -> __m128 {
+    {
+        let b: f32x2 = simd_cast(b.as_f64x2());
+        transmute(simd_insert(a.as_f32x4(), 0, simd_extract(b, 0)))
+    }
+}
 /// Returns the lower double-precision (64-bit) floating-point element of `a`.
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cvtsd_f64)
-// NOTE: Not modeled yet
-// pub fn _mm_cvtsd_f64(a: __m128d) -> f64 {
-//     { simd_extract(a, 0) }
-// }
+pub fn _mm_cvtsd_f64(a: __m128d) -> f64 {
+    simd_extract(a.as_f64x2(), 0)
+}
 /// Converts the lower single-precision (32-bit) floating-point element in `b`
 /// to a double-precision (64-bit) floating-point element, store the result in
 /// the lower element of the return value, and copies the upper element from `a`
 /// to the upper element the return value.
 ///
 /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cvtss_sd)
-// NOTE: Not modeled yet
-// pub fn _mm_cvtss_sd(a: __m128d, b: __m128) -> __m128d {
-//     { cvtss2sd(a, b) }
-// }
+pub fn _mm_cvtss_sd(a: __m128d, b: __m128) -> __m128d {
+    {
+        let b: f64x4 = simd_cast(b.as_f32x4());
+        transmute(simd_insert(a.as_f64x2(), 0, simd_extract(b, 0)))
+    }
+}
 /// Converts packed double-precision (64-bit) floating-point elements in `a` to
 /// packed 32-bit integers with truncation.
 ///
@@ -20,9 +20,14 @@ pub fn packsswb(a: i16x8, b: i16x8) -> i8x16 {
         }
     })
 }
+/// Multiplies the 16-bit lanes pairwise and adds adjacent products into 32-bit
+/// lanes. Each product fits in an `i32`, but their sum does not when both pairs
+/// are `-32768 * -32768`: the hardware wraps it to `i32::MIN`, so the sum wraps
+/// here as well.
 pub fn pmaddwd(a: i16x8, b: i16x8) -> i32x4 {
     i32x4::from_fn(|i| {
-        (a[2 * i] as i32) * (b[2 * i] as i32) + (a[2 * i + 1] as i32) * (b[2 * i + 1] as i32)
+        ((a[2 * i] as i32) * (b[2 * i] as i32))
+            .wrapping_add((a[2 * i + 1] as i32) * (b[2 * i + 1] as i32))
     })
 }
 pub fn psadbw(a: u8x16, b: u8x16) -> u64x2 {
@@ -77,39 +82,22 @@ pub fn psllq(a: i64x2, count: i64x2) -> i64x2 {
     })
 }
 
+/// Counts of 16 or more are clamped to 15, which fills each lane with its sign
+/// bit, where `psrlw` zeroes it instead.
 pub fn psraw(a: i16x8, count: i16x8) -> i16x8 {
     let count: u64 = ((count[3] as u16) as u64) * 281474976710656
         + ((count[2] as u16) as u64) * 4294967296
         + ((count[1] as u16) as u64) * 65536
         + ((count[0] as u16) as u64);
 
-    i16x8::from_fn(|i| {
-        if count > 15 {
-            if a[i] < 0 {
-                -1
-            } else {
-                0
-            }
-        } else {
-            a[i] >> count
-        }
-    })
+    i16x8::from_fn(|i| a[i] >> count.min(15))
 }
 
+/// Same as `psraw`, with counts clamped to 31.
 pub fn psrad(a: i32x4, count: i32x4) -> i32x4 {
     let count: u64 = ((count[1] as u32) as u64) * 4294967296 + ((count[0] as u32) as u64);
 
-    i32x4::from_fn(|i| {
-        if count > 31 {
-            if a[i] < 0 {
-                -1
-            } else {
-                0
-            }
-        } else {
-            a[i] << count
-        }
-    })
+    i32x4::from_fn(|i| a[i] >> count.min(31))
 }
 
 pub fn psrlw(a: i16x8, count: i16x8) -> i16x8 {
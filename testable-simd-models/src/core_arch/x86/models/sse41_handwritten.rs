@@ -0,0 +1,115 @@
+use crate::abstractions::{funarr::FunArray, simd::*};
+pub fn packusdw(a: i32x4, b: i32x4) -> u16x8 {
+    u16x8::from_fn(|i| {
+        let x = if i < 4 { a[i] } else { b[i - 4] };
+        if x > (u16::MAX as i32) {
+            u16::MAX
+        } else if x < (u16::MIN as i32) {
+            u16::MIN
+        } else {
+            x as u16
+        }
+    })
+}
+
+/// Multiplies the lanes of `a` and `b` selected by the high 4 bits of
+/// `imm8` (the other products are `+0.0`), and sums them up pairwise, as
+/// `(p0 + p1) + (p2 + p3)`. The sum goes to the lanes selected by the low 4
+/// bits of `imm8`, the other lanes are zeroed.
+pub fn dpps(a: f32x4, b: f32x4, imm8: u8) -> f32x4 {
+    let p = f32x4::from_fn(|i| {
+        if imm8 & (0x10 << i) != 0 {
+            a[i] * b[i]
+        } else {
+            0.0
+        }
+    });
+    let sum = (p[0] + p[1]) + (p[2] + p[3]);
+    f32x4::from_fn(|i| if imm8 & (1 << i) != 0 { sum } else { 0.0 })
+}
+
+/// Same as `dpps`, on two lanes: bits 4 and 5 of `imm8` select the products,
+/// and bits 0 and 1 the lanes receiving their sum.
+pub fn dppd(a: f64x2, b: f64x2, imm8: u8) -> f64x2 {
+    let p = f64x2::from_fn(|i| {
+        if imm8 & (0x10 << i) != 0 {
+            a[i] * b[i]
+        } else {
+            0.0
+        }
+    });
+    let sum = p[0] + p[1];
+    f64x2::from_fn(|i| if imm8 & (1 << i) != 0 { sum } else { 0.0 })
+}
+
+/// Rounds the lanes of `x` as selected by `rounding`. Its bits 0 and 1 pick
+/// the direction, unless bit 2 is set, in which case the direction is the one
+/// in `MXCSR.RC`. The models take `MXCSR` to be in its default state, which
+/// rounds to nearest. Bit 3 only suppresses the precision exception, and does
+/// not change the result.
+fn round<const N: u32, T: Float>(x: FunArray<N, T>, rounding: i32) -> FunArray<N, T> {
+    if rounding & 0b100 != 0 {
+        return simd_round_ties_even(x);
+    }
+    match rounding & 0b11 {
+        0 => simd_round_ties_even(x),
+        1 => simd_floor(x),
+        2 => simd_ceil(x),
+        _ => simd_trunc(x),
+    }
+}
+
+pub fn roundpd(a: f64x2, rounding: i32) -> f64x2 {
+    round(a, rounding)
+}
+
+pub fn roundps(a: f32x4, rounding: i32) -> f32x4 {
+    round(a, rounding)
+}
+
+/// Rounds the lowest lane of `b` as `roundpd` does, and takes the upper lane
+/// from `a`.
+pub fn roundsd(a: f64x2, b: f64x2, rounding: i32) -> f64x2 {
+    let r = round(b, rounding);
+    f64x2::from_fn(|i| if i == 0 { r[0] } else { a[i] })
+}
+
+/// Rounds the lowest lane of `b` as `roundps` does, and takes the upper lanes
+/// from `a`.
+pub fn roundss(a: f32x4, b: f32x4, rounding: i32) -> f32x4 {
+    let r = round(b, rounding);
+    f32x4::from_fn(|i| if i == 0 { r[0] } else { a[i] })
+}
+
+/// Returns the minimum of the lanes of `a` in lane 0 and its index in lane 1,
+/// the other lanes being zero. When the minimum appears several times, the
+/// lowest index is returned.
+pub fn phminposuw(a: u16x8) -> u16x8 {
+    let mut index = 0;
+    for i in 1..8 {
+        if a[i] < a[index] {
+            index = i;
+        }
+    }
+    let min = a[index];
+    u16x8::from_fn(|i| match i {
+        0 => min,
+        1 => index as u16,
+        _ => 0,
+    })
+}
+
+/// Sums the absolute differences of eight consecutive quadruplets of `a` with a
+/// single quadruplet of `b`. Bit 2 of `imm8` selects the offset (0 or 4) of the
+/// first quadruplet of `a`, and bits 0-1 select which quadruplet of `b` is used.
+/// Only these three bits are read, the 256-bit version calls this once per
+/// 128-bit lane with the bits of that lane shifted into place.
+pub fn mpsadbw(a: u8x16, b: u8x16, imm8: u8) -> u16x8 {
+    let a_offset = ((imm8 >> 2) & 1) as u32 * 4;
+    let b_offset = (imm8 & 3) as u32 * 4;
+    u16x8::from_fn(|i| {
+        (0..4)
+            .map(|j| a[a_offset + i + j].abs_diff(b[b_offset + j]) as u16)
+            .sum()
+    })
+}
@@ -0,0 +1,148 @@
+use crate::abstractions::{bitvec::BitVec, funarr::FunArray, simd::*};
+
+/// Splits a string operand into its elements, as selected by bits 0 and 1 of
+/// `imm8`: 16 bytes or 8 words, signed or unsigned. The elements come first,
+/// followed by zeros, along with their number.
+fn elements(a: i8x16, imm8: i8) -> (FunArray<16, i32>, u32) {
+    let words = BitVec::from(a).as_i16x8();
+    match imm8 & 0b11 {
+        0b00 => (FunArray::from_fn(|i| a[i] as u8 as i32), 16),
+        0b01 => (
+            FunArray::from_fn(|i| if i < 8 { words[i] as u16 as i32 } else { 0 }),
+            8,
+        ),
+        0b10 => (FunArray::from_fn(|i| a[i] as i32), 16),
+        _ => (
+            FunArray::from_fn(|i| if i < 8 { words[i] as i32 } else { 0 }),
+            8,
+        ),
+    }
+}
+
+/// The length of a string with an implicit length: the index of its first
+/// null element, or the number of elements if there is none.
+fn implicit_length(a: i8x16, imm8: i8) -> u32 {
+    let (a, n) = elements(a, imm8);
+    let mut length = n;
+    for i in (0..n).rev() {
+        if a[i] == 0 {
+            length = i;
+        }
+    }
+    length
+}
+
+/// The length of a string with an explicit length `l`: its absolute value,
+/// saturated to the number of elements.
+fn explicit_length(l: i32, imm8: i8) -> u32 {
+    let n = if imm8 & 1 == 0 { 16 } else { 8 };
+    l.unsigned_abs().min(n)
+}
+
+/// Compares the first `la` elements of `a` with the first `lb` of `b`, with
+/// the aggregation selected by bits 2 and 3 of `imm8`, and returns a bit mask
+/// with one bit per element of `b`, negated as selected by bits 4 and 5:
+///
+/// - equal any: whether `b[j]` is one of the elements of `a`,
+/// - ranges: whether `a[2k] <= b[j] <= a[2k + 1]` for some `k`,
+/// - equal each: whether `a[j] == b[j]`; past the end of both strings, the
+///   elements are taken to be equal,
+/// - equal ordered: whether `a` appears in `b` starting at `j`; `a` is allowed
+///   to run past the last element of the vector.
+///
+/// Elements past the end of a string never compare equal to valid ones.
+fn compare_strings(a: i8x16, la: u32, b: i8x16, lb: u32, imm8: i8) -> u16 {
+    let ((a, n), (b, _)) = (elements(a, imm8), elements(b, imm8));
+    let matches: FunArray<16, bool> = match (imm8 >> 2) & 0b11 {
+        0b00 => FunArray::from_fn(|j| j < lb && (0..la).any(|i| a[i] == b[j])),
+        0b01 => FunArray::from_fn(|j| {
+            j < lb && (0..la / 2).any(|k| a[2 * k] <= b[j] && b[j] <= a[2 * k + 1])
+        }),
+        0b10 => FunArray::from_fn(|j| match (j < la, j < lb) {
+            (true, true) => a[j] == b[j],
+            (false, false) => true,
+            _ => false,
+        }),
+        _ => FunArray::from_fn(|j| {
+            j < n && (0..n - j).all(|k| k >= la || (j + k < lb && a[k] == b[j + k]))
+        }),
+    };
+    let res1 = (0..n).fold(0u16, |res, j| res | ((matches[j] as u16) << j));
+    match (imm8 >> 4) & 0b11 {
+        0b01 => !res1 & (((1u32 << n) - 1) as u16),
+        0b11 => res1 ^ (((1u32 << lb) - 1) as u16),
+        _ => res1,
+    }
+}
+
+/// Spreads the result of `compare_strings` over a vector: either as is in
+/// the lowest bits, or, if bit 6 of `imm8` is set, with each bit expanded
+/// to a whole element.
+fn mask(res: u16, imm8: i8) -> u8x16 {
+    if imm8 & 0x40 == 0 {
+        u8x16::from_fn(|i| if i < 2 { (res >> (8 * i)) as u8 } else { 0 })
+    } else {
+        let size = if imm8 & 1 == 0 { 1 } else { 2 };
+        u8x16::from_fn(|i| {
+            if res & (1 << (i / size)) != 0 {
+                0xff
+            } else {
+                0
+            }
+        })
+    }
+}
+
+pub fn pcmpistrm128(a: i8x16, b: i8x16, imm8: i8) -> u8x16 {
+    let res = compare_strings(
+        a,
+        implicit_length(a, imm8),
+        b,
+        implicit_length(b, imm8),
+        imm8,
+    );
+    mask(res, imm8)
+}
+
+pub fn pcmpestrm128(a: i8x16, la: i32, b: i8x16, lb: i32, imm8: i8) -> u8x16 {
+    let res = compare_strings(
+        a,
+        explicit_length(la, imm8),
+        b,
+        explicit_length(lb, imm8),
+        imm8,
+    );
+    mask(res, imm8)
+}
+
+/// Accumulates the lowest `bits` bits of `v` into `crc`, one bit at a time
+/// from the least significant one, with the bit-reflected Castagnoli
+/// polynomial. Unlike most CRC32-C implementations, the instructions do not
+/// invert `crc` on the way in and out.
+fn crc32c(crc: u32, v: u64, bits: u32) -> u32 {
+    const POLYNOMIAL: u32 = 0x82F6_3B78;
+    let mut crc = crc;
+    for i in 0..bits {
+        let bit = (crc ^ (v >> i) as u32) & 1;
+        crc = (crc >> 1) ^ if bit == 1 { POLYNOMIAL } else { 0 };
+    }
+    crc
+}
+
+pub fn crc32_32_8(crc: u32, v: u8) -> u32 {
+    crc32c(crc, v as u64, 8)
+}
+
+pub fn crc32_32_16(crc: u32, v: u16) -> u32 {
+    crc32c(crc, v as u64, 16)
+}
+
+pub fn crc32_32_32(crc: u32, v: u32) -> u32 {
+    crc32c(crc, v as u64, 32)
+}
+
+/// Only the lowest 32 bits of `crc` are used, and the result is
+/// zero-extended.
+pub fn crc32_64_64(crc: u64, v: u64) -> u64 {
+    crc32c(crc as u32, v, 64) as u64
+}
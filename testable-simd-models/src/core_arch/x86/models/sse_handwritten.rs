@@ -0,0 +1,102 @@
+use crate::abstractions::{funarr::FunArray, simd::*};
+
+/// Picks the lane of `a` if it compares greater than the one of `b`, and the
+/// lane of `b` otherwise: when either lane is a NaN, or both are zeros, the
+/// result is the lane of `b`.
+pub(super) fn max<const N: u32, T: Float>(a: FunArray<N, T>, b: FunArray<N, T>) -> FunArray<N, T> {
+    FunArray::from_fn(|i| if a[i] > b[i] { a[i] } else { b[i] })
+}
+
+/// Like `max`, with the lane of `a` picked if it compares less than the one
+/// of `b`.
+pub(super) fn min<const N: u32, T: Float>(a: FunArray<N, T>, b: FunArray<N, T>) -> FunArray<N, T> {
+    FunArray::from_fn(|i| if a[i] < b[i] { a[i] } else { b[i] })
+}
+
+pub fn maxps(a: f32x4, b: f32x4) -> f32x4 {
+    max(a, b)
+}
+
+pub fn minps(a: f32x4, b: f32x4) -> f32x4 {
+    min(a, b)
+}
+
+/// Like `maxps` on lane 0, with lanes 1 to 3 copied from `a`.
+pub fn maxss(a: f32x4, b: f32x4) -> f32x4 {
+    simd_insert(a, 0, max(a, b)[0])
+}
+
+/// Like `minps` on lane 0, with lanes 1 to 3 copied from `a`.
+pub fn minss(a: f32x4, b: f32x4) -> f32x4 {
+    simd_insert(a, 0, min(a, b)[0])
+}
+
+/// Flushes a subnormal `x` to a zero of the same sign. The approximations below
+/// treat subnormal inputs as zeros, and flush subnormal results to zeros,
+/// regardless of the `MXCSR` settings.
+fn flush(x: f32) -> f32 {
+    if x.is_subnormal() {
+        0.0f32.copysign(x)
+    } else {
+        x
+    }
+}
+
+/// Computes the reciprocal of each lane. The hardware only approximates it,
+/// with a relative error of at most `1.5 * 2^-12`, which this model does not
+/// try to reproduce: it is exact, up to the rounding of `1.0 / x`.
+pub fn rcpps(a: f32x4) -> f32x4 {
+    f32x4::from_fn(|i| flush(1.0 / flush(a[i])))
+}
+
+/// Like `rcpps`, for the reciprocal of the square root of each lane.
+pub fn rsqrtps(a: f32x4) -> f32x4 {
+    f32x4::from_fn(|i| flush(1.0 / flush(a[i]).sqrt()))
+}
+
+/// Like `rcpps` on lane 0, with lanes 1 to 3 copied from `a`.
+pub fn rcpss(a: f32x4) -> f32x4 {
+    simd_insert(a, 0, rcpps(a)[0])
+}
+
+/// Like `rsqrtps` on lane 0, with lanes 1 to 3 copied from `a`.
+pub fn rsqrtss(a: f32x4) -> f32x4 {
+    simd_insert(a, 0, rsqrtps(a)[0])
+}
+
+/// Evaluates one of the 8 comparison predicates of SSE, as encoded in the
+/// immediate of `cmpps`. The ordered predicates (`eq`, `lt`, `le`, `ord`) are
+/// false when either operand is a NaN, and the unordered ones (`unord`, `neq`,
+/// `nlt`, `nle`), being their negations, are true. Greater-than comparisons
+/// have no predicate of their own, they are expressed by swapping the operands.
+pub(super) fn compare<T: Float>(a: T, b: T, imm8: i8) -> bool {
+    let unordered = a.is_nan() || b.is_nan();
+    match imm8 & 7 {
+        0 => a == b,
+        1 => a < b,
+        2 => a <= b,
+        3 => unordered,
+        4 => a != b,
+        5 => unordered || a >= b,
+        6 => unordered || a > b,
+        7 => !unordered,
+        _ => unreachable!(),
+    }
+}
+
+/// Compares the lanes of `a` and `b` with the predicate `imm8` (see
+/// `compare`), giving all ones for true and all zeros for false.
+pub fn cmpps(a: f32x4, b: f32x4, imm8: i8) -> i32x4 {
+    i32x4::from_fn(|i| if compare(a[i], b[i], imm8) { -1 } else { 0 })
+}
+
+/// Like `cmpps` on lane 0, with lanes 1 to 3 copied from `a`.
+pub fn cmpss(a: f32x4, b: f32x4, imm8: i8) -> i32x4 {
+    i32x4::from_fn(|i| {
+        if i == 0 {
+            cmpps(a, b, imm8)[0]
+        } else {
+            a[i].to_bits() as i32
+        }
+    })
+}
@@ -1,6 +1,8 @@
 use super::types::*;
 use super::upstream;
 use crate::abstractions::bitvec::BitVec;
+use crate::abstractions::simd::{f64x4, i64x4};
+use crate::helpers::test::mk_const_sweep;
 use crate::helpers::test::HasRandom;
 
 macro_rules! assert_feq {
@@ -11,7 +13,13 @@ macro_rules! assert_feq {
 
 /// Derives tests for a given intrinsics. Test that a given intrinsics and its model compute the same thing over random values (1000 by default).
 macro_rules! mk {
-    ($([$N:literal])?$name:ident$({$(<$($c:literal),*>),*})?($($x:ident : $ty:ident),*)) => {
+    ($([$N:literal])?$(#[$mode:ident($fty:ty)])?$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
+        mk!(@test[$($N)?, val$(, $mode $fty)?]$name$({$(<$($c),*>),*})?($($x : $ty),*)$(-> $ret)?);
+    };
+    ($([$N:literal])?$(#[$mode:ident($fty:ty)])?$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : &$ty:ident),+)$(-> $ret:ty)?) => {
+        mk!(@test[$($N)?, ref$(, $mode $fty)?]$name$({$(<$($c),*>),*})?($($x : $ty),*)$(-> $ret)?);
+    };
+    (@test[$($N:literal)?, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
         #[test]
         fn $name() {
             #[allow(unused)]
@@ -20,90 +28,83 @@ macro_rules! mk {
                 $(let n: usize = $N;)?
                     n
             };
-            mk!(@[N]$name$($(<$($c),*>)*)?($($x : $ty),*));
+            mk!(@[N, $pass$(, $mode $fty)?]$name$($(<$($c),*>)*)?($($x : $ty),*)$(-> $ret)?);
+        }
+    };
+    (@val $x:ident) => {
+        $x.into()
+    };
+    (@ref $x:ident) => {
+        &$x.into()
+    };
+    (@eq $model:expr, $upstream:expr) => {
+        $model == $upstream
+    };
+    (@eq $model:expr, $upstream:expr, $mode:ident $fty:ty) => {
+        super::mismatched_lanes::<$fty, _>($model, $upstream, mk!(@same $mode)).is_empty()
+    };
+    (@same float) => {
+        super::same_modulo_nan
+    };
+    (@same lanes) => {
+        super::same_bits
+    };
+    (@cmp[$inputs:expr] $model:expr, $upstream:expr) => {
+        assert_eq!($model, $upstream, "Failed with (shrunk) input values: {:?} (seed: {})", $inputs, crate::helpers::test::seed())
+    };
+    (@cmp[$inputs:expr] $model:expr, $upstream:expr, $mode:ident $fty:ty) => {
+        super::assert_lanes_eq::<$fty, _>($model, $upstream, mk!(@same $mode), $inputs)
+    };
+    (@[$N:ident, $pass:ident]$name:ident$(<$($c:tt),*>)?($($x:ident : $ty:ident),*) -> $ret:ty) => {
+        for _ in 0..$N {
+            $(let $x = std::cell::Cell::new($ty::random());)*
+                let fails = || {
+                    $(let $x = $x.get();)*
+                    let model: $ret = super::super::models::avx::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*);
+                    let upstream: $ret = unsafe { upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*) };
+                    model != upstream
+                };
+                if super::shrink(&[$(&$x),*], fails) {
+                    $(let $x = $x.get();)*
+                    let inputs: &[&dyn std::fmt::Debug] = &[$(&$x),*];
+                    let model: $ret = super::super::models::avx::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*);
+                    let upstream: $ret = unsafe { upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*) };
+                    mk!(@cmp[inputs] model, upstream);
+                }
         }
     };
-    (@[$N:ident]$name:ident$(<$($c:literal),*>)?($($x:ident : $ty:ident),*)) => {
+    (@[$N:ident, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident$(<$($c:tt),*>)?($($x:ident : $ty:ident),*)) => {
         for _ in 0..$N {
-            $(let $x = $ty::random();)*
-                assert_eq!(super::super::models::avx::$name$(::<$($c,)*>)?($($x.into(),)*), unsafe {
-                    BitVec::from(upstream::$name$(::<$($c,)*>)?($($x.into(),)*)).into()
-                });
+            $(let $x = std::cell::Cell::new($ty::random());)*
+                let fails = || {
+                    $(let $x = $x.get();)*
+                    !mk!(@eq super::super::models::avx::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*), unsafe {
+                        BitVec::from(upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*)).into()
+                    }$(, $mode $fty)?)
+                };
+                if super::shrink(&[$(&$x),*], fails) {
+                    $(let $x = $x.get();)*
+                    let inputs: &[&dyn std::fmt::Debug] = &[$(&$x),*];
+                    mk!(@cmp[inputs] super::super::models::avx::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*), unsafe {
+                        BitVec::from(upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*)).into()
+                    }$(, $mode $fty)?);
+                }
         }
     };
-    (@[$N:ident]$name:ident<$($c1:literal),*>$(<$($c:literal),*>)*($($x:ident : $ty:ident),*)) => {
+    (@[$N:ident, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident<$($c1:tt),*>$(<$($c:tt),*>)*($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
         let one = || {
-            mk!(@[$N]$name<$($c1),*>($($x : $ty),*));
+            mk!(@[$N, $pass$(, $mode $fty)?]$name<$($c1),*>($($x : $ty),*)$(-> $ret)?);
         };
         one();
-        mk!(@[$N]$name$(<$($c),*>)*($($x : $ty),*));
+        mk!(@[$N, $pass$(, $mode $fty)?]$name$(<$($c),*>)*($($x : $ty),*)$(-> $ret)?);
     }
 }
 mk!(_mm256_blendv_ps(a: __m256, b: __m256, c: __m256));
 
-#[test]
-fn _mm256_movemask_ps() {
-    let n = 1000;
-
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx::_mm256_movemask_ps(a.into()),
-            unsafe { upstream::_mm256_movemask_ps(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-}
-
-#[test]
-fn _mm256_movemask_pd() {
-    let n = 1000;
-
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx::_mm256_movemask_pd(a.into()),
-            unsafe { upstream::_mm256_movemask_pd(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-}
-
-#[test]
-fn _mm256_testz_si256() {
-    let n = 1000;
-
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        let b: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx::_mm256_testz_si256(a.into(), b.into()),
-            unsafe { upstream::_mm256_testz_si256(a.into(), b.into()) },
-            "Failed with input values: {:?}, {:?}",
-            a,
-            b
-        );
-    }
-}
-
-#[test]
-fn _mm256_testc_si256() {
-    let n = 1000;
-
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        let b: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx::_mm256_testc_si256(a.into(), b.into()),
-            unsafe { upstream::_mm256_testc_si256(a.into(), b.into()) },
-            "Failed with input values: {:?}, {:?}",
-            a,
-            b
-        );
-    }
-}
+mk!(_mm256_movemask_ps(a: __m256) -> i32);
+mk!(_mm256_movemask_pd(a: __m256d) -> i32);
+mk!(_mm256_testz_si256(a: __m256i, b: __m256i) -> i32);
+mk!(_mm256_testc_si256(a: __m256i, b: __m256i) -> i32);
 
 #[test]
 fn _mm256_cvtsd_f64() {
@@ -118,19 +119,34 @@ fn _mm256_cvtsd_f64() {
     }
 }
 
-#[test]
-fn _mm256_cvtsi256_si32() {
-    let n = 1000;
+mk!(_mm256_cvtsi256_si32(a: __m256i) -> i32);
+mk_const_sweep!([100]_mm256_extract_epi32{bits: 3}(a: __m256i) -> i32);
+mk_const_sweep!([100]_mm256_extract_epi64{bits: 2}(a: __m256i) -> i64);
+mk_const_sweep!([100]_mm256_insert_epi8{bits: 5}(a: __m256i, i: i8));
+mk_const_sweep!([100]_mm256_insert_epi16{bits: 4}(a: __m256i, i: i16));
+mk_const_sweep!([100]_mm256_insert_epi32{bits: 3}(a: __m256i, i: i32));
+mk_const_sweep!([100]_mm256_insert_epi64{bits: 2}(a: __m256i, i: i64));
 
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx::_mm256_cvtsi256_si32(a.into()),
-            unsafe { upstream::_mm256_cvtsi256_si32(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
+/// The 64-bit lanes are extracted as they are, negative ones included, and an
+/// inserted lane only replaces the one at `INDEX`.
+#[test]
+fn _mm256_extract_insert_epi64_signs() {
+    use super::super::models::avx;
+    let lanes = [i64::MIN, -1, 1, i64::MAX];
+    let a: __m256i = BitVec::from(i64x4::from_fn(|i| lanes[i as usize]));
+    macro_rules! check {
+        ($($index:literal),*) => {$(
+            let lane = avx::_mm256_extract_epi64::<$index>(a);
+            assert_eq!(lane, lanes[$index]);
+            assert_eq!(lane, unsafe { upstream::_mm256_extract_epi64::<$index>(a.into()) });
+            let r = avx::_mm256_insert_epi64::<$index>(a, -2);
+            assert_eq!(r, unsafe { BitVec::from(upstream::_mm256_insert_epi64::<$index>(a.into(), -2)) });
+            let mut expected = lanes;
+            expected[$index] = -2;
+            assert_eq!(r.to_vec::<i64>(), expected);
+        )*};
     }
+    check!(0, 1, 2, 3);
 }
 
 #[test]
@@ -223,6 +239,62 @@ mk!(_mm256_setr_epi64x(a: i64, b: i64, c: i64, d: i64));
 mk!(_mm256_set1_pd(a: f64));
 mk!(_mm256_set1_ps(a: f32));
 
+/// As for `_mm_set_pd`, reversing the arguments of `_mm256_set_pd` gives
+/// `_mm256_setr_pd`, bit for bit.
+#[test]
+fn _mm256_set_pd_order() {
+    use super::super::models::avx;
+    for _ in 0..1000 {
+        let [a, b, c, d] = [0; 4].map(|_| f64::from_bits(u64::random()));
+        let set = avx::_mm256_set_pd(a, b, c, d);
+        assert_eq!(set, avx::_mm256_setr_pd(d, c, b, a));
+        assert_eq!(set.to_vec::<u64>(), [d, c, b, a].map(f64::to_bits));
+        assert_eq!(set, unsafe {
+            BitVec::from(upstream::_mm256_set_pd(a, b, c, d))
+        });
+        assert_eq!(set, unsafe {
+            BitVec::from(upstream::_mm256_setr_pd(d, c, b, a))
+        });
+    }
+}
+mk!(_mm256_broadcast_ss(f: &f32));
+mk!(_mm_broadcast_ss(f: &f32));
+mk!(_mm256_broadcast_ps(a: &__m128));
+mk!(_mm256_broadcast_pd(a: &__m128d));
+mk_const_sweep!([100]_mm256_permute2f128_si256{bits: 8}(a: __m256i, b: __m256i));
+
+mk!(#[float(f64)]_mm256_add_pd(a: __m256d, b: __m256d));
+mk!(#[float(f32)]_mm256_add_ps(a: __m256, b: __m256));
+mk!(#[float(f64)]_mm256_sub_pd(a: __m256d, b: __m256d));
+mk!(#[float(f32)]_mm256_sub_ps(a: __m256, b: __m256));
+mk!(#[float(f64)]_mm256_max_pd(a: __m256d, b: __m256d));
+mk!(#[float(f32)]_mm256_max_ps(a: __m256, b: __m256));
+mk!(#[float(f64)]_mm256_min_pd(a: __m256d, b: __m256d));
+mk!(#[float(f32)]_mm256_min_ps(a: __m256, b: __m256));
+
+/// When both lanes are zeros, or either is a NaN, the minimum is the lane of
+/// the second operand, so swapping the operands changes the sign of the zeros
+/// in the result. `eq_modulo_nan` has to tell the two zeros apart for this to
+/// be checked at all.
+#[test]
+fn _mm256_min_pd_signed_zeros() {
+    use super::super::models::avx;
+    let a: __m256d = BitVec::from(f64x4::new(0.0, -0.0, f64::NAN, -1.0));
+    let b: __m256d = BitVec::from(f64x4::new(-0.0, 0.0, 1.0, f64::NAN));
+    for (a, b) in [(a, b), (b, a)] {
+        let r = avx::_mm256_min_pd(a, b);
+        assert!(super::eq_modulo_nan::<f64, _>(r, b), "{r:?} != {b:?}");
+        let upstream = unsafe { BitVec::from(upstream::_mm256_min_pd(a.into(), b.into())) };
+        assert!(
+            super::eq_modulo_nan::<f64, _>(r, upstream),
+            "{r:?} != {upstream:?}"
+        );
+    }
+    assert!(!super::eq_modulo_nan::<f64, _>(
+        avx::_mm256_min_pd(a, b),
+        avx::_mm256_min_pd(b, a)
+    ));
+}
 mk!(_mm256_and_pd(a: __m256d, b: __m256d));
 mk!(_mm256_and_ps(a: __m256, b: __m256));
 mk!(_mm256_or_pd(a: __m256d, b: __m256d));
@@ -234,8 +306,8 @@ mk!(_mm256_xor_pd(a: __m256d, b: __m256d));
 mk!(_mm256_xor_ps(a: __m256, b: __m256));
 mk!(_mm256_cvtepi32_pd(a: __m128i));
 mk!(_mm256_cvtepi32_ps(a: __m256i));
-mk!(_mm256_cvtpd_ps(a: __m256d));
-mk!(_mm256_cvtps_pd(a: __m128));
+mk!(#[float(f32)]_mm256_cvtpd_ps(a: __m256d));
+mk!(#[float(f64)]_mm256_cvtps_pd(a: __m128));
 mk!(_mm256_movehdup_ps(a: __m256));
 mk!(_mm256_moveldup_ps(a: __m256));
 mk!(_mm256_movedup_pd(a: __m256d));
@@ -259,12 +331,286 @@ mk!(_mm256_castsi128_si256(a: __m128i));
 mk!(_mm256_zextps128_ps256(a: __m128));
 mk!(_mm256_zextsi128_si256(a: __m128i));
 mk!(_mm256_zextpd128_pd256(a: __m128d));
+
+/// Indices 2 and 3 of the shuffle pick the lanes of the zero vector, so the
+/// lower lanes are the ones of `a`, bit for bit, and the upper ones are `+0.0`.
+#[test]
+fn _mm256_zextpd128_pd256_upper_zero() {
+    for a in [
+        [-0.0, f64::NAN],
+        [f64::MIN, f64::INFINITY],
+        [f64::random(), -f64::random()],
+    ] {
+        let r = super::super::models::avx::_mm256_zextpd128_pd256(BitVec::from_slice(&a, 64));
+        let lanes: Vec<u64> = r.to_vec();
+        assert_eq!(lanes, [a[0].to_bits(), a[1].to_bits(), 0, 0]);
+        let upstream = unsafe {
+            BitVec::from(upstream::_mm256_zextpd128_pd256(
+                BitVec::from_slice(&a, 64).into(),
+            ))
+        };
+        assert_eq!(r, upstream);
+    }
+}
 mk!(_mm256_undefined_ps());
 mk!(_mm256_undefined_pd());
 mk!(_mm256_undefined_si256());
+
+/// The `_undefined_*` intrinsics are modeled as the zero vector. This checks
+/// that all three give zero, and that it stays zero when converted to the
+/// upstream vector types and back to a `BitVec`.
+#[test]
+fn _mm256_undefined_zero() {
+    use super::super::models::avx;
+    let zero: BitVec<256> = BitVec::ZERO();
+    for r in [
+        avx::_mm256_undefined_ps(),
+        avx::_mm256_undefined_pd(),
+        avx::_mm256_undefined_si256(),
+    ] {
+        assert_eq!(r, zero);
+    }
+    let (ps, pd, si256): (upstream::__m256, upstream::__m256d, upstream::__m256i) = (
+        avx::_mm256_undefined_ps().into(),
+        avx::_mm256_undefined_pd().into(),
+        avx::_mm256_undefined_si256().into(),
+    );
+    assert_eq!(BitVec::from(ps), zero);
+    assert_eq!(BitVec::from(pd), zero);
+    assert_eq!(BitVec::from(si256), zero);
+}
 mk!(_mm256_set_m128(hi: __m128, lo: __m128));
 mk!(_mm256_set_m128d(hi: __m128d, lo: __m128d));
 mk!(_mm256_set_m128i(hi: __m128i, lo: __m128i));
 mk!(_mm256_setr_m128(lo: __m128, hi: __m128));
 mk!(_mm256_setr_m128d(lo: __m128d, hi: __m128d));
 mk!(_mm256_setr_m128i(lo: __m128i, hi: __m128i));
+
+/// The `d` and `i` variants go through `__m128` and `_mm256_set_m128`, which
+/// must not touch the bits: NaNs (signaling ones, and those with a payload),
+/// subnormals and negative zeros, as `f32` or as `f64`, all come out as is,
+/// `lo` in the lower half and `hi` in the upper one.
+#[test]
+fn _mm256_set_m128_bits() {
+    use super::super::models::avx;
+    let patterns: [u64; 8] = [
+        0x7ff0_0000_0000_0001,
+        0xfff8_dead_beef_0001,
+        0x0000_0000_0000_0001,
+        0x8000_0000_0000_0000,
+        0x7f80_0001_ff80_0001,
+        0x7fc0_1234_ffc0_5678,
+        0x0000_0001_807f_ffff,
+        0x8000_0000_8000_0000,
+    ];
+    let mut inputs: Vec<[u64; 4]> = (0..patterns.len())
+        .map(|i| [0, 1, 2, 3].map(|j| patterns[(i + j) % patterns.len()]))
+        .collect();
+    inputs.extend((0..100).map(|_| {
+        let v: BitVec<256> = BitVec::random();
+        let v = v.to_vec::<u64>();
+        [v[0], v[1], v[2], v[3]]
+    }));
+    for words in inputs {
+        let lo = BitVec::<128>::from_slice(&words[..2], 64);
+        let hi = BitVec::<128>::from_slice(&words[2..], 64);
+        let expected = BitVec::<256>::from_slice(&words, 64);
+        let results: [(BitVec<256>, BitVec<256>); 6] = unsafe {
+            [
+                (
+                    avx::_mm256_set_m128(hi, lo),
+                    upstream::_mm256_set_m128(hi.into(), lo.into()).into(),
+                ),
+                (
+                    avx::_mm256_set_m128d(hi, lo),
+                    upstream::_mm256_set_m128d(hi.into(), lo.into()).into(),
+                ),
+                (
+                    avx::_mm256_set_m128i(hi, lo),
+                    upstream::_mm256_set_m128i(hi.into(), lo.into()).into(),
+                ),
+                (
+                    avx::_mm256_setr_m128(lo, hi),
+                    upstream::_mm256_setr_m128(lo.into(), hi.into()).into(),
+                ),
+                (
+                    avx::_mm256_setr_m128d(lo, hi),
+                    upstream::_mm256_setr_m128d(lo.into(), hi.into()).into(),
+                ),
+                (
+                    avx::_mm256_setr_m128i(lo, hi),
+                    upstream::_mm256_setr_m128i(lo.into(), hi.into()).into(),
+                ),
+            ]
+        };
+        for (i, (model, upstream)) in results.into_iter().enumerate() {
+            assert_eq!(model, expected, "variant {i} with {words:x?}");
+            assert_eq!(upstream, expected, "variant {i} with {words:x?}");
+        }
+    }
+}
+
+/// Loads from every offset of a buffer, most of them unaligned.
+#[test]
+fn _mm256_loadu_si256_unaligned() {
+    for _ in 0..100 {
+        let mem: [u8; 64] = core::array::from_fn(|_| u8::random());
+        for offset in 0..32 {
+            let r = super::super::models::avx::_mm256_loadu_si256(&mem[offset..]);
+            assert_eq!(r, BitVec::from_slice(&mem[offset..offset + 32], 8));
+            assert_eq!(r, unsafe {
+                BitVec::from(upstream::_mm256_loadu_si256(
+                    mem[offset..].as_ptr() as *const upstream::__m256i
+                ))
+            });
+        }
+    }
+}
+
+/// Stores a loaded vector back to every offset of a buffer, checking that the
+/// original bytes are reproduced and that the bytes around them are left
+/// untouched.
+#[test]
+fn _mm256_storeu_si256_round_trip() {
+    use super::super::models::avx;
+    for _ in 0..100 {
+        let bytes: [u8; 32] = core::array::from_fn(|_| u8::random());
+        let a = avx::_mm256_loadu_si256(&bytes);
+        let mem: [u8; 64] = core::array::from_fn(|_| u8::random());
+        for offset in 0..32 {
+            let (mut model, mut expected) = (mem, mem);
+            avx::_mm256_storeu_si256(&mut model[offset..], a);
+            unsafe {
+                upstream::_mm256_storeu_si256(
+                    expected[offset..].as_mut_ptr() as *mut upstream::__m256i,
+                    a.into(),
+                )
+            };
+            assert_eq!(model, expected);
+            assert_eq!(model[offset..offset + 32], bytes);
+            assert_eq!(model[..offset], mem[..offset]);
+            assert_eq!(model[offset + 32..], mem[offset + 32..]);
+        }
+    }
+}
+
+/// Streams a vector to both 32-byte aligned offsets of a buffer, which
+/// upstream requires, checking the bytes and the ones around them as for
+/// `_mm256_storeu_si256`.
+#[test]
+fn _mm256_stream_si256_round_trip() {
+    use super::super::models::avx;
+    #[repr(align(32))]
+    #[derive(Clone, Copy)]
+    struct Aligned([u8; 64]);
+    for _ in 0..100 {
+        let bytes: [u8; 32] = core::array::from_fn(|_| u8::random());
+        let a = avx::_mm256_loadu_si256(&bytes);
+        let mem = Aligned(core::array::from_fn(|_| u8::random()));
+        for offset in [0, 32] {
+            let (mut model, mut expected) = (mem, mem);
+            avx::_mm256_stream_si256(&mut model.0[offset..], a);
+            unsafe {
+                upstream::_mm256_stream_si256(
+                    expected.0[offset..].as_mut_ptr() as *mut upstream::__m256i,
+                    a.into(),
+                );
+                upstream::_mm_sfence();
+            }
+            assert_eq!(model.0, expected.0);
+            assert_eq!(model.0[offset..offset + 32], bytes);
+            assert_eq!(model.0[..offset], mem.0[..offset]);
+            assert_eq!(model.0[offset + 32..], mem.0[offset + 32..]);
+        }
+    }
+}
+
+/// Derives a test for a floating-point masked load intrinsic, comparing the
+/// model against upstream on random memory and random masks. The memory is
+/// drawn as random bit patterns, so it covers NaNs and infinities as well.
+macro_rules! mk_maskload {
+    ($name:ident(mem_addr: &[$elem:ty; $lanes:literal], mask: $ty:ident) as $bits:ty) => {
+        #[test]
+        fn $name() {
+            for _ in 0..1000 {
+                let mem: [$elem; $lanes] =
+                    core::array::from_fn(|_| <$elem>::from_bits(<$bits>::random()));
+                let mask: $ty = BitVec::random();
+                let model = super::super::models::avx::$name(&mem, mask);
+                let upstream = unsafe { upstream::$name(mem.as_ptr(), mask.into()) };
+                assert_eq!(
+                    BitVec::from(model),
+                    BitVec::from(upstream),
+                    "{mem:?}, {mask:?}"
+                );
+            }
+        }
+    };
+}
+
+/// Derives a test for a floating-point masked store intrinsic. The destination
+/// is pre-filled with a sentinel, and the lanes that are masked off must still
+/// hold it after the store. Lanes are compared bitwise, since `a` may hold NaNs.
+macro_rules! mk_maskstore {
+    ($name:ident(mem_addr: &mut [$elem:ty; $lanes:literal], mask: $ty:ident, a: $ty2:ident) as $bits:ty) => {
+        #[test]
+        fn $name() {
+            const SENTINEL: $elem = -1.5;
+            for _ in 0..1000 {
+                let mask: [$bits; $lanes] = core::array::from_fn(|_| <$bits>::random());
+                let a: $ty2 = BitVec::random();
+                let mut model = [SENTINEL; $lanes];
+                let mut upstream = [SENTINEL; $lanes];
+                super::super::models::avx::$name(
+                    &mut model,
+                    BitVec::from_slice(&mask, <$bits>::BITS),
+                    a,
+                );
+                unsafe {
+                    upstream::$name(
+                        upstream.as_mut_ptr(),
+                        BitVec::from_slice(&mask, <$bits>::BITS).into(),
+                        a.into(),
+                    )
+                };
+                assert_eq!(model.map(<$elem>::to_bits), upstream.map(<$elem>::to_bits));
+                for i in 0..$lanes {
+                    if mask[i] >> (<$bits>::BITS - 1) == 0 {
+                        assert_eq!(
+                            model[i].to_bits(),
+                            SENTINEL.to_bits(),
+                            "lane {i} is masked off: {mask:?}"
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+mk_maskload!(_mm256_maskload_pd(mem_addr: &[f64; 4], mask: __m256i) as u64);
+mk_maskload!(_mm_maskload_pd(mem_addr: &[f64; 2], mask: __m128i) as u64);
+mk_maskload!(_mm256_maskload_ps(mem_addr: &[f32; 8], mask: __m256i) as u32);
+mk_maskload!(_mm_maskload_ps(mem_addr: &[f32; 4], mask: __m128i) as u32);
+mk_maskstore!(_mm256_maskstore_pd(mem_addr: &mut [f64; 4], mask: __m256i, a: __m256d) as u64);
+mk_maskstore!(_mm_maskstore_pd(mem_addr: &mut [f64; 2], mask: __m128i, a: __m128d) as u64);
+mk_maskstore!(_mm256_maskstore_ps(mem_addr: &mut [f32; 8], mask: __m256i, a: __m256) as u32);
+mk_maskstore!(_mm_maskstore_ps(mem_addr: &mut [f32; 4], mask: __m128i, a: __m128) as u32);
+
+/// Masked off lanes past the end of the slice are neither read nor written.
+#[test]
+fn _mm256_mask_ps_short_slice() {
+    use super::super::models::avx;
+    let mask = BitVec::from_slice(&[-1i32, 0, -1, 0, 0, 0, 0, 0], 32);
+    let r = avx::_mm256_maskload_ps(&[1.0, 2.0, 3.0], mask);
+    assert_eq!(
+        r,
+        BitVec::from_slice(
+            &[1.0f32, 0.0, 3.0, 0.0, 0.0, 0.0, 0.0, 0.0].map(f32::to_bits),
+            32
+        )
+    );
+    let mut mem = [-1.0f32; 3];
+    avx::_mm256_maskstore_ps(&mut mem, mask, avx::_mm256_set1_ps(7.0));
+    assert_eq!(mem, [7.0, -1.0, 7.0]);
+}
@@ -1,11 +1,20 @@
 use super::types::*;
 use super::upstream;
 use crate::abstractions::bitvec::BitVec;
-use crate::helpers::test::HasRandom;
+use crate::abstractions::simd::u8x32;
+use crate::helpers::test::mk_const_sweep;
+use crate::helpers::test::mk_exhaustive;
+use crate::helpers::test::{HasRandom, HasRandomBiased};
 
 /// Derives tests for a given intrinsics. Test that a given intrinsics and its model compute the same thing over random values (1000 by default).
 macro_rules! mk {
-    ($([$N:literal])?$name:ident$({$(<$($c:literal),*>),*})?($($x:ident : $ty:ident),*)) => {
+    ($([$N:literal])?$(#[$mode:ident($fty:ty)])?$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
+        mk!(@test[$($N)?, val$(, $mode $fty)?]$name$({$(<$($c),*>),*})?($($x : $ty),*)$(-> $ret)?);
+    };
+    ($([$N:literal])?$(#[$mode:ident($fty:ty)])?$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : &$ty:ident),+)$(-> $ret:ty)?) => {
+        mk!(@test[$($N)?, ref$(, $mode $fty)?]$name$({$(<$($c),*>),*})?($($x : $ty),*)$(-> $ret)?);
+    };
+    (@test[$($N:literal)?, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
         #[test]
         fn $name() {
             #[allow(unused)]
@@ -14,29 +23,82 @@ macro_rules! mk {
                 $(let n: usize = $N;)?
                     n
             };
-            mk!(@[N]$name$($(<$($c),*>)*)?($($x : $ty),*));
+            mk!(@[N, $pass$(, $mode $fty)?]$name$($(<$($c),*>)*)?($($x : $ty),*)$(-> $ret)?);
+        }
+    };
+    (@val $x:ident) => {
+        $x.into()
+    };
+    (@ref $x:ident) => {
+        &$x.into()
+    };
+    (@eq $model:expr, $upstream:expr) => {
+        $model == $upstream
+    };
+    (@eq $model:expr, $upstream:expr, $mode:ident $fty:ty) => {
+        super::mismatched_lanes::<$fty, _>($model, $upstream, mk!(@same $mode)).is_empty()
+    };
+    (@same float) => {
+        super::same_modulo_nan
+    };
+    (@same lanes) => {
+        super::same_bits
+    };
+    (@cmp[$inputs:expr] $model:expr, $upstream:expr) => {
+        assert_eq!($model, $upstream, "Failed with (shrunk) input values: {:?} (seed: {})", $inputs, crate::helpers::test::seed())
+    };
+    (@cmp[$inputs:expr] $model:expr, $upstream:expr, $mode:ident $fty:ty) => {
+        super::assert_lanes_eq::<$fty, _>($model, $upstream, mk!(@same $mode), $inputs)
+    };
+    (@[$N:ident, $pass:ident]$name:ident$(<$($c:tt),*>)?($($x:ident : $ty:ident),*) -> $ret:ty) => {
+        for _ in 0..$N {
+            $(let $x = std::cell::Cell::new($ty::random());)*
+                let fails = || {
+                    $(let $x = $x.get();)*
+                    let model: $ret = super::super::models::avx2::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*);
+                    let upstream: $ret = unsafe { upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*) };
+                    model != upstream
+                };
+                if super::shrink(&[$(&$x),*], fails) {
+                    $(let $x = $x.get();)*
+                    let inputs: &[&dyn std::fmt::Debug] = &[$(&$x),*];
+                    let model: $ret = super::super::models::avx2::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*);
+                    let upstream: $ret = unsafe { upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*) };
+                    mk!(@cmp[inputs] model, upstream);
+                }
         }
     };
-    (@[$N:ident]$name:ident$(<$($c:literal),*>)?($($x:ident : $ty:ident),*)) => {
+    (@[$N:ident, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident$(<$($c:tt),*>)?($($x:ident : $ty:ident),*)) => {
         for _ in 0..$N {
-            $(let $x = $ty::random();)*
-                assert_eq!(super::super::models::avx2::$name$(::<$($c,)*>)?($($x.into(),)*), unsafe {
-                    BitVec::from(upstream::$name$(::<$($c,)*>)?($($x.into(),)*)).into()
-                });
+            $(let $x = std::cell::Cell::new($ty::random());)*
+                let fails = || {
+                    $(let $x = $x.get();)*
+                    !mk!(@eq super::super::models::avx2::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*), unsafe {
+                        BitVec::from(upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*)).into()
+                    }$(, $mode $fty)?)
+                };
+                if super::shrink(&[$(&$x),*], fails) {
+                    $(let $x = $x.get();)*
+                    let inputs: &[&dyn std::fmt::Debug] = &[$(&$x),*];
+                    mk!(@cmp[inputs] super::super::models::avx2::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*), unsafe {
+                        BitVec::from(upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*)).into()
+                    }$(, $mode $fty)?);
+                }
         }
     };
-    (@[$N:ident]$name:ident<$($c1:literal),*>$(<$($c:literal),*>)*($($x:ident : $ty:ident),*)) => {
+    (@[$N:ident, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident<$($c1:tt),*>$(<$($c:tt),*>)*($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
         let one = || {
-            mk!(@[$N]$name<$($c1),*>($($x : $ty),*));
+            mk!(@[$N, $pass$(, $mode $fty)?]$name<$($c1),*>($($x : $ty),*)$(-> $ret)?);
         };
         one();
-        mk!(@[$N]$name$(<$($c),*>)*($($x : $ty),*));
+        mk!(@[$N, $pass$(, $mode $fty)?]$name$(<$($c),*>)*($($x : $ty),*)$(-> $ret)?);
     }
 }
 
 mk!(_mm256_abs_epi32(a: BitVec));
 mk!(_mm256_abs_epi16(a: BitVec));
 mk!(_mm256_abs_epi8(a: BitVec));
+mk_exhaustive!(avx2::_mm256_abs_epi8(a: __m256i));
 mk!(_mm256_add_epi64(a: BitVec, b: BitVec));
 mk!(_mm256_add_epi32(a: BitVec, b: BitVec));
 mk!(_mm256_add_epi16(a: BitVec, b: BitVec));
@@ -45,9 +107,97 @@ mk!(_mm256_adds_epi8(a: BitVec, b: BitVec));
 mk!(_mm256_adds_epi16(a: BitVec, b: BitVec));
 mk!(_mm256_adds_epu8(a: BitVec, b: BitVec));
 mk!(_mm256_adds_epu16(a: BitVec, b: BitVec));
-mk!([100]_mm256_alignr_epi8{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>,<33>,<34>,<35>,<36>,<37>,<38>,<39>,<40>,<41>,<42>,<43>,<44>,<45>,<46>,<47>,<48>,<49>,<50>,<51>,<52>,<53>,<54>,<55>,<56>,<57>,<58>,<59>,<60>,<61>,<62>,<63>,<64>,<65>,<66>,<67>,<68>,<69>,<70>,<71>,<72>,<73>,<74>,<75>,<76>,<77>,<78>,<79>,<80>,<81>,<82>,<83>,<84>,<85>,<86>,<87>,<88>,<89>,<90>,<91>,<92>,<93>,<94>,<95>,<96>,<97>,<98>,<99>,<100>,<101>,<102>,<103>,<104>,<105>,<106>,<107>,<108>,<109>,<110>,<111>,<112>,<113>,<114>,<115>,<116>,<117>,<118>,<119>,<120>,<121>,<122>,<123>,<124>,<125>,<126>,<127>,<128>,<129>,<130>,<131>,<132>,<133>,<134>,<135>,<136>,<137>,<138>,<139>,<140>,<141>,<142>,<143>,<144>,<145>,<146>,<147>,<148>,<149>,<150>,<151>,<152>,<153>,<154>,<155>,<156>,<157>,<158>,<159>,<160>,<161>,<162>,<163>,<164>,<165>,<166>,<167>,<168>,<169>,<170>,<171>,<172>,<173>,<174>,<175>,<176>,<177>,<178>,<179>,<180>,<181>,<182>,<183>,<184>,<185>,<186>,<187>,<188>,<189>,<190>,<191>,<192>,<193>,<194>,<195>,<196>,<197>,<198>,<199>,<200>,<201>,<202>,<203>,<204>,<205>,<206>,<207>,<208>,<209>,<210>,<211>,<212>,<213>,<214>,<215>,<216>,<217>,<218>,<219>,<220>,<221>,<222>,<223>,<224>,<225>,<226>,<227>,<228>,<229>,<230>,<231>,<232>,<233>,<234>,<235>,<236>,<237>,<238>,<239>,<240>,<241>,<242>,<243>,<244>,<245>,<246>,<247>,<248>,<249>,<250>,<251>,<252>,<253>,<254>,<255>}(a: BitVec, b: BitVec));
-mk!([100]_mm256_permute2x128_si256{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>,<33>,<34>,<35>,<36>,<37>,<38>,<39>,<40>,<41>,<42>,<43>,<44>,<45>,<46>,<47>,<48>,<49>,<50>,<51>,<52>,<53>,<54>,<55>,<56>,<57>,<58>,<59>,<60>,<61>,<62>,<63>,<64>,<65>,<66>,<67>,<68>,<69>,<70>,<71>,<72>,<73>,<74>,<75>,<76>,<77>,<78>,<79>,<80>,<81>,<82>,<83>,<84>,<85>,<86>,<87>,<88>,<89>,<90>,<91>,<92>,<93>,<94>,<95>,<96>,<97>,<98>,<99>,<100>,<101>,<102>,<103>,<104>,<105>,<106>,<107>,<108>,<109>,<110>,<111>,<112>,<113>,<114>,<115>,<116>,<117>,<118>,<119>,<120>,<121>,<122>,<123>,<124>,<125>,<126>,<127>,<128>,<129>,<130>,<131>,<132>,<133>,<134>,<135>,<136>,<137>,<138>,<139>,<140>,<141>,<142>,<143>,<144>,<145>,<146>,<147>,<148>,<149>,<150>,<151>,<152>,<153>,<154>,<155>,<156>,<157>,<158>,<159>,<160>,<161>,<162>,<163>,<164>,<165>,<166>,<167>,<168>,<169>,<170>,<171>,<172>,<173>,<174>,<175>,<176>,<177>,<178>,<179>,<180>,<181>,<182>,<183>,<184>,<185>,<186>,<187>,<188>,<189>,<190>,<191>,<192>,<193>,<194>,<195>,<196>,<197>,<198>,<199>,<200>,<201>,<202>,<203>,<204>,<205>,<206>,<207>,<208>,<209>,<210>,<211>,<212>,<213>,<214>,<215>,<216>,<217>,<218>,<219>,<220>,<221>,<222>,<223>,<224>,<225>,<226>,<227>,<228>,<229>,<230>,<231>,<232>,<233>,<234>,<235>,<236>,<237>,<238>,<239>,<240>,<241>,<242>,<243>,<244>,<245>,<246>,<247>,<248>,<249>,<250>,<251>,<252>,<253>,<254>,<255>}(a: BitVec, b: BitVec));
+mk_const_sweep!([100]_mm256_alignr_epi8{bits: 8}(a: BitVec, b: BitVec));
+
+/// Pins the immediates around the branches of the model: below and at 16, where
+/// the lanes of `a` get shifted in, above 16, where zeros do, and 32 and above,
+/// where nothing is left. The bytes of `a` are numbered from 100 and those of `b`
+/// from 0, so that the result tells where each byte comes from.
+#[test]
+fn _mm256_alignr_epi8_boundaries() {
+    use super::super::models::avx2;
+    let a: __m256i = BitVec::from_slice(&core::array::from_fn::<u8, 32, _>(|i| 100 + i as u8), 8);
+    let b: __m256i = BitVec::from_slice(&core::array::from_fn::<u8, 32, _>(|i| i as u8), 8);
+    fn expected(imm8: usize) -> [u8; 32] {
+        core::array::from_fn(|i| {
+            let (lane, j) = (i / 16 * 16, i % 16);
+            match j + imm8 {
+                k @ 0..16 => (lane + k) as u8,
+                k @ 16..32 => 100 + (lane + k - 16) as u8,
+                _ => 0,
+            }
+        })
+    }
+    macro_rules! check {
+        ($($imm8:literal),*) => {$(
+            let r = avx2::_mm256_alignr_epi8::<$imm8>(a, b);
+            assert_eq!(r.to_vec::<u8>(), expected($imm8), "IMM8 = {}", $imm8);
+            assert_eq!(
+                r,
+                unsafe { BitVec::from(upstream::_mm256_alignr_epi8::<$imm8>(a.into(), b.into())) },
+                "IMM8 = {}",
+                $imm8
+            );
+        )*};
+    }
+    check!(0, 1, 15, 16, 17, 31, 32, 255);
+}
+mk_const_sweep!([100]_mm256_permute2x128_si256{bits: 8}(a: __m256i, b: __m256i));
+
+/// Bit 3 of `IMM8` zeroes the low half of the result and bit 7 its high half,
+/// whatever the halves selected by bits `[1:0]` and `[5:4]`. Bits 2 and 6 are
+/// ignored.
+#[test]
+fn _mm256_permute2x128_si256_zeroing() {
+    fn check<const IMM8: i32>(expected: [u128; 2]) {
+        let a = BitVec::from_slice(&[1u128, 2], 128);
+        let b = BitVec::from_slice(&[3u128, 4], 128);
+        let r = super::super::models::avx2::_mm256_permute2x128_si256::<IMM8>(a, b);
+        assert_eq!(r, BitVec::from_slice(&expected, 128), "IMM8 = {IMM8:#x}");
+        let upstream = unsafe { upstream::_mm256_permute2x128_si256::<IMM8>(a.into(), b.into()) };
+        assert_eq!(r, BitVec::from(upstream), "IMM8 = {IMM8:#x}");
+    }
+    check::<0x31>([2, 4]);
+    check::<0x39>([0, 4]);
+    check::<0xb1>([2, 0]);
+    check::<0xb9>([0, 0]);
+    check::<0x08>([0, 1]);
+    check::<0x80>([1, 0]);
+    check::<0x88>([0, 0]);
+    check::<0x75>([2, 4]);
+    check::<0xff>([0, 0]);
+}
 mk!(_mm256_blendv_epi8(a: BitVec, b: BitVec, mask: BitVec));
+
+/// Only the top bit of each mask byte is looked at: masks with every other bit
+/// set (`0x7f`) select from `a`, and masks with only the top bit set (`0x80`)
+/// select from `b`, which a model testing the mask bytes against zero would get
+/// wrong, without random masks being likely to show it.
+#[test]
+fn _mm256_blendv_epi8_sign_bit() {
+    use super::super::models::avx2;
+    let masks = [0x7f, 0x80, 0x01, 0xff, 0x00, 0xc0, 0x40, 0xfe];
+    let a: __m256i = BitVec::from_slice(&core::array::from_fn::<u8, 32, _>(|i| i as u8), 8);
+    let b: __m256i = BitVec::from_slice(&core::array::from_fn::<u8, 32, _>(|i| 100 + i as u8), 8);
+    let mask_bytes: [u8; 32] = core::array::from_fn(|i| masks[i % masks.len()]);
+    let mask: __m256i = BitVec::from_slice(&mask_bytes, 8);
+    let expected: [u8; 32] = core::array::from_fn(|i| {
+        if mask_bytes[i] & 0x80 != 0 {
+            100 + i as u8
+        } else {
+            i as u8
+        }
+    });
+    let r = avx2::_mm256_blendv_epi8(a, b, mask);
+    assert_eq!(r.to_vec::<u8>(), expected);
+    assert_eq!(r, unsafe {
+        BitVec::from(upstream::_mm256_blendv_epi8(
+            a.into(),
+            b.into(),
+            mask.into(),
+        ))
+    });
+}
 mk!(_mm_broadcastb_epi8(a: BitVec));
 mk!(_mm256_broadcastb_epi8(a: BitVec));
 mk!(_mm_broadcastd_epi32(a: BitVec));
@@ -78,6 +228,73 @@ mk!(_mm256_cvtepu32_epi64(a: BitVec));
 mk!(_mm256_cvtepu8_epi16(a: BitVec));
 mk!(_mm256_cvtepu8_epi32(a: BitVec));
 mk!(_mm256_cvtepu8_epi64(a: BitVec));
+
+/// Feeds the extensions source lanes with the sign bit set (`0x80`, `0x8000`,
+/// ...), all ones, and small positive values, all distinct so that picking the
+/// wrong source lanes shows up. The `epi` versions have to sign extend them and
+/// the `epu` ones to zero extend them, which `as` does on the matching types.
+#[test]
+fn _mm256_cvtep_extension_boundaries() {
+    use super::super::models::avx2;
+    macro_rules! check {
+        ($($name:ident: $src:ty => $dst:ty),*) => {$({
+            let src: [$src; 128 / <$src>::BITS as usize] = core::array::from_fn(|i| match i % 3 {
+                0 => (1 << (<$src>::BITS - 1)) | i as $src,
+                1 => !(i as $src),
+                _ => i as $src,
+            });
+            let a: __m128i = BitVec::from_slice(&src, <$src>::BITS);
+            let expected: [$dst; 256 / <$dst>::BITS as usize] = core::array::from_fn(|i| src[i] as $dst);
+            let model = avx2::$name(a);
+            assert_eq!(model, BitVec::from_slice(&expected, <$dst>::BITS), "{}", stringify!($name));
+            assert_eq!(
+                model,
+                unsafe { BitVec::from(upstream::$name(a.into())) },
+                "{}",
+                stringify!($name)
+            );
+        })*};
+    }
+    check!(
+        _mm256_cvtepi8_epi16: i8 => i16,
+        _mm256_cvtepi8_epi32: i8 => i32,
+        _mm256_cvtepi8_epi64: i8 => i64,
+        _mm256_cvtepi16_epi32: i16 => i32,
+        _mm256_cvtepi16_epi64: i16 => i64,
+        _mm256_cvtepi32_epi64: i32 => i64,
+        _mm256_cvtepu8_epi16: u8 => i16,
+        _mm256_cvtepu8_epi32: u8 => i32,
+        _mm256_cvtepu8_epi64: u8 => i64,
+        _mm256_cvtepu16_epi32: u16 => i32,
+        _mm256_cvtepu16_epi64: u16 => i64,
+        _mm256_cvtepu32_epi64: u32 => i64
+    );
+}
+
+/// `i16::MIN` and `i32::MIN` are the only values whose sign extension and zero
+/// extension differ in every upper bit.
+#[test]
+fn _mm256_cvtep_min() {
+    use super::super::models::avx2;
+    let a: __m128i = BitVec::from_slice(&[i16::MIN; 8], 16);
+    assert_eq!(
+        avx2::_mm256_cvtepi16_epi32(a),
+        BitVec::from_slice(&[i32::from(i16::MIN); 8], 32)
+    );
+    assert_eq!(
+        avx2::_mm256_cvtepu16_epi32(a),
+        BitVec::from_slice(&[0x8000i32; 8], 32)
+    );
+    let a: __m128i = BitVec::from_slice(&[i32::MIN; 4], 32);
+    assert_eq!(
+        avx2::_mm256_cvtepi32_epi64(a),
+        BitVec::from_slice(&[i64::from(i32::MIN); 4], 64)
+    );
+    assert_eq!(
+        avx2::_mm256_cvtepu32_epi64(a),
+        BitVec::from_slice(&[0x8000_0000i64; 4], 64)
+    );
+}
 mk!(_mm256_extracti128_si256{<0>,<1>}(a: BitVec));
 mk!(_mm256_hadd_epi16(a: BitVec, b: BitVec));
 mk!(_mm256_hadd_epi32(a: BitVec, b: BitVec));
@@ -86,6 +303,26 @@ mk!(_mm256_hsub_epi32(a: BitVec, b: BitVec));
 mk!(_mm256_hsubs_epi16(a: BitVec, b: BitVec));
 mk!(_mm256_inserti128_si256{<0>,<1>}(a: BitVec, b: BitVec));
 mk!(_mm256_madd_epi16(a: BitVec, b: BitVec));
+
+/// `-32768 * -32768 + -32768 * -32768` is `2^31`, which does not fit in an
+/// `i32` and wraps to `i32::MIN`. The other pairs stay just within range.
+#[test]
+fn _mm256_madd_epi16_overflow() {
+    let a: [i16; 16] = core::array::from_fn(|i| if i % 4 == 3 { 32767 } else { -32768 });
+    let a = BitVec::from_slice(&a, 16);
+    let r = super::super::models::avx2::_mm256_madd_epi16(a, a);
+    let expected: [i32; 8] = core::array::from_fn(|i| {
+        if i % 2 == 0 {
+            i32::MIN
+        } else {
+            (1 << 30) + 32767 * 32767
+        }
+    });
+    assert_eq!(r, BitVec::from_slice(&expected, 32));
+    assert_eq!(r, unsafe {
+        BitVec::from(upstream::_mm256_madd_epi16(a.into(), a.into()))
+    });
+}
 mk!(_mm256_maddubs_epi16(a: BitVec, b: BitVec));
 mk!(_mm256_max_epi16(a: BitVec, b: BitVec));
 mk!(_mm256_max_epi32(a: BitVec, b: BitVec));
@@ -112,27 +349,80 @@ mk!(_mm256_packs_epi32(a: BitVec, b: BitVec));
 mk!(_mm256_packus_epi16(a: BitVec, b: BitVec));
 mk!(_mm256_packus_epi32(a: BitVec, b: BitVec));
 mk!(_mm256_permutevar8x32_epi32(a: BitVec, b: BitVec));
-#[test]
-fn _mm256_movemask_epi8() {
-    let n = 1000;
+mk!(_mm256_permutevar8x32_ps(a: __m256, idx: __m256i));
+mk!(_mm256_movemask_epi8(a: BitVec) -> i32);
+mk_const_sweep!([100]_mm256_mpsadbw_epu8{bits: 8}(a: __m256i, b: __m256i));
 
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_movemask_epi8(a.into()),
-            unsafe { upstream::_mm256_movemask_epi8(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
+/// With `a[j] = j` and `b` all zeros, every sum is `4 * (offset + k) + 6` plus
+/// the lane base, which pins down the offsets each lane reads from `IMM8`: bits
+/// 0-2 for the low lane and bits 3-5 for the high lane.
+#[test]
+fn _mm256_mpsadbw_epu8_offsets() {
+    fn check<const IMM8: i32>() {
+        let a = BitVec::from_slice(&core::array::from_fn::<u8, 32, _>(|j| j as u8), 8);
+        let r = super::super::models::avx2::_mm256_mpsadbw_epu8::<IMM8>(a, BitVec::ZERO());
+        let offset = |imm8: i32| ((imm8 >> 2) & 1) as u16 * 4;
+        let expected: [u16; 16] = core::array::from_fn(|k| {
+            let (base, offset) = if k < 8 {
+                (0, offset(IMM8))
+            } else {
+                (64, offset(IMM8 >> 3))
+            };
+            base + 4 * (offset + k as u16 % 8) + 6
+        });
+        assert_eq!(r, BitVec::from_slice(&expected, 16), "IMM8 = {IMM8}");
+        let upstream =
+            unsafe { upstream::_mm256_mpsadbw_epu8::<IMM8>(a.into(), BitVec::ZERO().into()) };
+        assert_eq!(r, BitVec::from(upstream), "IMM8 = {IMM8}");
+    }
+    check::<0b000_000>();
+    check::<0b000_100>();
+    check::<0b100_000>();
+    check::<0b100_100>();
+    check::<0b011_111>();
+    check::<0b111_011>();
 }
-mk!([100]_mm256_mpsadbw_epu8{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>,<33>,<34>,<35>,<36>,<37>,<38>,<39>,<40>,<41>,<42>,<43>,<44>,<45>,<46>,<47>,<48>,<49>,<50>,<51>,<52>,<53>,<54>,<55>,<56>,<57>,<58>,<59>,<60>,<61>,<62>,<63>,<64>,<65>,<66>,<67>,<68>,<69>,<70>,<71>,<72>,<73>,<74>,<75>,<76>,<77>,<78>,<79>,<80>,<81>,<82>,<83>,<84>,<85>,<86>,<87>,<88>,<89>,<90>,<91>,<92>,<93>,<94>,<95>,<96>,<97>,<98>,<99>,<100>,<101>,<102>,<103>,<104>,<105>,<106>,<107>,<108>,<109>,<110>,<111>,<112>,<113>,<114>,<115>,<116>,<117>,<118>,<119>,<120>,<121>,<122>,<123>,<124>,<125>,<126>,<127>,<128>,<129>,<130>,<131>,<132>,<133>,<134>,<135>,<136>,<137>,<138>,<139>,<140>,<141>,<142>,<143>,<144>,<145>,<146>,<147>,<148>,<149>,<150>,<151>,<152>,<153>,<154>,<155>,<156>,<157>,<158>,<159>,<160>,<161>,<162>,<163>,<164>,<165>,<166>,<167>,<168>,<169>,<170>,<171>,<172>,<173>,<174>,<175>,<176>,<177>,<178>,<179>,<180>,<181>,<182>,<183>,<184>,<185>,<186>,<187>,<188>,<189>,<190>,<191>,<192>,<193>,<194>,<195>,<196>,<197>,<198>,<199>,<200>,<201>,<202>,<203>,<204>,<205>,<206>,<207>,<208>,<209>,<210>,<211>,<212>,<213>,<214>,<215>,<216>,<217>,<218>,<219>,<220>,<221>,<222>,<223>,<224>,<225>,<226>,<227>,<228>,<229>,<230>,<231>,<232>,<233>,<234>,<235>,<236>,<237>,<238>,<239>,<240>,<241>,<242>,<243>,<244>,<245>,<246>,<247>,<248>,<249>,<250>,<251>,<252>,<253>,<254>,<255>}(a: BitVec, b: BitVec));
 
-mk!([100]_mm256_permute4x64_epi64{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>,<33>,<34>,<35>,<36>,<37>,<38>,<39>,<40>,<41>,<42>,<43>,<44>,<45>,<46>,<47>,<48>,<49>,<50>,<51>,<52>,<53>,<54>,<55>,<56>,<57>,<58>,<59>,<60>,<61>,<62>,<63>,<64>,<65>,<66>,<67>,<68>,<69>,<70>,<71>,<72>,<73>,<74>,<75>,<76>,<77>,<78>,<79>,<80>,<81>,<82>,<83>,<84>,<85>,<86>,<87>,<88>,<89>,<90>,<91>,<92>,<93>,<94>,<95>,<96>,<97>,<98>,<99>,<100>,<101>,<102>,<103>,<104>,<105>,<106>,<107>,<108>,<109>,<110>,<111>,<112>,<113>,<114>,<115>,<116>,<117>,<118>,<119>,<120>,<121>,<122>,<123>,<124>,<125>,<126>,<127>,<128>,<129>,<130>,<131>,<132>,<133>,<134>,<135>,<136>,<137>,<138>,<139>,<140>,<141>,<142>,<143>,<144>,<145>,<146>,<147>,<148>,<149>,<150>,<151>,<152>,<153>,<154>,<155>,<156>,<157>,<158>,<159>,<160>,<161>,<162>,<163>,<164>,<165>,<166>,<167>,<168>,<169>,<170>,<171>,<172>,<173>,<174>,<175>,<176>,<177>,<178>,<179>,<180>,<181>,<182>,<183>,<184>,<185>,<186>,<187>,<188>,<189>,<190>,<191>,<192>,<193>,<194>,<195>,<196>,<197>,<198>,<199>,<200>,<201>,<202>,<203>,<204>,<205>,<206>,<207>,<208>,<209>,<210>,<211>,<212>,<213>,<214>,<215>,<216>,<217>,<218>,<219>,<220>,<221>,<222>,<223>,<224>,<225>,<226>,<227>,<228>,<229>,<230>,<231>,<232>,<233>,<234>,<235>,<236>,<237>,<238>,<239>,<240>,<241>,<242>,<243>,<244>,<245>,<246>,<247>,<248>,<249>,<250>,<251>,<252>,<253>,<254>,<255>}(a: BitVec));
-mk!([100]_mm256_shuffle_epi32{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>,<33>,<34>,<35>,<36>,<37>,<38>,<39>,<40>,<41>,<42>,<43>,<44>,<45>,<46>,<47>,<48>,<49>,<50>,<51>,<52>,<53>,<54>,<55>,<56>,<57>,<58>,<59>,<60>,<61>,<62>,<63>,<64>,<65>,<66>,<67>,<68>,<69>,<70>,<71>,<72>,<73>,<74>,<75>,<76>,<77>,<78>,<79>,<80>,<81>,<82>,<83>,<84>,<85>,<86>,<87>,<88>,<89>,<90>,<91>,<92>,<93>,<94>,<95>,<96>,<97>,<98>,<99>,<100>,<101>,<102>,<103>,<104>,<105>,<106>,<107>,<108>,<109>,<110>,<111>,<112>,<113>,<114>,<115>,<116>,<117>,<118>,<119>,<120>,<121>,<122>,<123>,<124>,<125>,<126>,<127>,<128>,<129>,<130>,<131>,<132>,<133>,<134>,<135>,<136>,<137>,<138>,<139>,<140>,<141>,<142>,<143>,<144>,<145>,<146>,<147>,<148>,<149>,<150>,<151>,<152>,<153>,<154>,<155>,<156>,<157>,<158>,<159>,<160>,<161>,<162>,<163>,<164>,<165>,<166>,<167>,<168>,<169>,<170>,<171>,<172>,<173>,<174>,<175>,<176>,<177>,<178>,<179>,<180>,<181>,<182>,<183>,<184>,<185>,<186>,<187>,<188>,<189>,<190>,<191>,<192>,<193>,<194>,<195>,<196>,<197>,<198>,<199>,<200>,<201>,<202>,<203>,<204>,<205>,<206>,<207>,<208>,<209>,<210>,<211>,<212>,<213>,<214>,<215>,<216>,<217>,<218>,<219>,<220>,<221>,<222>,<223>,<224>,<225>,<226>,<227>,<228>,<229>,<230>,<231>,<232>,<233>,<234>,<235>,<236>,<237>,<238>,<239>,<240>,<241>,<242>,<243>,<244>,<245>,<246>,<247>,<248>,<249>,<250>,<251>,<252>,<253>,<254>,<255>}(a: BitVec));
+mk_const_sweep!([100]_mm256_permute4x64_epi64{bits: 8}(a: BitVec));
+mk_const_sweep!([100]_mm256_permute4x64_pd{bits: 8}(a: __m256d));
+mk_const_sweep!([100]_mm256_shuffle_epi32{bits: 8}(a: BitVec));
+mk_const_sweep!([100]_mm256_blend_epi16{bits: 8}(a: BitVec, b: BitVec));
 mk!([100]_mm256_shufflehi_epi16{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>,<33>,<34>,<35>,<36>,<37>,<38>,<39>,<40>,<41>,<42>,<43>,<44>,<45>,<46>,<47>,<48>,<49>,<50>,<51>,<52>,<53>,<54>,<55>,<56>,<57>,<58>,<59>,<60>,<61>,<62>,<63>,<64>,<65>,<66>,<67>,<68>,<69>,<70>,<71>,<72>,<73>,<74>,<75>,<76>,<77>,<78>,<79>,<80>,<81>,<82>,<83>,<84>,<85>,<86>,<87>,<88>,<89>,<90>,<91>,<92>,<93>,<94>,<95>,<96>,<97>,<98>,<99>,<100>,<101>,<102>,<103>,<104>,<105>,<106>,<107>,<108>,<109>,<110>,<111>,<112>,<113>,<114>,<115>,<116>,<117>,<118>,<119>,<120>,<121>,<122>,<123>,<124>,<125>,<126>,<127>,<128>,<129>,<130>,<131>,<132>,<133>,<134>,<135>,<136>,<137>,<138>,<139>,<140>,<141>,<142>,<143>,<144>,<145>,<146>,<147>,<148>,<149>,<150>,<151>,<152>,<153>,<154>,<155>,<156>,<157>,<158>,<159>,<160>,<161>,<162>,<163>,<164>,<165>,<166>,<167>,<168>,<169>,<170>,<171>,<172>,<173>,<174>,<175>,<176>,<177>,<178>,<179>,<180>,<181>,<182>,<183>,<184>,<185>,<186>,<187>,<188>,<189>,<190>,<191>,<192>,<193>,<194>,<195>,<196>,<197>,<198>,<199>,<200>,<201>,<202>,<203>,<204>,<205>,<206>,<207>,<208>,<209>,<210>,<211>,<212>,<213>,<214>,<215>,<216>,<217>,<218>,<219>,<220>,<221>,<222>,<223>,<224>,<225>,<226>,<227>,<228>,<229>,<230>,<231>,<232>,<233>,<234>,<235>,<236>,<237>,<238>,<239>,<240>,<241>,<242>,<243>,<244>,<245>,<246>,<247>,<248>,<249>,<250>,<251>,<252>,<253>,<254>,<255>}(a: BitVec));
 mk!([100]_mm256_shufflelo_epi16{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>,<33>,<34>,<35>,<36>,<37>,<38>,<39>,<40>,<41>,<42>,<43>,<44>,<45>,<46>,<47>,<48>,<49>,<50>,<51>,<52>,<53>,<54>,<55>,<56>,<57>,<58>,<59>,<60>,<61>,<62>,<63>,<64>,<65>,<66>,<67>,<68>,<69>,<70>,<71>,<72>,<73>,<74>,<75>,<76>,<77>,<78>,<79>,<80>,<81>,<82>,<83>,<84>,<85>,<86>,<87>,<88>,<89>,<90>,<91>,<92>,<93>,<94>,<95>,<96>,<97>,<98>,<99>,<100>,<101>,<102>,<103>,<104>,<105>,<106>,<107>,<108>,<109>,<110>,<111>,<112>,<113>,<114>,<115>,<116>,<117>,<118>,<119>,<120>,<121>,<122>,<123>,<124>,<125>,<126>,<127>,<128>,<129>,<130>,<131>,<132>,<133>,<134>,<135>,<136>,<137>,<138>,<139>,<140>,<141>,<142>,<143>,<144>,<145>,<146>,<147>,<148>,<149>,<150>,<151>,<152>,<153>,<154>,<155>,<156>,<157>,<158>,<159>,<160>,<161>,<162>,<163>,<164>,<165>,<166>,<167>,<168>,<169>,<170>,<171>,<172>,<173>,<174>,<175>,<176>,<177>,<178>,<179>,<180>,<181>,<182>,<183>,<184>,<185>,<186>,<187>,<188>,<189>,<190>,<191>,<192>,<193>,<194>,<195>,<196>,<197>,<198>,<199>,<200>,<201>,<202>,<203>,<204>,<205>,<206>,<207>,<208>,<209>,<210>,<211>,<212>,<213>,<214>,<215>,<216>,<217>,<218>,<219>,<220>,<221>,<222>,<223>,<224>,<225>,<226>,<227>,<228>,<229>,<230>,<231>,<232>,<233>,<234>,<235>,<236>,<237>,<238>,<239>,<240>,<241>,<242>,<243>,<244>,<245>,<246>,<247>,<248>,<249>,<250>,<251>,<252>,<253>,<254>,<255>}(a: BitVec));
 mk!(_mm256_sad_epu8(a: BitVec, b: BitVec));
+
+/// Each 64-bit lane holds the sum of its eight absolute differences in bits
+/// `[15:0]`, and zeros in bits `[63:16]`. The largest sum, `8 * 255`, is checked
+/// along with random ones, whose bytes are biased towards `0` and `255`.
+#[test]
+fn _mm256_sad_epu8_layout() {
+    let ones = BitVec::from_slice(&[0xffu8; 32], 8);
+    let r = super::super::models::avx2::_mm256_sad_epu8(ones, BitVec::ZERO());
+    assert_eq!(r, BitVec::from_slice(&[8 * 255u64; 4], 64));
+    for _ in 0..1000 {
+        let (a, b) = (
+            BitVec::from(u8x32::random_biased()),
+            BitVec::from(u8x32::random_biased()),
+        );
+        let r = super::super::models::avx2::_mm256_sad_epu8(a, b);
+        for lane in 0..4 {
+            let sum: u16 = (0..8)
+                .map(|i| {
+                    let i = 8 * lane + i;
+                    a.bits::<8>(8 * i..8 * i + 8)
+                        .to_int::<u8>()
+                        .abs_diff(b.bits::<8>(8 * i..8 * i + 8).to_int::<u8>())
+                        as u16
+                })
+                .sum();
+            assert_eq!(r.bits::<16>(64 * lane..64 * lane + 16).to_int::<u16>(), sum);
+            assert_eq!(r.bits::<48>(64 * lane + 16..64 * lane + 64), BitVec::ZERO());
+        }
+        assert_eq!(r, unsafe {
+            BitVec::from(upstream::_mm256_sad_epu8(a.into(), b.into()))
+        });
+    }
+}
 mk!(_mm256_shuffle_epi8(a: BitVec, b: BitVec));
 mk!(_mm256_sign_epi16(a: BitVec, b: BitVec));
 mk!(_mm256_sign_epi32(a: BitVec, b: BitVec));
@@ -151,6 +441,56 @@ mk!(_mm_sllv_epi64(a: BitVec, count: BitVec));
 mk!(_mm256_sllv_epi64(a: BitVec, count: BitVec));
 mk!(_mm256_sra_epi16(a: BitVec, count: BitVec));
 mk!(_mm256_sra_epi32(a: BitVec, count: BitVec));
+
+/// Arithmetic shifts by a register count at or past the lane width clamp the
+/// count, so negative lanes become `-1` and the others `0`.
+#[test]
+fn _mm256_sra_epi16_epi32_saturate() {
+    use super::super::models::avx2;
+    let a16: __m256i = BitVec::from_slice(
+        &[
+            i16::MIN,
+            -32767,
+            -2,
+            -1,
+            0,
+            1,
+            2,
+            i16::MAX,
+            -300,
+            300,
+            -16384,
+            16384,
+            -5,
+            5,
+            -128,
+            127,
+        ],
+        16,
+    );
+    let a32: __m256i = BitVec::from_slice(&[i32::MIN, -2, -1, 0, 1, 2, i32::MAX, -70000], 32);
+    for c in [15u64, 16, 17, 31, 32, 33, 64, 255, 1 << 32, u64::MAX] {
+        let count: __m128i = BitVec::from_slice(&[c, 0], 64);
+        let r = avx2::_mm256_sra_epi16(a16, count);
+        assert_eq!(r, unsafe {
+            BitVec::from(upstream::_mm256_sra_epi16(a16.into(), count.into()))
+        });
+        if c >= 16 {
+            assert_eq!(r, avx2::_mm256_srai_epi16::<15>(a16), "count {c}");
+        }
+        let r = avx2::_mm256_sra_epi32(a32, count);
+        assert_eq!(r, unsafe {
+            BitVec::from(upstream::_mm256_sra_epi32(a32.into(), count.into()))
+        });
+        if c >= 32 {
+            assert_eq!(
+                r,
+                BitVec::from_slice(&[-1i32, -1, -1, 0, 0, 0, 0, -1], 32),
+                "count {c}"
+            );
+        }
+    }
+}
 mk!([100]_mm256_srai_epi16{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>,<33>,<34>,<35>,<36>,<37>,<38>,<39>,<40>,<41>,<42>,<43>,<44>,<45>,<46>,<47>,<48>,<49>,<50>,<51>,<52>,<53>,<54>,<55>,<56>,<57>,<58>,<59>,<60>,<61>,<62>,<63>,<64>,<65>,<66>,<67>,<68>,<69>,<70>,<71>,<72>,<73>,<74>,<75>,<76>,<77>,<78>,<79>,<80>,<81>,<82>,<83>,<84>,<85>,<86>,<87>,<88>,<89>,<90>,<91>,<92>,<93>,<94>,<95>,<96>,<97>,<98>,<99>,<100>,<101>,<102>,<103>,<104>,<105>,<106>,<107>,<108>,<109>,<110>,<111>,<112>,<113>,<114>,<115>,<116>,<117>,<118>,<119>,<120>,<121>,<122>,<123>,<124>,<125>,<126>,<127>,<128>,<129>,<130>,<131>,<132>,<133>,<134>,<135>,<136>,<137>,<138>,<139>,<140>,<141>,<142>,<143>,<144>,<145>,<146>,<147>,<148>,<149>,<150>,<151>,<152>,<153>,<154>,<155>,<156>,<157>,<158>,<159>,<160>,<161>,<162>,<163>,<164>,<165>,<166>,<167>,<168>,<169>,<170>,<171>,<172>,<173>,<174>,<175>,<176>,<177>,<178>,<179>,<180>,<181>,<182>,<183>,<184>,<185>,<186>,<187>,<188>,<189>,<190>,<191>,<192>,<193>,<194>,<195>,<196>,<197>,<198>,<199>,<200>,<201>,<202>,<203>,<204>,<205>,<206>,<207>,<208>,<209>,<210>,<211>,<212>,<213>,<214>,<215>,<216>,<217>,<218>,<219>,<220>,<221>,<222>,<223>,<224>,<225>,<226>,<227>,<228>,<229>,<230>,<231>,<232>,<233>,<234>,<235>,<236>,<237>,<238>,<239>,<240>,<241>,<242>,<243>,<244>,<245>,<246>,<247>,<248>,<249>,<250>,<251>,<252>,<253>,<254>,<255>}(a: BitVec));
 mk!(_mm256_srai_epi32{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>,<33>,<34>,<35>,<36>,<37>,<38>,<39>,<40>,<41>,<42>,<43>,<44>,<45>,<46>,<47>,<48>,<49>,<50>,<51>,<52>,<53>,<54>,<55>,<56>,<57>,<58>,<59>,<60>,<61>,<62>,<63>,<64>,<65>,<66>,<67>,<68>,<69>,<70>,<71>,<72>,<73>,<74>,<75>,<76>,<77>,<78>,<79>,<80>,<81>,<82>,<83>,<84>,<85>,<86>,<87>,<88>,<89>,<90>,<91>,<92>,<93>,<94>,<95>,<96>,<97>,<98>,<99>,<100>,<101>,<102>,<103>,<104>,<105>,<106>,<107>,<108>,<109>,<110>,<111>,<112>,<113>,<114>,<115>,<116>,<117>,<118>,<119>,<120>,<121>,<122>,<123>,<124>,<125>,<126>,<127>,<128>,<129>,<130>,<131>,<132>,<133>,<134>,<135>,<136>,<137>,<138>,<139>,<140>,<141>,<142>,<143>,<144>,<145>,<146>,<147>,<148>,<149>,<150>,<151>,<152>,<153>,<154>,<155>,<156>,<157>,<158>,<159>,<160>,<161>,<162>,<163>,<164>,<165>,<166>,<167>,<168>,<169>,<170>,<171>,<172>,<173>,<174>,<175>,<176>,<177>,<178>,<179>,<180>,<181>,<182>,<183>,<184>,<185>,<186>,<187>,<188>,<189>,<190>,<191>,<192>,<193>,<194>,<195>,<196>,<197>,<198>,<199>,<200>,<201>,<202>,<203>,<204>,<205>,<206>,<207>,<208>,<209>,<210>,<211>,<212>,<213>,<214>,<215>,<216>,<217>,<218>,<219>,<220>,<221>,<222>,<223>,<224>,<225>,<226>,<227>,<228>,<229>,<230>,<231>,<232>,<233>,<234>,<235>,<236>,<237>,<238>,<239>,<240>,<241>,<242>,<243>,<244>,<245>,<246>,<247>,<248>,<249>,<250>,<251>,<252>,<253>,<254>,<255>}(a: BitVec));
 mk!(_mm_srav_epi32(a: BitVec, count: BitVec));
@@ -160,6 +500,17 @@ mk!([100]_mm256_bsrli_epi128{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<
 mk!(_mm256_srl_epi16(a: BitVec, count: BitVec));
 mk!(_mm256_srl_epi32(a: BitVec, count: BitVec));
 mk!(_mm256_srl_epi64(a: BitVec, count: BitVec));
+
+super::mk_shift_count_test!(_mm256_sll_srl_sra_counts: avx2, __m256i, [
+    (_mm256_sll_epi16, 16, false),
+    (_mm256_sll_epi32, 32, false),
+    (_mm256_sll_epi64, 64, false),
+    (_mm256_srl_epi16, 16, false),
+    (_mm256_srl_epi32, 32, false),
+    (_mm256_srl_epi64, 64, false),
+    (_mm256_sra_epi16, 16, true),
+    (_mm256_sra_epi32, 32, true),
+]);
 mk!([100]_mm256_srli_epi16{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>,<33>,<34>,<35>,<36>,<37>,<38>,<39>,<40>,<41>,<42>,<43>,<44>,<45>,<46>,<47>,<48>,<49>,<50>,<51>,<52>,<53>,<54>,<55>,<56>,<57>,<58>,<59>,<60>,<61>,<62>,<63>,<64>,<65>,<66>,<67>,<68>,<69>,<70>,<71>,<72>,<73>,<74>,<75>,<76>,<77>,<78>,<79>,<80>,<81>,<82>,<83>,<84>,<85>,<86>,<87>,<88>,<89>,<90>,<91>,<92>,<93>,<94>,<95>,<96>,<97>,<98>,<99>,<100>,<101>,<102>,<103>,<104>,<105>,<106>,<107>,<108>,<109>,<110>,<111>,<112>,<113>,<114>,<115>,<116>,<117>,<118>,<119>,<120>,<121>,<122>,<123>,<124>,<125>,<126>,<127>,<128>,<129>,<130>,<131>,<132>,<133>,<134>,<135>,<136>,<137>,<138>,<139>,<140>,<141>,<142>,<143>,<144>,<145>,<146>,<147>,<148>,<149>,<150>,<151>,<152>,<153>,<154>,<155>,<156>,<157>,<158>,<159>,<160>,<161>,<162>,<163>,<164>,<165>,<166>,<167>,<168>,<169>,<170>,<171>,<172>,<173>,<174>,<175>,<176>,<177>,<178>,<179>,<180>,<181>,<182>,<183>,<184>,<185>,<186>,<187>,<188>,<189>,<190>,<191>,<192>,<193>,<194>,<195>,<196>,<197>,<198>,<199>,<200>,<201>,<202>,<203>,<204>,<205>,<206>,<207>,<208>,<209>,<210>,<211>,<212>,<213>,<214>,<215>,<216>,<217>,<218>,<219>,<220>,<221>,<222>,<223>,<224>,<225>,<226>,<227>,<228>,<229>,<230>,<231>,<232>,<233>,<234>,<235>,<236>,<237>,<238>,<239>,<240>,<241>,<242>,<243>,<244>,<245>,<246>,<247>,<248>,<249>,<250>,<251>,<252>,<253>,<254>,<255>}(a: BitVec));
 mk!([100]_mm256_srli_epi32{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>,<33>,<34>,<35>,<36>,<37>,<38>,<39>,<40>,<41>,<42>,<43>,<44>,<45>,<46>,<47>,<48>,<49>,<50>,<51>,<52>,<53>,<54>,<55>,<56>,<57>,<58>,<59>,<60>,<61>,<62>,<63>,<64>,<65>,<66>,<67>,<68>,<69>,<70>,<71>,<72>,<73>,<74>,<75>,<76>,<77>,<78>,<79>,<80>,<81>,<82>,<83>,<84>,<85>,<86>,<87>,<88>,<89>,<90>,<91>,<92>,<93>,<94>,<95>,<96>,<97>,<98>,<99>,<100>,<101>,<102>,<103>,<104>,<105>,<106>,<107>,<108>,<109>,<110>,<111>,<112>,<113>,<114>,<115>,<116>,<117>,<118>,<119>,<120>,<121>,<122>,<123>,<124>,<125>,<126>,<127>,<128>,<129>,<130>,<131>,<132>,<133>,<134>,<135>,<136>,<137>,<138>,<139>,<140>,<141>,<142>,<143>,<144>,<145>,<146>,<147>,<148>,<149>,<150>,<151>,<152>,<153>,<154>,<155>,<156>,<157>,<158>,<159>,<160>,<161>,<162>,<163>,<164>,<165>,<166>,<167>,<168>,<169>,<170>,<171>,<172>,<173>,<174>,<175>,<176>,<177>,<178>,<179>,<180>,<181>,<182>,<183>,<184>,<185>,<186>,<187>,<188>,<189>,<190>,<191>,<192>,<193>,<194>,<195>,<196>,<197>,<198>,<199>,<200>,<201>,<202>,<203>,<204>,<205>,<206>,<207>,<208>,<209>,<210>,<211>,<212>,<213>,<214>,<215>,<216>,<217>,<218>,<219>,<220>,<221>,<222>,<223>,<224>,<225>,<226>,<227>,<228>,<229>,<230>,<231>,<232>,<233>,<234>,<235>,<236>,<237>,<238>,<239>,<240>,<241>,<242>,<243>,<244>,<245>,<246>,<247>,<248>,<249>,<250>,<251>,<252>,<253>,<254>,<255>}(a: BitVec));
 mk!([100]_mm256_srli_epi64{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>,<33>,<34>,<35>,<36>,<37>,<38>,<39>,<40>,<41>,<42>,<43>,<44>,<45>,<46>,<47>,<48>,<49>,<50>,<51>,<52>,<53>,<54>,<55>,<56>,<57>,<58>,<59>,<60>,<61>,<62>,<63>,<64>,<65>,<66>,<67>,<68>,<69>,<70>,<71>,<72>,<73>,<74>,<75>,<76>,<77>,<78>,<79>,<80>,<81>,<82>,<83>,<84>,<85>,<86>,<87>,<88>,<89>,<90>,<91>,<92>,<93>,<94>,<95>,<96>,<97>,<98>,<99>,<100>,<101>,<102>,<103>,<104>,<105>,<106>,<107>,<108>,<109>,<110>,<111>,<112>,<113>,<114>,<115>,<116>,<117>,<118>,<119>,<120>,<121>,<122>,<123>,<124>,<125>,<126>,<127>,<128>,<129>,<130>,<131>,<132>,<133>,<134>,<135>,<136>,<137>,<138>,<139>,<140>,<141>,<142>,<143>,<144>,<145>,<146>,<147>,<148>,<149>,<150>,<151>,<152>,<153>,<154>,<155>,<156>,<157>,<158>,<159>,<160>,<161>,<162>,<163>,<164>,<165>,<166>,<167>,<168>,<169>,<170>,<171>,<172>,<173>,<174>,<175>,<176>,<177>,<178>,<179>,<180>,<181>,<182>,<183>,<184>,<185>,<186>,<187>,<188>,<189>,<190>,<191>,<192>,<193>,<194>,<195>,<196>,<197>,<198>,<199>,<200>,<201>,<202>,<203>,<204>,<205>,<206>,<207>,<208>,<209>,<210>,<211>,<212>,<213>,<214>,<215>,<216>,<217>,<218>,<219>,<220>,<221>,<222>,<223>,<224>,<225>,<226>,<227>,<228>,<229>,<230>,<231>,<232>,<233>,<234>,<235>,<236>,<237>,<238>,<239>,<240>,<241>,<242>,<243>,<244>,<245>,<246>,<247>,<248>,<249>,<250>,<251>,<252>,<253>,<254>,<255>}(a: BitVec));
@@ -167,6 +518,48 @@ mk!(_mm_srlv_epi32(a: BitVec, count: BitVec));
 mk!(_mm256_srlv_epi32(a: BitVec, count: BitVec));
 mk!(_mm_srlv_epi64(a: BitVec, count: BitVec));
 mk!(_mm256_srlv_epi64(a: BitVec, count: BitVec));
+
+/// Each lane of the variable shifts is shifted by its own count, and only the
+/// lanes whose count is at least the lane width, or negative as a signed
+/// integer, are zeroed or sign-filled. Every rotation of the counts below puts
+/// in-range and out-of-range counts next to each other in the same vector.
+#[test]
+fn _mm_sllv_srlv_srav_mixed_counts() {
+    use super::super::models::avx2;
+    let counts32: [i32; 10] = [0, 1, 31, 32, 33, -1, 7, i32::MIN, 255, 16];
+    let counts64: [i64; 10] = [0, 1, 63, 64, 65, -1, 32, i64::MIN, 255, 31];
+    for _ in 0..20 {
+        let a128: __m128i = BitVec::random();
+        let a256: __m256i = BitVec::random();
+        for r in 0..10 {
+            let c32 = |n: usize| -> Vec<i32> { (0..n).map(|i| counts32[(i + r) % 10]).collect() };
+            let c64 = |n: usize| -> Vec<i64> { (0..n).map(|i| counts64[(i + r) % 10]).collect() };
+            macro_rules! check {
+                ($($name:ident($a:ident, $count:expr, $lane:ty),)*) => {$(
+                    let count = BitVec::from_slice(&$count, <$lane>::BITS);
+                    super::assert_lanes_eq::<$lane, _>(
+                        avx2::$name($a, count),
+                        unsafe { BitVec::from(upstream::$name($a.into(), count.into())) },
+                        super::same_bits,
+                        &[&stringify!($name), &$a, &count],
+                    );
+                )*};
+            }
+            check!(
+                _mm_sllv_epi32(a128, c32(4), i32),
+                _mm256_sllv_epi32(a256, c32(8), i32),
+                _mm_sllv_epi64(a128, c64(2), i64),
+                _mm256_sllv_epi64(a256, c64(4), i64),
+                _mm_srlv_epi32(a128, c32(4), i32),
+                _mm256_srlv_epi32(a256, c32(8), i32),
+                _mm_srlv_epi64(a128, c64(2), i64),
+                _mm256_srlv_epi64(a256, c64(4), i64),
+                _mm_srav_epi32(a128, c32(4), i32),
+                _mm256_srav_epi32(a256, c32(8), i32),
+            );
+        }
+    }
+}
 mk!(_mm256_sub_epi16(a: BitVec, b: BitVec));
 mk!(_mm256_sub_epi32(a: BitVec, b: BitVec));
 mk!(_mm256_sub_epi64(a: BitVec, b: BitVec));
@@ -185,449 +578,9 @@ mk!(_mm256_unpackhi_epi64(a: BitVec, b: BitVec));
 mk!(_mm256_unpacklo_epi64(a: BitVec, b: BitVec));
 mk!(_mm256_xor_si256(a: BitVec, b: BitVec));
 
-#[test]
-fn _mm256_extract_epi8() {
-    let n = 100;
+mk!([100]_mm256_extract_epi8{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>}(a: BitVec) -> i32);
 
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<0>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<0>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<1>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<1>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<2>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<2>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<3>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<3>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<4>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<4>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<5>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<5>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<6>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<6>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<7>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<7>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<8>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<8>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<9>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<9>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<10>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<10>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<11>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<11>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<12>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<12>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<13>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<13>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<14>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<14>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<15>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<15>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<16>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<16>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<17>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<17>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<18>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<18>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<19>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<19>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<20>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<20>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<21>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<21>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<22>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<22>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<23>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<23>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<24>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<24>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<25>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<25>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<26>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<26>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<27>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<27>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<28>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<28>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<29>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<29>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<30>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<30>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi8::<31>(a.into()),
-            unsafe { upstream::_mm256_extract_epi8::<31>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-}
-
-#[test]
-fn _mm256_extract_epi16() {
-    let n = 100;
-
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi16::<0>(a.into()),
-            unsafe { upstream::_mm256_extract_epi16::<0>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi16::<1>(a.into()),
-            unsafe { upstream::_mm256_extract_epi16::<1>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi16::<2>(a.into()),
-            unsafe { upstream::_mm256_extract_epi16::<2>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi16::<3>(a.into()),
-            unsafe { upstream::_mm256_extract_epi16::<3>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi16::<4>(a.into()),
-            unsafe { upstream::_mm256_extract_epi16::<4>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi16::<5>(a.into()),
-            unsafe { upstream::_mm256_extract_epi16::<5>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi16::<6>(a.into()),
-            unsafe { upstream::_mm256_extract_epi16::<6>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi16::<7>(a.into()),
-            unsafe { upstream::_mm256_extract_epi16::<7>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi16::<8>(a.into()),
-            unsafe { upstream::_mm256_extract_epi16::<8>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi16::<9>(a.into()),
-            unsafe { upstream::_mm256_extract_epi16::<9>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi16::<10>(a.into()),
-            unsafe { upstream::_mm256_extract_epi16::<10>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi16::<11>(a.into()),
-            unsafe { upstream::_mm256_extract_epi16::<11>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi16::<12>(a.into()),
-            unsafe { upstream::_mm256_extract_epi16::<12>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi16::<13>(a.into()),
-            unsafe { upstream::_mm256_extract_epi16::<13>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi16::<14>(a.into()),
-            unsafe { upstream::_mm256_extract_epi16::<14>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-    for _ in 0..n {
-        let a: BitVec<256> = BitVec::random();
-        assert_eq!(
-            super::super::models::avx2::_mm256_extract_epi16::<15>(a.into()),
-            unsafe { upstream::_mm256_extract_epi16::<15>(a.into()) },
-            "Failed with input value: {:?}",
-            a
-        );
-    }
-}
+mk!([100]_mm256_extract_epi16{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>}(a: BitVec) -> i32);
 
 mk!(_mm256_and_si256(a: __m256i, b: __m256i));
 mk!(_mm256_andnot_si256(a: __m256i, b: __m256i));
@@ -637,3 +590,212 @@ mk!(_mm_broadcastsd_pd(a: __m128d));
 mk!(_mm256_broadcastsd_pd(a: __m128d));
 mk!(_mm_broadcastss_ps(a: __m128));
 mk!(_mm256_broadcastss_ps(a: __m128));
+
+/// Derives tests for a gather intrinsic: the "memory" is a random array of 64
+/// elements, which the model gets as a slice and upstream as a pointer, and the
+/// offsets are random but kept in-bounds for each of the scales.
+macro_rules! mk_gather {
+    ($name:ident(slice: &[$elem:ty], offsets: [$ity:ty; $lanes:literal])) => {
+        #[test]
+        fn $name() {
+            mk_gather!(@scale $name, $elem, $ity, $lanes, 1);
+            mk_gather!(@scale $name, $elem, $ity, $lanes, 2);
+            mk_gather!(@scale $name, $elem, $ity, $lanes, 4);
+            mk_gather!(@scale $name, $elem, $ity, $lanes, 8);
+        }
+    };
+    (@scale $name:ident, $elem:ty, $ity:ty, $lanes:literal, $scale:literal) => {
+        for _ in 0..100 {
+            let mem: [$elem; 64] = core::array::from_fn(|_| <$elem>::random());
+            let max = (mem.len() - 1) * size_of::<$elem>() / $scale;
+            let offsets: [$ity; $lanes] =
+                core::array::from_fn(|_| (u64::random() % (max as u64 + 1)) as $ity);
+            let offsets = BitVec::from_slice(&offsets, <$ity>::BITS);
+            let model = super::super::models::avx2::$name::<$scale>(&mem, offsets.into());
+            let upstream = unsafe { upstream::$name::<$scale>(mem.as_ptr(), offsets.into()) };
+            assert_eq!(
+                BitVec::from(model),
+                BitVec::from(upstream),
+                "scale {}, offsets {:?}",
+                $scale,
+                offsets
+            );
+        }
+    };
+}
+
+mk_gather!(_mm_i32gather_epi32(slice: &[i32], offsets: [i32; 4]));
+mk_gather!(_mm256_i32gather_epi32(slice: &[i32], offsets: [i32; 8]));
+mk_gather!(_mm256_i64gather_epi64(slice: &[i64], offsets: [i64; 4]));
+mk_gather!(_mm_i32gather_ps(slice: &[f32], offsets: [i32; 4]));
+mk_gather!(_mm256_i32gather_ps(slice: &[f32], offsets: [i32; 8]));
+mk_gather!(_mm256_i64gather_pd(slice: &[f64], offsets: [i64; 4]));
+
+#[test]
+#[should_panic(expected = "gather reads outside of the slice")]
+fn _mm256_i32gather_epi32_negative_offset() {
+    let mem = [0i32; 8];
+    let offsets = BitVec::from_slice(&[0i32, 1, 2, 3, -1, 5, 6, 7], 32);
+    super::super::models::avx2::_mm256_i32gather_epi32::<4>(&mem, offsets);
+}
+
+#[test]
+#[should_panic(expected = "gather reads outside of the slice")]
+fn _mm256_i64gather_epi64_overflowing_offset() {
+    let mem = [0i64; 8];
+    let offsets = BitVec::from_slice(&[0i64, 1, i64::MAX / 4, 3], 64);
+    super::super::models::avx2::_mm256_i64gather_epi64::<8>(&mem, offsets);
+}
+
+/// Derives tests for a masked gather intrinsic, like `mk_gather!`. The source
+/// and the mask are random, so each test mixes gathered lanes with lanes taken
+/// from the source. The offsets of the masked off lanes point far outside of
+/// the memory: the model panics if it reads them.
+macro_rules! mk_mask_gather {
+    ($name:ident(slice: &[$elem:ty], offsets: [$ity:ty; $lanes:literal], mask: [$mty:ty])) => {
+        #[test]
+        fn $name() {
+            mk_mask_gather!(@scale $name, $elem, $ity, $mty, $lanes, 1);
+            mk_mask_gather!(@scale $name, $elem, $ity, $mty, $lanes, 2);
+            mk_mask_gather!(@scale $name, $elem, $ity, $mty, $lanes, 4);
+            mk_mask_gather!(@scale $name, $elem, $ity, $mty, $lanes, 8);
+        }
+    };
+    (@scale $name:ident, $elem:ty, $ity:ty, $mty:ty, $lanes:literal, $scale:literal) => {
+        for _ in 0..100 {
+            let mem: [$elem; 64] = core::array::from_fn(|_| <$elem>::random());
+            let max = (mem.len() - 1) * size_of::<$elem>() / $scale;
+            let mask: [$mty; $lanes] = core::array::from_fn(|_| <$mty>::random());
+            let offsets: [$ity; $lanes] = core::array::from_fn(|i| {
+                if mask[i] < 0 {
+                    (u64::random() % (max as u64 + 1)) as $ity
+                } else {
+                    <$ity>::MAX
+                }
+            });
+            let src = BitVec::random();
+            let offsets = BitVec::from_slice(&offsets, <$ity>::BITS);
+            let mask = BitVec::from_slice(&mask, <$mty>::BITS);
+            let model = super::super::models::avx2::$name::<$scale>(
+                src.into(),
+                &mem,
+                offsets.into(),
+                mask.into(),
+            );
+            let upstream = unsafe {
+                upstream::$name::<$scale>(src.into(), mem.as_ptr(), offsets.into(), mask.into())
+            };
+            assert_eq!(
+                BitVec::from(model),
+                BitVec::from(upstream),
+                "scale {}, src {:?}, offsets {:?}, mask {:?}",
+                $scale,
+                src,
+                offsets,
+                mask
+            );
+        }
+    };
+}
+
+mk_mask_gather!(_mm_mask_i32gather_epi32(slice: &[i32], offsets: [i32; 4], mask: [i32]));
+mk_mask_gather!(_mm256_mask_i32gather_epi32(slice: &[i32], offsets: [i32; 8], mask: [i32]));
+mk_mask_gather!(_mm256_mask_i64gather_epi64(slice: &[i64], offsets: [i64; 4], mask: [i64]));
+mk_mask_gather!(_mm_mask_i32gather_ps(slice: &[f32], offsets: [i32; 4], mask: [i32]));
+mk_mask_gather!(_mm256_mask_i32gather_ps(slice: &[f32], offsets: [i32; 8], mask: [i32]));
+mk_mask_gather!(_mm256_mask_i64gather_pd(slice: &[f64], offsets: [i64; 4], mask: [i64]));
+
+/// Derives a test for a masked load intrinsic, comparing the model against
+/// upstream on random memory and random masks.
+macro_rules! mk_maskload {
+    ($name:ident(mem_addr: &[$elem:ty; $lanes:literal], mask: $ty:ident)) => {
+        #[test]
+        fn $name() {
+            for _ in 0..1000 {
+                let mem: [$elem; $lanes] = core::array::from_fn(|_| <$elem>::random());
+                let mask: $ty = BitVec::random();
+                let model = super::super::models::avx2::$name(&mem, mask.into());
+                let upstream = unsafe { upstream::$name(mem.as_ptr(), mask.into()) };
+                assert_eq!(
+                    BitVec::from(model),
+                    BitVec::from(upstream),
+                    "{mem:?}, {mask:?}"
+                );
+            }
+        }
+    };
+}
+
+/// Derives a test for a masked store intrinsic. The destination is pre-filled
+/// with a sentinel, and the lanes that are masked off must still hold it after
+/// the store.
+macro_rules! mk_maskstore {
+    ($name:ident(mem_addr: &mut [$elem:ty; $lanes:literal], mask: $ty:ident, a: $ty2:ident)) => {
+        #[test]
+        fn $name() {
+            const SENTINEL: $elem = 0x5a5a5a5a;
+            for _ in 0..1000 {
+                let mask: [$elem; $lanes] = core::array::from_fn(|_| <$elem>::random());
+                let a: $ty2 = BitVec::random();
+                let mut model = [SENTINEL; $lanes];
+                let mut upstream = [SENTINEL; $lanes];
+                super::super::models::avx2::$name(
+                    &mut model,
+                    BitVec::from_slice(&mask, <$elem>::BITS).into(),
+                    a.into(),
+                );
+                unsafe {
+                    upstream::$name(
+                        upstream.as_mut_ptr(),
+                        BitVec::from_slice(&mask, <$elem>::BITS).into(),
+                        a.into(),
+                    )
+                };
+                assert_eq!(model, upstream, "{mask:?}, {a:?}");
+                for i in 0..$lanes {
+                    if mask[i] >= 0 {
+                        assert_eq!(model[i], SENTINEL, "lane {i} is masked off: {mask:?}");
+                    }
+                }
+            }
+        }
+    };
+}
+
+mk_maskload!(_mm_maskload_epi32(mem_addr: &[i32; 4], mask: __m128i));
+mk_maskload!(_mm256_maskload_epi32(mem_addr: &[i32; 8], mask: __m256i));
+mk_maskload!(_mm_maskload_epi64(mem_addr: &[i64; 2], mask: __m128i));
+mk_maskload!(_mm256_maskload_epi64(mem_addr: &[i64; 4], mask: __m256i));
+mk_maskstore!(_mm_maskstore_epi32(mem_addr: &mut [i32; 4], mask: __m128i, a: __m128i));
+mk_maskstore!(_mm256_maskstore_epi32(mem_addr: &mut [i32; 8], mask: __m256i, a: __m256i));
+mk_maskstore!(_mm_maskstore_epi64(mem_addr: &mut [i64; 2], mask: __m128i, a: __m128i));
+mk_maskstore!(_mm256_maskstore_epi64(mem_addr: &mut [i64; 4], mask: __m256i, a: __m256i));
+
+#[test]
+fn _mm256_maskload_epi32_short_slice() {
+    let mem = [1, 2, 3];
+    let mask = BitVec::from_slice(&[-1i32, 0, -1, 0, 0, 0, 0, 0], 32);
+    let r = super::super::models::avx2::_mm256_maskload_epi32(&mem, mask);
+    assert_eq!(r, BitVec::from_slice(&[1i32, 0, 3, 0, 0, 0, 0, 0], 32));
+}
+
+/// Loads from both 32-byte aligned offsets of a buffer, which upstream
+/// requires, and checks the result is the one of an ordinary load.
+#[test]
+fn _mm256_stream_load_si256() {
+    use super::super::models::{avx, avx2};
+    #[repr(align(32))]
+    struct Aligned([u8; 64]);
+    for _ in 0..100 {
+        let mem = Aligned(core::array::from_fn(|_| u8::random()));
+        for offset in [0, 32] {
+            let r = avx2::_mm256_stream_load_si256(&mem.0[offset..]);
+            assert_eq!(r, avx::_mm256_loadu_si256(&mem.0[offset..]));
+            assert_eq!(r, unsafe {
+                BitVec::from(upstream::_mm256_stream_load_si256(
+                    mem.0[offset..].as_ptr() as *const upstream::__m256i
+                ))
+            });
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use super::types::*;
+use super::upstream;
+use crate::abstractions::bitvec::BitVec;
+use crate::helpers::test::HasRandom;
+
+/// Derives tests for a given intrinsics. Test that a given intrinsics and its model compute the same thing over random values (1000 by default).
+macro_rules! mk {
+    ($([$N:literal])?$(#[$mode:ident($fty:ty)])?$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
+        mk!(@test[$($N)?, val$(, $mode $fty)?]$name$({$(<$($c),*>),*})?($($x : $ty),*)$(-> $ret)?);
+    };
+    ($([$N:literal])?$(#[$mode:ident($fty:ty)])?$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : &$ty:ident),+)$(-> $ret:ty)?) => {
+        mk!(@test[$($N)?, ref$(, $mode $fty)?]$name$({$(<$($c),*>),*})?($($x : $ty),*)$(-> $ret)?);
+    };
+    (@test[$($N:literal)?, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
+        #[test]
+        fn $name() {
+            if !std::is_x86_feature_detected!("avx512f") {
+                return;
+            }
+            #[allow(unused)]
+            const N: usize = {
+                let n: usize = 1000;
+                $(let n: usize = $N;)?
+                    n
+            };
+            mk!(@[N, $pass$(, $mode $fty)?]$name$($(<$($c),*>)*)?($($x : $ty),*)$(-> $ret)?);
+        }
+    };
+    (@val $x:ident) => {
+        $x.into()
+    };
+    (@ref $x:ident) => {
+        &$x.into()
+    };
+    (@eq $model:expr, $upstream:expr) => {
+        $model == $upstream
+    };
+    (@eq $model:expr, $upstream:expr, $mode:ident $fty:ty) => {
+        super::mismatched_lanes::<$fty, _>($model, $upstream, mk!(@same $mode)).is_empty()
+    };
+    (@same float) => {
+        super::same_modulo_nan
+    };
+    (@same lanes) => {
+        super::same_bits
+    };
+    (@cmp[$inputs:expr] $model:expr, $upstream:expr) => {
+        assert_eq!($model, $upstream, "Failed with (shrunk) input values: {:?} (seed: {})", $inputs, crate::helpers::test::seed())
+    };
+    (@cmp[$inputs:expr] $model:expr, $upstream:expr, $mode:ident $fty:ty) => {
+        super::assert_lanes_eq::<$fty, _>($model, $upstream, mk!(@same $mode), $inputs)
+    };
+    (@[$N:ident, $pass:ident]$name:ident$(<$($c:tt),*>)?($($x:ident : $ty:ident),*) -> $ret:ty) => {
+        for _ in 0..$N {
+            $(let $x = std::cell::Cell::new($ty::random());)*
+                let fails = || {
+                    $(let $x = $x.get();)*
+                    let model: $ret = super::super::models::avx512f::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*);
+                    let upstream: $ret = unsafe { upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*) };
+                    model != upstream
+                };
+                if super::shrink(&[$(&$x),*], fails) {
+                    $(let $x = $x.get();)*
+                    let inputs: &[&dyn std::fmt::Debug] = &[$(&$x),*];
+                    let model: $ret = super::super::models::avx512f::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*);
+                    let upstream: $ret = unsafe { upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*) };
+                    mk!(@cmp[inputs] model, upstream);
+                }
+        }
+    };
+    (@[$N:ident, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident$(<$($c:tt),*>)?($($x:ident : $ty:ident),*)) => {
+        for _ in 0..$N {
+            $(let $x = std::cell::Cell::new($ty::random());)*
+                let fails = || {
+                    $(let $x = $x.get();)*
+                    !mk!(@eq super::super::models::avx512f::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*), unsafe {
+                        BitVec::from(upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*)).into()
+                    }$(, $mode $fty)?)
+                };
+                if super::shrink(&[$(&$x),*], fails) {
+                    $(let $x = $x.get();)*
+                    let inputs: &[&dyn std::fmt::Debug] = &[$(&$x),*];
+                    mk!(@cmp[inputs] super::super::models::avx512f::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*), unsafe {
+                        BitVec::from(upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*)).into()
+                    }$(, $mode $fty)?);
+                }
+        }
+    };
+    (@[$N:ident, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident<$($c1:tt),*>$(<$($c:tt),*>)*($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
+        let one = || {
+            mk!(@[$N, $pass$(, $mode $fty)?]$name<$($c1),*>($($x : $ty),*)$(-> $ret)?);
+        };
+        one();
+        mk!(@[$N, $pass$(, $mode $fty)?]$name$(<$($c),*>)*($($x : $ty),*)$(-> $ret)?);
+    }
+}
+
+mk!(_mm512_add_epi32(a: __m512i, b: __m512i));
+mk!(_mm512_and_si512(a: __m512i, b: __m512i));
+mk!(_mm512_cmpeq_epi32_mask(a: __m512i, b: __m512i) -> __mmask16);
+
+/// Random vectors are almost never equal lane-wise, so this also compares
+/// vectors that agree on a random subset of their lanes.
+#[test]
+fn _mm512_cmpeq_epi32_mask_partially_equal() {
+    if !std::is_x86_feature_detected!("avx512f") {
+        return;
+    }
+    for _ in 0..1000 {
+        let k = u16::random();
+        let a: [i32; 16] = core::array::from_fn(|_| i32::random());
+        let b: [i32; 16] = core::array::from_fn(|i| {
+            if (k >> i) & 1 == 1 {
+                a[i]
+            } else {
+                i32::random()
+            }
+        });
+        let (a, b): (__m512i, __m512i) = (BitVec::from_slice(&a, 32), BitVec::from_slice(&b, 32));
+        assert_eq!(
+            super::super::models::avx512f::_mm512_cmpeq_epi32_mask(a, b),
+            unsafe { upstream::_mm512_cmpeq_epi32_mask(a.into(), b.into()) },
+            "{a:?}, {b:?}"
+        );
+    }
+}
+
+mk!(_mm512_mask_blend_epi32(k: __mmask16, a: __m512i, b: __m512i));
+mk!(_mm512_set1_epi32(a: i32));
+mk!(_mm512_setzero_si512());
@@ -5,6 +5,10 @@
 //! generating random inputs, passing them as arguments
 //! to both the models in this crate, and the corresponding intrinsics
 //! in the Rust core and then comparing their outputs.
+//! Since the upstream intrinsics are actually executed, the tests of a target
+//! feature (e.g. `avx512f`) can only be run on a CPU supporting it. The
+//! `avx512f` tests check for it at runtime and return early when it is missing,
+//! as an illegal instruction would abort the whole test binary.
 //!
 //! To add a test for a modelled intrinsic, go the appropriate file, and
 //! use the `mk!` macro to define it.
@@ -22,23 +26,327 @@
 //! The const values are necessary if the function has constant arguments, but should be discarded if not.
 //! The function name and the function arguments are necessary in all cases.
 //!
-//! Note: This only works if the function returns a bit-vector or funarray. If it returns an integer, the
-//! test has to be written manually. It is recommended that the manually defined test follows
+//! Intrinsics returning a plain scalar declare its type after the arguments, in which case
+//! the outputs are compared directly instead of going through a bit-vector or funarray, e.g.
+//!
+//! `mk!(_mm256_movemask_epi8(a: __m256i) -> i32);`
+//! `mk!([100]_mm256_extract_epi16{<0>,<1>}(a: __m256i) -> i32);`
+//!
+//! Intrinsics reading their inputs from memory take references instead, written as `&<type>` in the
+//! argument list. A random value of the referenced type is generated, and a reference to that same
+//! value is passed to both the model and the upstream intrinsic, e.g.
+//!
+//! `mk!(_mm256_broadcast_ss(f: &f32));`
+//! `mk!(_mm256_broadcast_ps(a: &__m128));`
+//!
+//! Either all or none of the arguments have to be references.
+//!
+//! Note: This only works if the function returns a bit-vector, a funarray or a scalar. Anything
+//! else has to be tested manually. It is recommended that the manually defined test follows
 //! the pattern of tests defined via the `mk!` invocation. It is also recommended that, in the
 //! case that the intrinsic takes constant arguments, each and every possible constant value
 //! (upto a maximum of 255) that can be passed to the function be used for testing. The number
 //! of constant values passed depends on if the Rust intrinsics statically asserts that the
 //! length of the constant argument be less than or equal to a certain number of bits.
+//! Rather than listing all of those values by hand, use `mk_const_sweep!`, which takes that number
+//! of bits instead of the const values, e.g. `mk_const_sweep!([100]_mm256_alignr_epi8{bits: 8}(a: __m256i, b: __m256i));`.
+//!
+//! Unary intrinsics on 8-bit lanes can additionally be tested on a fixed set of structured inputs
+//! (see `crate::helpers::test::byte_patterns`) with `mk_exhaustive!`, which takes the model module
+//! along with the function name, e.g. `mk_exhaustive!(ssse3::_mm_abs_epi8(a: __m128i));`.
+//!
+//! Intrinsics on floating-point lanes can mark their tests with a `#[float(<lane type>)]` flag,
+//! placed right before the function name, e.g.
+//!
+//! `mk!(#[float(f32)]_mm256_add_ps(a: __m256, b: __m256));`
+//! `mk!([100]#[float(f64)]_mm256_sub_pd(a: __m256d, b: __m256d));`
+//!
+//! With the flag, outputs are compared lane by lane using `eq_modulo_nan` instead of `assert_eq!`:
+//! any two NaNs of the lane's width are taken to be equal (the payload of a NaN produced by the hardware
+//! is not something the models try to reproduce), while every other bit pattern has to match exactly.
+//! In particular, `0.0` and `-0.0` are not taken to be equal, even though they compare equal as floats:
+//! some intrinsics (e.g. `_mm256_min_pd`, which returns its second operand when both are zeros) tell
+//! them apart, and so do the tests.
+//!
+//! Intrinsics that only approximate their result (e.g. `_mm_rcp_ps`) use an `#[approx(<lane type>)]`
+//! flag instead, in which case lanes are compared with `eq_within_relative_error`: NaNs are handled as
+//! above, and other lanes only have to be within a relative error of `2^-11` of each other, as long
+//! as they are neither zeros nor infinities, e.g.
+//!
+//! `mk!(#[approx(f32)]_mm_rcp_ps(a: __m128));`
+//!
+//! Other intrinsics can name their lane type in a `#[lanes(<lane type>)]` flag, e.g.
+//!
+//! `mk!(#[lanes(i16)]_mm_add_epi16(a: __m128i, b: __m128i));`
+//!
+//! Outputs are then still compared bit for bit, but should they differ, the failure message lists
+//! each lane that does, with its index and its value in both outputs, instead of printing the two
+//! whole bit-vectors (see `assert_lanes_eq`). Tests flagged with `#[float]` or `#[approx]` report
+//! their failures the same way.
+//!
+//! The random inputs are drawn from a generator seeded with `crate::helpers::test::DEFAULT_SEED`,
+//! so every run of a test sees the same inputs. A different seed can be picked by setting the
+//! `SIMD_TEST_SEED` environment variable, e.g. `SIMD_TEST_SEED=42 cargo test`. The seed is printed
+//! at the start of each test, and is part of the failure message along with the inputs, so a
+//! failing case can be replayed by re-running the test with that seed.
+//!
+//! Before a failure is reported, the offending inputs are shrunk (see `shrink`): bytes of bit-vectors
+//! and scalar arguments are repeatedly zeroed or halved for as long as the model and the upstream
+//! intrinsic still disagree, so that the failure message shows the smallest input found.
 
 mod avx;
 mod avx2;
+mod avx512f;
+mod sse;
 mod sse2;
+mod sse41;
+mod sse42;
 mod ssse3;
+use crate::abstractions::bit::MachineNumeric;
 use crate::abstractions::bitvec::*;
+use crate::abstractions::simd::Float;
+use std::cell::Cell;
+use std::fmt::Debug;
+
+/// Whether two lanes are bitwise equal.
+pub(crate) fn same_bits<T: MachineNumeric>(x: T, y: T) -> bool {
+    x.to_u128() == y.to_u128()
+}
+
+/// Whether two lanes are equal for `eq_modulo_nan`: two NaNs are, regardless
+/// of their sign and payload, other lanes have to be bitwise equal.
+pub(crate) fn same_modulo_nan<T: Float>(x: T, y: T) -> bool {
+    (x.is_nan() && y.is_nan()) || same_bits(x, y)
+}
+
+/// Whether two lanes are equal for `eq_within_relative_error`: two NaNs are,
+/// and so are two finite non-zero lanes whose relative difference is below
+/// `2^-11`. Zeros and infinities have to be bitwise equal, sign included.
+pub(crate) fn within_relative_error<T: Float + Into<f64>>(x: T, y: T) -> bool {
+    let (x, y): (f64, f64) = (x.into(), y.into());
+    if x.is_nan() || y.is_nan() {
+        x.is_nan() && y.is_nan()
+    } else if y == 0.0 || y.is_infinite() {
+        x.to_bits() == y.to_bits()
+    } else {
+        ((x - y) / y).abs() < 2f64.powi(-11)
+    }
+}
+
+/// Compares two bit-vectors lane by lane, with lanes of type `T`. Two NaN
+/// lanes are considered equal regardless of their sign and payload, all other
+/// lanes have to be bitwise equal.
+pub(crate) fn eq_modulo_nan<T: Float, const N: u32>(model: BitVec<N>, upstream: BitVec<N>) -> bool {
+    mismatched_lanes::<T, N>(model, upstream, same_modulo_nan).is_empty()
+}
+
+/// Compares two bit-vectors lane by lane, with lanes of type `T`, for
+/// intrinsics that only approximate their result (see `within_relative_error`).
+pub(crate) fn eq_within_relative_error<T: Float + Into<f64>, const N: u32>(
+    model: BitVec<N>,
+    upstream: BitVec<N>,
+) -> bool {
+    mismatched_lanes::<T, N>(model, upstream, within_relative_error).is_empty()
+}
+
+/// Decodes two bit-vectors into lanes of type `T`, and returns the index and
+/// both values of every lane on which `same` does not hold.
+pub(crate) fn mismatched_lanes<T: MachineNumeric + Copy, const N: u32>(
+    model: BitVec<N>,
+    upstream: BitVec<N>,
+    same: impl Fn(T, T) -> bool,
+) -> Vec<(usize, T, T)> {
+    model
+        .to_vec::<T>()
+        .into_iter()
+        .zip(upstream.to_vec::<T>())
+        .enumerate()
+        .filter(|&(_, (x, y))| !same(x, y))
+        .map(|(i, (x, y))| (i, x, y))
+        .collect()
+}
+
+/// Asserts that `same` holds on every lane of `model` and `upstream`, decoded
+/// into lanes of type `T`. This is what `mk!` uses when given a lane type: on
+/// failure, the message lists each lane that differs, with its value in both
+/// results, followed by the inputs and the seed of the test, e.g.
+///
+/// ```text
+/// 1 of 8 lanes differ:
+///   lane 5: model 2, upstream 1
+/// Failed with (shrunk) input values: [..] (seed: ..)
+/// ```
+#[track_caller]
+pub(crate) fn assert_lanes_eq<T: MachineNumeric + Copy + Debug, const N: u32>(
+    model: BitVec<N>,
+    upstream: BitVec<N>,
+    same: impl Fn(T, T) -> bool,
+    inputs: &[&dyn Debug],
+) {
+    let mismatches = mismatched_lanes(model, upstream, same);
+    if !mismatches.is_empty() {
+        let lanes: String = mismatches
+            .iter()
+            .map(|(i, x, y)| format!("  lane {i}: model {x:?}, upstream {y:?}\n"))
+            .collect();
+        panic!(
+            "{} of {} lanes differ:\n{lanes}Failed with (shrunk) input values: {inputs:?} (seed: {})",
+            mismatches.len(),
+            N / T::BITS,
+            crate::helpers::test::seed()
+        );
+    }
+}
+
+/// Derives a test of the shifts by a register count of the given model file,
+/// each listed with its lane width and whether it is arithmetic, e.g.
+///
+/// `mk_shift_count_test!(_mm_sll_counts: sse2, __m128i, [(_mm_sll_epi16, 16, false)]);`
+///
+/// The shift count is the whole lower 64 bits of `count`, whose upper bits are
+/// ignored, and counts of at least the lane width shift every bit out, giving
+/// zeros, or copies of the sign bit for the arithmetic shifts, rather than
+/// being reduced modulo the width. Random counts are almost always too large,
+/// so the test goes through the ones around each width, with random upper bits.
+macro_rules! mk_shift_count_test {
+    ($test:ident: $module:ident, $vty:ty, [$(($name:ident, $width:literal, $arithmetic:literal)),* $(,)?]) => {
+        #[test]
+        fn $test() {
+            use $crate::abstractions::bitvec::BitVec;
+            use $crate::helpers::test::HasRandom;
+            use super::super::models::$module;
+            const COUNTS: [u64; 15] = [0, 1, 7, 8, 15, 16, 17, 31, 32, 33, 63, 64, 255, 1 << 32, u64::MAX];
+            for _ in 0..100 {
+                let a: $vty = BitVec::random();
+                for c in COUNTS {
+                    let count: BitVec<128> = BitVec::from_slice(&[c, u64::random()], 64);
+                    $(
+                    let r = $module::$name(a, count);
+                    assert_eq!(
+                        r,
+                        unsafe { BitVec::from(upstream::$name(a.into(), count.into())) },
+                        "{} with count {c}",
+                        stringify!($name)
+                    );
+                    if c >= $width && !$arithmetic {
+                        assert_eq!(r, BitVec::ZERO(), "{} with count {c}", stringify!($name));
+                    }
+                    )*
+                }
+            }
+        }
+    };
+}
+pub(crate) use mk_shift_count_test;
+
+/// Inputs that can be made simpler while looking for a minimal counterexample.
+pub(crate) trait Shrink: Copy {
+    /// Returns the values that are one step simpler than `self`. Each of them
+    /// has to be strictly simpler, so that shrinking eventually terminates.
+    fn candidates(&self) -> Vec<Self>;
+}
+
+impl<const N: u32> Shrink for BitVec<N> {
+    /// Zeroes or halves one of the bytes of the bit-vector.
+    fn candidates(&self) -> Vec<Self> {
+        let bytes: Vec<u8> = self.to_vec();
+        let mut candidates = vec![];
+        for (i, &byte) in bytes.iter().enumerate() {
+            let smaller = match byte {
+                0 => vec![],
+                1 => vec![0],
+                _ => vec![0, byte >> 1],
+            };
+            for smaller in smaller {
+                let mut bytes = bytes.clone();
+                bytes[i] = smaller;
+                candidates.push(BitVec::from_slice(&bytes, 8));
+            }
+        }
+        candidates
+    }
+}
+
+macro_rules! shrink_int {
+    ($($ty:ty),*) => {
+        $(impl Shrink for $ty {
+            fn candidates(&self) -> Vec<Self> {
+                match *self {
+                    0 => vec![],
+                    x if x / 2 == 0 => vec![0],
+                    x => vec![0, x / 2],
+                }
+            }
+        })*
+    };
+}
+shrink_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+macro_rules! shrink_float {
+    ($($ty:ty),*) => {
+        $(impl Shrink for $ty {
+            fn candidates(&self) -> Vec<Self> {
+                let half = *self / 2.0;
+                let mut candidates = vec![];
+                if self.to_bits() != 0 {
+                    candidates.push(0.0);
+                }
+                if half.to_bits() != 0 && half.to_bits() != self.to_bits() {
+                    candidates.push(half);
+                }
+                candidates
+            }
+        })*
+    };
+}
+shrink_float!(f32, f64);
+
+/// Greedily shrinks `inputs` as long as `fails` keeps holding, and returns
+/// whether it held for the original inputs. When it returns `true`, `inputs`
+/// hold a counterexample that cannot be shrunk any further.
+///
+/// This is used by `mk!` to minimize inputs on which a model and the upstream
+/// intrinsic disagree, and only does any work if they do.
+pub(crate) fn shrink(inputs: &[&dyn ShrinkInput], fails: impl Fn() -> bool) -> bool {
+    if !fails() {
+        return false;
+    }
+    while inputs.iter().any(|input| input.shrink_step(&fails)) {}
+    true
+}
+
+/// An input of a test, being shrunk in place. See `shrink`.
+pub(crate) trait ShrinkInput {
+    /// Replaces the input by the first of its candidates for which `fails`
+    /// still holds, returning whether there was one.
+    fn shrink_step(&self, fails: &dyn Fn() -> bool) -> bool;
+}
+
+impl<T: Shrink> ShrinkInput for Cell<T> {
+    fn shrink_step(&self, fails: &dyn Fn() -> bool) -> bool {
+        let original = self.get();
+        for candidate in original.candidates() {
+            self.set(candidate);
+            if fails() {
+                return true;
+            }
+        }
+        self.set(original);
+        false
+    }
+}
 
 pub(crate) mod types {
     use crate::abstractions::bitvec::*;
 
+    #[allow(non_camel_case_types)]
+    pub type __m512i = BitVec<512>;
+    #[allow(non_camel_case_types)]
+    pub type __m512 = BitVec<512>;
+    #[allow(non_camel_case_types)]
+    pub type __m512d = BitVec<512>;
+    #[allow(non_camel_case_types)]
+    pub type __mmask16 = u16;
     #[allow(non_camel_case_types)]
     pub type __m256i = BitVec<256>;
     #[allow(non_camel_case_types)]
@@ -62,13 +370,64 @@ pub(crate) mod upstream {
 
 mod conversions {
     use super::upstream::{
-        __m128, __m128d, __m128i, __m256, __m256d, __m256i, _mm256_castpd_si256,
-        _mm256_castps_si256, _mm256_castsi256_pd, _mm256_castsi256_ps, _mm256_loadu_si256,
-        _mm256_storeu_si256, _mm_castpd_si128, _mm_castps_si128, _mm_castsi128_pd,
-        _mm_castsi128_ps, _mm_loadu_si128, _mm_storeu_si128,
+        __m128, __m128d, __m128i, __m256, __m256d, __m256i, __m512, __m512d, __m512i,
+        _mm256_castpd_si256, _mm256_castps_si256, _mm256_castsi256_pd, _mm256_castsi256_ps,
+        _mm256_loadu_si256, _mm256_storeu_si256, _mm512_castpd_si512, _mm512_castps_si512,
+        _mm512_castsi512_pd, _mm512_castsi512_ps, _mm512_loadu_si512, _mm512_storeu_si512,
+        _mm_castpd_si128, _mm_castps_si128, _mm_castsi128_pd, _mm_castsi128_ps, _mm_loadu_si128,
+        _mm_storeu_si128,
     };
     use super::BitVec;
 
+    impl From<BitVec<512>> for __m512i {
+        fn from(bv: BitVec<512>) -> __m512i {
+            let bv: &[u8] = &bv.to_vec()[..];
+            unsafe { _mm512_loadu_si512(bv.as_ptr() as *const _) }
+        }
+    }
+    impl From<BitVec<512>> for __m512 {
+        fn from(bv: BitVec<512>) -> __m512 {
+            let bv: &[u8] = &bv.to_vec()[..];
+            unsafe { _mm512_castsi512_ps(_mm512_loadu_si512(bv.as_ptr() as *const _)) }
+        }
+    }
+    impl From<BitVec<512>> for __m512d {
+        fn from(bv: BitVec<512>) -> __m512d {
+            let bv: &[u8] = &bv.to_vec()[..];
+            unsafe { _mm512_castsi512_pd(_mm512_loadu_si512(bv.as_ptr() as *const _)) }
+        }
+    }
+
+    impl From<__m512i> for BitVec<512> {
+        fn from(vec: __m512i) -> BitVec<512> {
+            let mut v = [0u8; 64];
+            unsafe {
+                _mm512_storeu_si512(v.as_mut_ptr() as *mut _, vec);
+            }
+            BitVec::from_slice(&v[..], 8)
+        }
+    }
+
+    impl From<__m512> for BitVec<512> {
+        fn from(vec: __m512) -> BitVec<512> {
+            let mut v = [0u8; 64];
+            unsafe {
+                _mm512_storeu_si512(v.as_mut_ptr() as *mut _, _mm512_castps_si512(vec));
+            }
+            BitVec::from_slice(&v[..], 8)
+        }
+    }
+
+    impl From<__m512d> for BitVec<512> {
+        fn from(vec: __m512d) -> BitVec<512> {
+            let mut v = [0u8; 64];
+            unsafe {
+                _mm512_storeu_si512(v.as_mut_ptr() as *mut _, _mm512_castpd_si512(vec));
+            }
+            BitVec::from_slice(&v[..], 8)
+        }
+    }
+
     impl From<BitVec<256>> for __m256i {
         fn from(bv: BitVec<256>) -> __m256i {
             let bv: &[u8] = &bv.to_vec()[..];
@@ -0,0 +1,402 @@
+use super::types::*;
+use super::upstream;
+use crate::abstractions::bitvec::BitVec;
+use crate::abstractions::simd::f32x4;
+use crate::helpers::test::mk_const_sweep;
+use crate::helpers::test::HasRandom;
+
+/// Derives tests for a given intrinsics. Test that a given intrinsics and its model compute the same thing over random values (1000 by default).
+macro_rules! mk {
+    ($([$N:literal])?$(#[$mode:ident($fty:ty)])?$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
+        mk!(@test[$($N)?, val$(, $mode $fty)?]$name$({$(<$($c),*>),*})?($($x : $ty),*)$(-> $ret)?);
+    };
+    ($([$N:literal])?$(#[$mode:ident($fty:ty)])?$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : &$ty:ident),+)$(-> $ret:ty)?) => {
+        mk!(@test[$($N)?, ref$(, $mode $fty)?]$name$({$(<$($c),*>),*})?($($x : $ty),*)$(-> $ret)?);
+    };
+    (@test[$($N:literal)?, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
+        #[test]
+        fn $name() {
+            #[allow(unused)]
+            const N: usize = {
+                let n: usize = 1000;
+                $(let n: usize = $N;)?
+                    n
+            };
+            mk!(@[N, $pass$(, $mode $fty)?]$name$($(<$($c),*>)*)?($($x : $ty),*)$(-> $ret)?);
+        }
+    };
+    (@val $x:ident) => {
+        $x.into()
+    };
+    (@ref $x:ident) => {
+        &$x.into()
+    };
+    (@eq $model:expr, $upstream:expr) => {
+        $model == $upstream
+    };
+    (@eq $model:expr, $upstream:expr, $mode:ident $fty:ty) => {
+        super::mismatched_lanes::<$fty, _>($model, $upstream, mk!(@same $mode)).is_empty()
+    };
+    (@same float) => {
+        super::same_modulo_nan
+    };
+    (@same lanes) => {
+        super::same_bits
+    };
+    (@same approx) => {
+        super::within_relative_error
+    };
+    (@cmp[$inputs:expr] $model:expr, $upstream:expr) => {
+        assert_eq!($model, $upstream, "Failed with (shrunk) input values: {:?} (seed: {})", $inputs, crate::helpers::test::seed())
+    };
+    (@cmp[$inputs:expr] $model:expr, $upstream:expr, $mode:ident $fty:ty) => {
+        super::assert_lanes_eq::<$fty, _>($model, $upstream, mk!(@same $mode), $inputs)
+    };
+    (@[$N:ident, $pass:ident]$name:ident$(<$($c:tt),*>)?($($x:ident : $ty:ident),*) -> $ret:ty) => {
+        for _ in 0..$N {
+            $(let $x = std::cell::Cell::new($ty::random());)*
+                let fails = || {
+                    $(let $x = $x.get();)*
+                    let model: $ret = super::super::models::sse::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*);
+                    let upstream: $ret = unsafe { upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*) };
+                    model != upstream
+                };
+                if super::shrink(&[$(&$x),*], fails) {
+                    $(let $x = $x.get();)*
+                    let inputs: &[&dyn std::fmt::Debug] = &[$(&$x),*];
+                    let model: $ret = super::super::models::sse::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*);
+                    let upstream: $ret = unsafe { upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*) };
+                    mk!(@cmp[inputs] model, upstream);
+                }
+        }
+    };
+    (@[$N:ident, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident$(<$($c:tt),*>)?($($x:ident : $ty:ident),*)) => {
+        for _ in 0..$N {
+            $(let $x = std::cell::Cell::new($ty::random());)*
+                let fails = || {
+                    $(let $x = $x.get();)*
+                    !mk!(@eq super::super::models::sse::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*), unsafe {
+                        BitVec::from(upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*)).into()
+                    }$(, $mode $fty)?)
+                };
+                if super::shrink(&[$(&$x),*], fails) {
+                    $(let $x = $x.get();)*
+                    let inputs: &[&dyn std::fmt::Debug] = &[$(&$x),*];
+                    mk!(@cmp[inputs] super::super::models::sse::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*), unsafe {
+                        BitVec::from(upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*)).into()
+                    }$(, $mode $fty)?);
+                }
+        }
+    };
+    (@[$N:ident, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident<$($c1:tt),*>$(<$($c:tt),*>)*($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
+        let one = || {
+            mk!(@[$N, $pass$(, $mode $fty)?]$name<$($c1),*>($($x : $ty),*)$(-> $ret)?);
+        };
+        one();
+        mk!(@[$N, $pass$(, $mode $fty)?]$name$(<$($c),*>)*($($x : $ty),*)$(-> $ret)?);
+    }
+}
+mk!(#[float(f32)]_mm_add_ss(a: __m128, b: __m128));
+mk!(#[float(f32)]_mm_add_ps(a: __m128, b: __m128));
+mk!(#[float(f32)]_mm_sub_ss(a: __m128, b: __m128));
+mk!(#[float(f32)]_mm_sub_ps(a: __m128, b: __m128));
+mk!(#[float(f32)]_mm_mul_ss(a: __m128, b: __m128));
+mk!(#[float(f32)]_mm_mul_ps(a: __m128, b: __m128));
+mk!(#[float(f32)]_mm_div_ss(a: __m128, b: __m128));
+mk!(#[float(f32)]_mm_div_ps(a: __m128, b: __m128));
+mk!(_mm_min_ss(a: __m128, b: __m128));
+mk!(_mm_min_ps(a: __m128, b: __m128));
+mk!(_mm_max_ss(a: __m128, b: __m128));
+mk!(_mm_max_ps(a: __m128, b: __m128));
+mk!(#[approx(f32)]_mm_rcp_ss(a: __m128));
+mk!(#[approx(f32)]_mm_rcp_ps(a: __m128));
+mk!(#[approx(f32)]_mm_rsqrt_ss(a: __m128));
+mk!(#[approx(f32)]_mm_rsqrt_ps(a: __m128));
+mk!(_mm_cmpeq_ss(a: __m128, b: __m128));
+mk!(_mm_cmplt_ss(a: __m128, b: __m128));
+mk!(_mm_cmple_ss(a: __m128, b: __m128));
+mk!(_mm_cmpgt_ss(a: __m128, b: __m128));
+mk!(_mm_cmpge_ss(a: __m128, b: __m128));
+mk!(_mm_cmpneq_ss(a: __m128, b: __m128));
+mk!(_mm_cmpnlt_ss(a: __m128, b: __m128));
+mk!(_mm_cmpnle_ss(a: __m128, b: __m128));
+mk!(_mm_cmpngt_ss(a: __m128, b: __m128));
+mk!(_mm_cmpnge_ss(a: __m128, b: __m128));
+mk!(_mm_cmpord_ss(a: __m128, b: __m128));
+mk!(_mm_cmpunord_ss(a: __m128, b: __m128));
+mk!(_mm_cmpeq_ps(a: __m128, b: __m128));
+mk!(_mm_cmplt_ps(a: __m128, b: __m128));
+mk!(_mm_cmple_ps(a: __m128, b: __m128));
+mk!(_mm_cmpgt_ps(a: __m128, b: __m128));
+mk!(_mm_cmpge_ps(a: __m128, b: __m128));
+mk!(_mm_cmpneq_ps(a: __m128, b: __m128));
+mk!(_mm_cmpnlt_ps(a: __m128, b: __m128));
+mk!(_mm_cmpnle_ps(a: __m128, b: __m128));
+mk!(_mm_cmpngt_ps(a: __m128, b: __m128));
+mk!(_mm_cmpnge_ps(a: __m128, b: __m128));
+mk!(_mm_cmpord_ps(a: __m128, b: __m128));
+mk!(_mm_cmpunord_ps(a: __m128, b: __m128));
+
+/// When either lane is a NaN, or both are zeros, the minimum and the maximum
+/// are the lane of `b`, bit for bit, so they are not symmetric: swapping the
+/// operands swaps the NaN and the sign of the zero in the result. The scalar
+/// forms do the same on lane 0, and keep lanes 1 to 3 of `a`.
+#[test]
+fn _mm_min_max_asymmetry() {
+    use super::super::models::sse;
+    let nan = f32::from_bits(0x7fc0_1234);
+    let a: __m128 = BitVec::from(f32x4::new(nan, 3.0, 0.0, -0.0));
+    let b: __m128 = BitVec::from(f32x4::new(3.0, nan, -0.0, 0.0));
+    for (a, b) in [(a, b), (b, a)] {
+        macro_rules! check {
+            ($($name:ident),*) => {($({
+                let r = sse::$name(a, b);
+                assert_eq!(r, unsafe { BitVec::from(upstream::$name(a.into(), b.into())) }, "{}", stringify!($name));
+                r
+            }),*)};
+        }
+        let (min_ps, max_ps, min_ss, max_ss) =
+            check!(_mm_min_ps, _mm_max_ps, _mm_min_ss, _mm_max_ss);
+        assert_eq!(min_ps, b);
+        assert_eq!(max_ps, b);
+        let (a, b) = (a.to_vec::<u32>(), b.to_vec::<u32>());
+        for r in [min_ss, max_ss] {
+            let r = r.to_vec::<u32>();
+            assert_eq!(r[0], b[0]);
+            assert_eq!(r[1..], a[1..]);
+        }
+    }
+}
+
+/// Random lanes are almost never equal, nor NaNs, so this goes through every
+/// pair of a NaN, a zero of either sign, and finite or infinite values, in both
+/// orders, against every predicate. The scalar forms keep lanes 1 to 3 of `a`
+/// bit for bit.
+#[test]
+fn _mm_cmp_special_values() {
+    use super::super::models::sse;
+    let values = [
+        f32::NAN,
+        f32::from_bits(0xffc0_1234),
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+    ];
+    for x in values {
+        for y in values {
+            let a: __m128 = BitVec::from(f32x4::new(x, y, x, f32::from_bits(0x7fc0_5678)));
+            let b: __m128 = BitVec::from(f32x4::new(y, x, x, y));
+            macro_rules! check {
+                ($($name:ident),*) => {$({
+                    let r = sse::$name(a, b);
+                    assert_eq!(r, unsafe { BitVec::from(upstream::$name(a.into(), b.into())) }, "{} with {x} and {y}", stringify!($name));
+                    if stringify!($name).ends_with("_ss") {
+                        assert_eq!(r.to_vec::<u32>()[1..], a.to_vec::<u32>()[1..], "{}", stringify!($name));
+                    }
+                })*};
+            }
+            check!(
+                _mm_cmpeq_ss,
+                _mm_cmplt_ss,
+                _mm_cmple_ss,
+                _mm_cmpgt_ss,
+                _mm_cmpge_ss,
+                _mm_cmpneq_ss,
+                _mm_cmpnlt_ss,
+                _mm_cmpnle_ss,
+                _mm_cmpngt_ss,
+                _mm_cmpnge_ss,
+                _mm_cmpord_ss,
+                _mm_cmpunord_ss,
+                _mm_cmpeq_ps,
+                _mm_cmplt_ps,
+                _mm_cmple_ps,
+                _mm_cmpgt_ps,
+                _mm_cmpge_ps,
+                _mm_cmpneq_ps,
+                _mm_cmpnlt_ps,
+                _mm_cmpnle_ps,
+                _mm_cmpngt_ps,
+                _mm_cmpnge_ps,
+                _mm_cmpord_ps,
+                _mm_cmpunord_ps
+            );
+        }
+    }
+}
+
+/// The scalar forms only compute lane 0, and copy lanes 1 to 3 of `a` bit for
+/// bit, NaN payloads included, which the NaN-tolerant comparison of `mk!` would
+/// not notice.
+#[test]
+fn _mm_arith_ss_upper_lanes() {
+    use super::super::models::sse;
+    for _ in 0..1000 {
+        let (a, b): (__m128, __m128) = (BitVec::random(), BitVec::random());
+        for (r, lane0) in [
+            (
+                sse::_mm_add_ss(a, b),
+                f32::from_bits(a.to_vec::<u32>()[0]) + f32::from_bits(b.to_vec::<u32>()[0]),
+            ),
+            (
+                sse::_mm_sub_ss(a, b),
+                f32::from_bits(a.to_vec::<u32>()[0]) - f32::from_bits(b.to_vec::<u32>()[0]),
+            ),
+            (
+                sse::_mm_mul_ss(a, b),
+                f32::from_bits(a.to_vec::<u32>()[0]) * f32::from_bits(b.to_vec::<u32>()[0]),
+            ),
+            (
+                sse::_mm_div_ss(a, b),
+                f32::from_bits(a.to_vec::<u32>()[0]) / f32::from_bits(b.to_vec::<u32>()[0]),
+            ),
+        ] {
+            let (r, a) = (r.to_vec::<u32>(), a.to_vec::<u32>());
+            assert_eq!(r[1..], a[1..], "{a:x?}");
+            let r0 = f32::from_bits(r[0]);
+            assert!(
+                (r0.is_nan() && lane0.is_nan()) || r0 == lane0,
+                "{r0} != {lane0}"
+            );
+        }
+    }
+}
+
+/// Random inputs rarely hit zeros, subnormals (which the approximations take to
+/// be zeros), infinities, or the largest finite value (whose reciprocal would
+/// be subnormal, and is flushed to zero), which all have exact results, nor the
+/// negative values that have no reciprocal square root. The scalar forms
+/// keep lanes 1 to 3 of `a` bit for bit, which the relative-error comparison of
+/// `mk!` would not check on NaN lanes.
+#[test]
+fn _mm_rcp_rsqrt_special_values() {
+    use super::super::models::sse;
+    let values = [
+        0.0,
+        -0.0,
+        f32::from_bits(1),
+        -f32::from_bits(0x007f_ffff),
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        -1.0,
+        f32::MAX,
+    ];
+    for lanes in values.chunks(4) {
+        let a: __m128 = BitVec::from(f32x4::from_fn(|i| lanes[i as usize]));
+        for (model, upstream) in [
+            (sse::_mm_rcp_ps(a), unsafe {
+                upstream::_mm_rcp_ps(a.into())
+            }),
+            (sse::_mm_rsqrt_ps(a), unsafe {
+                upstream::_mm_rsqrt_ps(a.into())
+            }),
+        ] {
+            assert!(
+                super::eq_within_relative_error::<f32, _>(model, BitVec::from(upstream)),
+                "{model:?} != {upstream:?}"
+            );
+        }
+    }
+    for _ in 0..1000 {
+        let a: __m128 = BitVec::random();
+        for r in [sse::_mm_rcp_ss(a), sse::_mm_rsqrt_ss(a)] {
+            assert_eq!(r.to_vec::<u32>()[1..], a.to_vec::<u32>()[1..]);
+        }
+    }
+}
+
+/// The lowest lane is extracted bit for bit, including NaNs of any sign and
+/// payload, which random inputs almost never have in lane 0.
+#[test]
+fn _mm_cvtss_f32() {
+    let nans = [f32::NAN, -f32::NAN, f32::from_bits(0x7f80_0001)];
+    let inputs = (0..1000)
+        .map(|_| BitVec::random())
+        .chain(nans.map(|nan| BitVec::from(f32x4::new(nan, 1.0, 2.0, 3.0))));
+    for a in inputs {
+        let a: __m128 = a;
+        assert_eq!(
+            super::super::models::sse::_mm_cvtss_f32(a).to_bits(),
+            unsafe { upstream::_mm_cvtss_f32(a.into()) }.to_bits()
+        );
+    }
+}
+
+mk!(_mm_set1_ps(a: f32));
+mk!(_mm_set_ps(a: f32, b: f32, c: f32, d: f32));
+mk!(_mm_setr_ps(a: f32, b: f32, c: f32, d: f32));
+mk!(_mm_setzero_ps());
+
+/// `_mm_set_ps` takes its arguments from the highest lane down and
+/// `_mm_setr_ps` from the lowest up, so they give each other's lanes reversed,
+/// and `_mm_set1_ps` puts its argument, NaNs included, in every lane.
+#[test]
+fn _mm_set_ps_order() {
+    use super::super::models::sse;
+    for _ in 0..1000 {
+        let [a, b, c, d] = [0; 4].map(|_| f32::from_bits(u32::random()));
+        let set = sse::_mm_set_ps(a, b, c, d);
+        assert_eq!(set, sse::_mm_setr_ps(d, c, b, a));
+        assert_eq!(set.to_vec::<u32>(), [d, c, b, a].map(f32::to_bits));
+        assert_eq!(set, unsafe {
+            BitVec::from(upstream::_mm_setr_ps(d, c, b, a))
+        });
+        assert_eq!(sse::_mm_set1_ps(a).to_vec::<u32>(), [a.to_bits(); 4]);
+        assert_eq!(sse::_mm_set1_ps(a), unsafe {
+            BitVec::from(upstream::_mm_set_ps1(a))
+        });
+    }
+}
+
+mk_const_sweep!([100]_mm_shuffle_ps{bits: 8}(a: __m128, b: __m128));
+mk!(_mm_unpackhi_ps(a: __m128, b: __m128));
+mk!(_mm_unpacklo_ps(a: __m128, b: __m128));
+mk!(_mm_movehl_ps(a: __m128, b: __m128));
+mk!(_mm_movelh_ps(a: __m128, b: __m128));
+mk!(_mm_movemask_ps(a: __m128) -> i32);
+
+/// Goes through every combination of sign bits, on lanes that are zeros, NaNs,
+/// infinities or plain values, so that the mask only depends on the sign bit
+/// and not on how the lane compares to zero.
+#[test]
+fn _mm_movemask_ps_signs() {
+    let magnitudes = [0.0, f32::NAN, f32::INFINITY, 1.5, f32::from_bits(1)];
+    for mask in 0..16 {
+        for magnitude in magnitudes {
+            let a: __m128 = BitVec::from(f32x4::from_fn(|i| {
+                if mask >> i & 1 == 1 {
+                    -magnitude
+                } else {
+                    magnitude
+                }
+            }));
+            let model = super::super::models::sse::_mm_movemask_ps(a);
+            assert_eq!(model, unsafe { upstream::_mm_movemask_ps(a.into()) });
+            assert_eq!(model, mask);
+        }
+    }
+}
+
+/// With the lanes of `a` numbered 0 to 3 and those of `b` 10 to 13, the result
+/// spells out which lane ended up where.
+#[test]
+fn _mm_unpack_ps_indices() {
+    use super::super::models::sse;
+    let a: __m128 = BitVec::from(f32x4::new(0.0, 1.0, 2.0, 3.0));
+    let b: __m128 = BitVec::from(f32x4::new(10.0, 11.0, 12.0, 13.0));
+    for (r, expected) in [
+        (sse::_mm_unpackhi_ps(a, b), [2.0, 12.0, 3.0, 13.0]),
+        (sse::_mm_unpacklo_ps(a, b), [0.0, 10.0, 1.0, 11.0]),
+    ] {
+        assert_eq!(r.to_vec::<f32>(), expected);
+    }
+    assert_eq!(sse::_mm_unpackhi_ps(a, b), unsafe {
+        BitVec::from(upstream::_mm_unpackhi_ps(a.into(), b.into()))
+    });
+    assert_eq!(sse::_mm_unpacklo_ps(a, b), unsafe {
+        BitVec::from(upstream::_mm_unpacklo_ps(a.into(), b.into()))
+    });
+}
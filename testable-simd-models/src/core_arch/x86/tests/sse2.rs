@@ -1,11 +1,18 @@
 use super::types::*;
 use super::upstream;
 use crate::abstractions::bitvec::BitVec;
-use crate::helpers::test::HasRandom;
+use crate::abstractions::simd::{f32x4, f64x2, i16x8, u8x16};
+use crate::helpers::test::{HasRandom, HasRandomBiased};
 
 /// Derives tests for a given intrinsics. Test that a given intrinsics and its model compute the same thing over random values (1000 by default).
 macro_rules! mk {
-    ($([$N:literal])?$name:ident$({$(<$($c:literal),*>),*})?($($x:ident : $ty:ident),*)) => {
+    ($([$N:literal])?$(#[$mode:ident($fty:ty)])?$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
+        mk!(@test[$($N)?, val$(, $mode $fty)?]$name$({$(<$($c),*>),*})?($($x : $ty),*)$(-> $ret)?);
+    };
+    ($([$N:literal])?$(#[$mode:ident($fty:ty)])?$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : &$ty:ident),+)$(-> $ret:ty)?) => {
+        mk!(@test[$($N)?, ref$(, $mode $fty)?]$name$({$(<$($c),*>),*})?($($x : $ty),*)$(-> $ret)?);
+    };
+    (@test[$($N:literal)?, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
         #[test]
         fn $name() {
             #[allow(unused)]
@@ -14,27 +21,98 @@ macro_rules! mk {
                 $(let n: usize = $N;)?
                     n
             };
-            mk!(@[N]$name$($(<$($c),*>)*)?($($x : $ty),*));
+            mk!(@[N, $pass$(, $mode $fty)?]$name$($(<$($c),*>)*)?($($x : $ty),*)$(-> $ret)?);
+        }
+    };
+    (@val $x:ident) => {
+        $x.into()
+    };
+    (@ref $x:ident) => {
+        &$x.into()
+    };
+    (@eq $model:expr, $upstream:expr) => {
+        $model == $upstream
+    };
+    (@eq $model:expr, $upstream:expr, $mode:ident $fty:ty) => {
+        super::mismatched_lanes::<$fty, _>($model, $upstream, mk!(@same $mode)).is_empty()
+    };
+    (@same float) => {
+        super::same_modulo_nan
+    };
+    (@same lanes) => {
+        super::same_bits
+    };
+    (@cmp[$inputs:expr] $model:expr, $upstream:expr) => {
+        assert_eq!($model, $upstream, "Failed with (shrunk) input values: {:?} (seed: {})", $inputs, crate::helpers::test::seed())
+    };
+    (@cmp[$inputs:expr] $model:expr, $upstream:expr, $mode:ident $fty:ty) => {
+        super::assert_lanes_eq::<$fty, _>($model, $upstream, mk!(@same $mode), $inputs)
+    };
+    (@[$N:ident, $pass:ident]$name:ident$(<$($c:tt),*>)?($($x:ident : $ty:ident),*) -> $ret:ty) => {
+        for _ in 0..$N {
+            $(let $x = std::cell::Cell::new($ty::random());)*
+                let fails = || {
+                    $(let $x = $x.get();)*
+                    let model: $ret = super::super::models::sse2::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*);
+                    let upstream: $ret = unsafe { upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*) };
+                    model != upstream
+                };
+                if super::shrink(&[$(&$x),*], fails) {
+                    $(let $x = $x.get();)*
+                    let inputs: &[&dyn std::fmt::Debug] = &[$(&$x),*];
+                    let model: $ret = super::super::models::sse2::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*);
+                    let upstream: $ret = unsafe { upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*) };
+                    mk!(@cmp[inputs] model, upstream);
+                }
         }
     };
-    (@[$N:ident]$name:ident$(<$($c:literal),*>)?($($x:ident : $ty:ident),*)) => {
+    (@[$N:ident, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident$(<$($c:tt),*>)?($($x:ident : $ty:ident),*)) => {
         for _ in 0..$N {
-            $(let $x = $ty::random();)*
-                assert_eq!(super::super::models::sse2::$name$(::<$($c,)*>)?($($x.into(),)*), unsafe {
-                    BitVec::from(upstream::$name$(::<$($c,)*>)?($($x.into(),)*)).into()
-                });
+            $(let $x = std::cell::Cell::new($ty::random());)*
+                let fails = || {
+                    $(let $x = $x.get();)*
+                    !mk!(@eq super::super::models::sse2::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*), unsafe {
+                        BitVec::from(upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*)).into()
+                    }$(, $mode $fty)?)
+                };
+                if super::shrink(&[$(&$x),*], fails) {
+                    $(let $x = $x.get();)*
+                    let inputs: &[&dyn std::fmt::Debug] = &[$(&$x),*];
+                    mk!(@cmp[inputs] super::super::models::sse2::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*), unsafe {
+                        BitVec::from(upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*)).into()
+                    }$(, $mode $fty)?);
+                }
         }
     };
-    (@[$N:ident]$name:ident<$($c1:literal),*>$(<$($c:literal),*>)*($($x:ident : $ty:ident),*)) => {
+    (@[$N:ident, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident<$($c1:tt),*>$(<$($c:tt),*>)*($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
         let one = || {
-            mk!(@[$N]$name<$($c1),*>($($x : $ty),*));
+            mk!(@[$N, $pass$(, $mode $fty)?]$name<$($c1),*>($($x : $ty),*)$(-> $ret)?);
         };
         one();
-        mk!(@[$N]$name$(<$($c),*>)*($($x : $ty),*));
+        mk!(@[$N, $pass$(, $mode $fty)?]$name$(<$($c),*>)*($($x : $ty),*)$(-> $ret)?);
     }
 }
 mk!(_mm_add_epi8(a: __m128i, b: __m128i));
-mk!(_mm_add_epi16(a: __m128i, b: __m128i));
+mk!(#[lanes(i16)]_mm_add_epi16(a: __m128i, b: __m128i));
+
+/// A model that is off in a single lane gets that lane, and only that one,
+/// reported, with its value in both results.
+#[test]
+#[should_panic(expected = "1 of 8 lanes differ:\n  lane 5: model 16, upstream 15\n")]
+fn _mm_add_epi16_broken_model() {
+    let a: __m128i = BitVec::from(i16x8::from_fn(|i| i as i16));
+    let b: __m128i = BitVec::from(i16x8::splat(10));
+    let broken = |a: __m128i, b: __m128i| -> __m128i {
+        let r = super::super::models::sse2::_mm_add_epi16(a, b).as_i16x8();
+        BitVec::from(i16x8::from_fn(|i| if i == 5 { r[i] + 1 } else { r[i] }))
+    };
+    super::assert_lanes_eq::<i16, _>(
+        broken(a, b),
+        unsafe { BitVec::from(upstream::_mm_add_epi16(a.into(), b.into())) },
+        super::same_bits,
+        &[&a, &b],
+    );
+}
 mk!(_mm_add_epi32(a: __m128i, b: __m128i));
 mk!(_mm_add_epi64(a: __m128i, b: __m128i));
 mk!(_mm_adds_epi8(a: __m128i, b: __m128i));
@@ -44,6 +122,26 @@ mk!(_mm_adds_epu16(a: __m128i, b: __m128i));
 mk!(_mm_avg_epu8(a: __m128i, b: __m128i));
 mk!(_mm_avg_epu16(a: __m128i, b: __m128i));
 mk!(_mm_madd_epi16(a: __m128i, b: __m128i));
+
+/// `-32768 * -32768 + -32768 * -32768` is `2^31`, which does not fit in an
+/// `i32` and wraps to `i32::MIN`. The other pairs stay just within range.
+#[test]
+fn _mm_madd_epi16_overflow() {
+    let a: [i16; 8] = core::array::from_fn(|i| if i % 4 == 3 { 32767 } else { -32768 });
+    let a = BitVec::from_slice(&a, 16);
+    let r = super::super::models::sse2::_mm_madd_epi16(a, a);
+    let expected: [i32; 4] = core::array::from_fn(|i| {
+        if i % 2 == 0 {
+            i32::MIN
+        } else {
+            (1 << 30) + 32767 * 32767
+        }
+    });
+    assert_eq!(r, BitVec::from_slice(&expected, 32));
+    assert_eq!(r, unsafe {
+        BitVec::from(upstream::_mm_madd_epi16(a.into(), a.into()))
+    });
+}
 mk!(_mm_max_epi16(a: __m128i, b: __m128i));
 mk!(_mm_max_epu8(a: __m128i, b: __m128i));
 mk!(_mm_min_epi16(a: __m128i, b: __m128i));
@@ -53,6 +151,39 @@ mk!(_mm_mulhi_epu16(a: __m128i, b: __m128i));
 mk!(_mm_mullo_epi16(a: __m128i, b: __m128i));
 mk!(_mm_mul_epu32(a: __m128i, b: __m128i));
 mk!(_mm_sad_epu8(a: __m128i, b: __m128i));
+
+/// Each 64-bit lane holds the sum of its eight absolute differences in bits
+/// `[15:0]`, and zeros in bits `[63:16]`. The largest sum, `8 * 255`, is checked
+/// along with random ones, whose bytes are biased towards `0` and `255`.
+#[test]
+fn _mm_sad_epu8_layout() {
+    let ones = BitVec::from_slice(&[0xffu8; 16], 8);
+    let r = super::super::models::sse2::_mm_sad_epu8(ones, BitVec::ZERO());
+    assert_eq!(r, BitVec::from_slice(&[8 * 255u64; 2], 64));
+    for _ in 0..1000 {
+        let (a, b) = (
+            BitVec::from(u8x16::random_biased()),
+            BitVec::from(u8x16::random_biased()),
+        );
+        let r = super::super::models::sse2::_mm_sad_epu8(a, b);
+        for lane in 0..2 {
+            let sum: u16 = (0..8)
+                .map(|i| {
+                    let i = 8 * lane + i;
+                    a.bits::<8>(8 * i..8 * i + 8)
+                        .to_int::<u8>()
+                        .abs_diff(b.bits::<8>(8 * i..8 * i + 8).to_int::<u8>())
+                        as u16
+                })
+                .sum();
+            assert_eq!(r.bits::<16>(64 * lane..64 * lane + 16).to_int::<u16>(), sum);
+            assert_eq!(r.bits::<48>(64 * lane + 16..64 * lane + 64), BitVec::ZERO());
+        }
+        assert_eq!(r, unsafe {
+            BitVec::from(upstream::_mm_sad_epu8(a.into(), b.into()))
+        });
+    }
+}
 mk!(_mm_sub_epi8(a: __m128i, b: __m128i));
 mk!(_mm_sub_epi16(a: __m128i, b: __m128i));
 mk!(_mm_sub_epi32(a: __m128i, b: __m128i));
@@ -96,6 +227,52 @@ mk!([100]_mm_srl_epi32(a: __m128i, count: __m128i));
 mk!([100]_mm_srli_epi64{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>,<33>,<34>,<35>,<36>,<37>,<38>,<39>,<40>,<41>,<42>,<43>,<44>,<45>,<46>,<47>,<48>,<49>,<50>,<51>,<52>,<53>,<54>,<55>,<56>,<57>,<58>,<59>,<60>,<61>,<62>,<63>,<64>,<65>,<66>,<67>,<68>,<69>,<70>,<71>,<72>,<73>,<74>,<75>,<76>,<77>,<78>,<79>,<80>,<81>,<82>,<83>,<84>,<85>,<86>,<87>,<88>,<89>,<90>,<91>,<92>,<93>,<94>,<95>,<96>,<97>,<98>,<99>,<100>,<101>,<102>,<103>,<104>,<105>,<106>,<107>,<108>,<109>,<110>,<111>,<112>,<113>,<114>,<115>,<116>,<117>,<118>,<119>,<120>,<121>,<122>,<123>,<124>,<125>,<126>,<127>,<128>,<129>,<130>,<131>,<132>,<133>,<134>,<135>,<136>,<137>,<138>,<139>,<140>,<141>,<142>,<143>,<144>,<145>,<146>,<147>,<148>,<149>,<150>,<151>,<152>,<153>,<154>,<155>,<156>,<157>,<158>,<159>,<160>,<161>,<162>,<163>,<164>,<165>,<166>,<167>,<168>,<169>,<170>,<171>,<172>,<173>,<174>,<175>,<176>,<177>,<178>,<179>,<180>,<181>,<182>,<183>,<184>,<185>,<186>,<187>,<188>,<189>,<190>,<191>,<192>,<193>,<194>,<195>,<196>,<197>,<198>,<199>,<200>,<201>,<202>,<203>,<204>,<205>,<206>,<207>,<208>,<209>,<210>,<211>,<212>,<213>,<214>,<215>,<216>,<217>,<218>,<219>,<220>,<221>,<222>,<223>,<224>,<225>,<226>,<227>,<228>,<229>,<230>,<231>,<232>,<233>,<234>,<235>,<236>,<237>,<238>,<239>,<240>,<241>,<242>,<243>,<244>,<245>,<246>,<247>,<248>,<249>,<250>,<251>,<252>,<253>,<254>,<255>}(a: __m128i));
 
 mk!(_mm_srl_epi64(a: __m128i, count: __m128i));
+
+super::mk_shift_count_test!(_mm_sll_srl_sra_counts: sse2, __m128i, [
+    (_mm_sll_epi16, 16, false),
+    (_mm_sll_epi32, 32, false),
+    (_mm_sll_epi64, 64, false),
+    (_mm_srl_epi16, 16, false),
+    (_mm_srl_epi32, 32, false),
+    (_mm_srl_epi64, 64, false),
+    (_mm_sra_epi16, 16, true),
+    (_mm_sra_epi32, 32, true),
+]);
+
+/// A count whose low 32 bits are in range but whose next 32 bits are not zero
+/// is out of range as a whole, so the lanes are zeroed or sign-filled instead
+/// of being shifted by the low bits alone.
+#[test]
+fn _mm_sll_srl_sra_count_high_bits() {
+    use super::super::models::sse2;
+    let a: __m128i = BitVec::from_slice(&[i16::MIN, -2, -1, 0, 1, 2, i16::MAX, 0x1234], 16);
+    let zero: __m128i = BitVec::from_slice(&[0u64; 2], 64);
+    for c in [(1u64 << 32) | 1, (1 << 32) | 3, (1 << 48) | 1, 1 << 63] {
+        let count: __m128i = BitVec::from_slice(&[c, 0], 64);
+        macro_rules! check {
+            ($($name:ident => $expected:expr,)*) => {$(
+                let r = sse2::$name(a, count);
+                assert_eq!(
+                    r,
+                    unsafe { BitVec::from(upstream::$name(a.into(), count.into())) },
+                    "{} with count {c:#x}",
+                    stringify!($name)
+                );
+                assert_eq!(r, $expected, "{} with count {c:#x}", stringify!($name));
+            )*};
+        }
+        check!(
+            _mm_sll_epi16 => zero,
+            _mm_sll_epi32 => zero,
+            _mm_sll_epi64 => zero,
+            _mm_srl_epi16 => zero,
+            _mm_srl_epi32 => zero,
+            _mm_srl_epi64 => zero,
+            _mm_sra_epi16 => BitVec::from_slice(&[-1i16, -1, -1, 0, 0, 0, 0, 0], 16),
+            _mm_sra_epi32 => sse2::_mm_srai_epi32::<31>(a),
+        );
+    }
+}
 mk!(_mm_and_si128(a: __m128i, b: __m128i));
 mk!(_mm_andnot_si128(a: __m128i, b: __m128i));
 mk!(_mm_or_si128(a: __m128i, b: __m128i));
@@ -177,6 +354,25 @@ mk!(_mm_setr_epi8(
     e0: i8
 ));
 mk!(_mm_setzero_si128());
+mk!(_mm_set_pd(a: f64, b: f64));
+mk!(_mm_setr_pd(a: f64, b: f64));
+mk!(_mm_set_pd1(a: f64));
+
+/// `_mm_set_pd` takes its arguments from the highest lane down, and
+/// `_mm_setr_pd` from the lowest up, so swapping the arguments of one gives the
+/// other, bit for bit.
+#[test]
+fn _mm_set_pd_order() {
+    use super::super::models::sse2;
+    for _ in 0..1000 {
+        let (a, b) = (f64::from_bits(u64::random()), f64::from_bits(u64::random()));
+        let set = sse2::_mm_set_pd(a, b);
+        assert_eq!(set, sse2::_mm_setr_pd(b, a));
+        assert_eq!(set.to_vec::<u64>(), [b.to_bits(), a.to_bits()]);
+        assert_eq!(set, unsafe { BitVec::from(upstream::_mm_set_pd(a, b)) });
+        assert_eq!(set, unsafe { BitVec::from(upstream::_mm_setr_pd(b, a)) });
+    }
+}
 mk!(_mm_move_epi64(a: __m128i));
 mk!(_mm_packs_epi16(a: __m128i, b: __m128i));
 mk!(_mm_packs_epi32(a: __m128i, b: __m128i));
@@ -199,3 +395,135 @@ mk!(_mm_unpacklo_epi16(a: __m128i, b: __m128i));
 mk!(_mm_unpacklo_epi32(a: __m128i, b: __m128i));
 mk!(_mm_unpacklo_epi64(a: __m128i, b: __m128i));
 mk!(_mm_undefined_si128());
+mk!(#[float(f64)]_mm_add_pd(a: __m128d, b: __m128d));
+mk!(#[float(f64)]_mm_sub_pd(a: __m128d, b: __m128d));
+mk!(#[float(f32)]_mm_cvtsd_ss(a: __m128, b: __m128d));
+mk!(#[float(f64)]_mm_cvtss_sd(a: __m128d, b: __m128));
+mk!(#[float(f64)]_mm_cvtepi32_pd(a: __m128i));
+
+#[test]
+fn _mm_cvtsd_ss_rounding() {
+    let a: __m128 = BitVec::from(f32x4::new(1.5, -2.0, 3.25, f32::MAX));
+    // Each `f64` is paired with the `f32` it rounds to (round-to-nearest-even).
+    let cases = [
+        (0.1f64, 0.1f32),
+        (1.0 + f64::EPSILON, 1.0),
+        (16777217.0, 16777216.0),
+        (16777219.0, 16777220.0),
+        (-1.0e-50, -0.0),
+        (f64::MAX, f32::INFINITY),
+        (f64::MIN, f32::NEG_INFINITY),
+    ];
+    for (x, expected) in cases {
+        let b: __m128d = BitVec::from(f64x2::new(x, -7.5));
+        let r = super::super::models::sse2::_mm_cvtsd_ss(a, b);
+        assert_eq!(r.as_f32x4()[0].to_bits(), expected.to_bits());
+        for i in 1..4 {
+            assert_eq!(r.as_f32x4()[i].to_bits(), a.as_f32x4()[i].to_bits());
+        }
+        assert_eq!(r, unsafe {
+            BitVec::from(upstream::_mm_cvtsd_ss(a.into(), b.into()))
+        });
+    }
+}
+
+#[test]
+fn _mm_cvtss_sd_widening() {
+    let a: __m128d = BitVec::from(f64x2::new(1.0e300, -0.1));
+    for x in [0.1f32, -0.0, f32::MIN_POSITIVE, f32::MAX, f32::NEG_INFINITY] {
+        let b: __m128 = BitVec::from(f32x4::new(x, 2.0, 3.0, 4.0));
+        let r = super::super::models::sse2::_mm_cvtss_sd(a, b);
+        assert_eq!(r.as_f64x2()[0].to_bits(), (x as f64).to_bits());
+        assert_eq!(r.as_f64x2()[1].to_bits(), (-0.1f64).to_bits());
+        assert_eq!(r, unsafe {
+            BitVec::from(upstream::_mm_cvtss_sd(a.into(), b.into()))
+        });
+    }
+}
+
+/// Compares the returned `f64` by its bits, both with upstream and with lane 0
+/// of the model's own `as_f64x2` view, so a NaN must keep its sign and payload.
+/// Random inputs rarely put a NaN in lane 0, so a few are added explicitly.
+#[test]
+fn _mm_cvtsd_f64() {
+    use super::super::models::sse2;
+    let nans = [f64::NAN, -f64::NAN, f64::from_bits(0x7ff0_0000_0000_0001)];
+    let inputs = (0..1000)
+        .map(|_| BitVec::random())
+        .chain(nans.map(|nan| BitVec::from(f64x2::new(nan, 1.0))));
+    for a in inputs {
+        let a: __m128d = a;
+        assert_eq!(
+            sse2::_mm_cvtsd_f64(a).to_bits(),
+            unsafe { upstream::_mm_cvtsd_f64(a.into()) }.to_bits()
+        );
+        assert_eq!(sse2::_mm_cvtsd_f64(a).to_bits(), a.as_f64x2()[0].to_bits());
+    }
+}
+
+/// Only the two lower lanes are converted, and the two upper ones are ignored
+/// whatever they hold. Every `i32` is exactly representable as an `f64`.
+#[test]
+fn _mm_cvtepi32_pd_lower_lanes() {
+    let lower = [
+        (i32::MIN, i32::MAX),
+        (-1, 0),
+        (i32::MAX, i32::MIN),
+        (1 << 24 | 1, -(1 << 30) - 1),
+    ];
+    for (e0, e1) in lower {
+        let expected = BitVec::from(f64x2::new(e0 as f64, e1 as f64));
+        for (e2, e3) in [(0, 0), (i32::MIN, i32::MAX), (i32::random(), i32::random())] {
+            let a = BitVec::from_slice(&[e0, e1, e2, e3], 32);
+            let r = super::super::models::sse2::_mm_cvtepi32_pd(a);
+            assert_eq!(r, expected);
+            assert_eq!(r, unsafe {
+                BitVec::from(upstream::_mm_cvtepi32_pd(a.into()))
+            });
+        }
+    }
+}
+
+/// Loads from every offset of a buffer, most of them unaligned.
+#[test]
+fn _mm_loadu_si128_unaligned() {
+    for _ in 0..100 {
+        let mem: [u8; 32] = core::array::from_fn(|_| u8::random());
+        for offset in 0..16 {
+            let r = super::super::models::sse2::_mm_loadu_si128(&mem[offset..]);
+            assert_eq!(r, BitVec::from_slice(&mem[offset..offset + 16], 8));
+            assert_eq!(r, unsafe {
+                BitVec::from(upstream::_mm_loadu_si128(
+                    mem[offset..].as_ptr() as *const upstream::__m128i
+                ))
+            });
+        }
+    }
+}
+
+/// Stores to every offset of a buffer, checking that the bytes around the
+/// stored ones are left untouched, and that loading them back gives `a`.
+#[test]
+fn _mm_storeu_si128_round_trip() {
+    for _ in 0..100 {
+        let a = __m128i::random();
+        let mem: [u8; 32] = core::array::from_fn(|_| u8::random());
+        for offset in 0..16 {
+            let (mut model, mut expected) = (mem, mem);
+            super::super::models::sse2::_mm_storeu_si128(&mut model[offset..], a);
+            unsafe {
+                upstream::_mm_storeu_si128(
+                    expected[offset..].as_mut_ptr() as *mut upstream::__m128i,
+                    a.into(),
+                )
+            };
+            assert_eq!(model, expected);
+            assert_eq!(model[..offset], mem[..offset]);
+            assert_eq!(model[offset + 16..], mem[offset + 16..]);
+            assert_eq!(
+                super::super::models::sse2::_mm_loadu_si128(&model[offset..]),
+                a
+            );
+        }
+    }
+}
@@ -0,0 +1,397 @@
+use super::types::*;
+use super::upstream;
+use crate::abstractions::bitvec::BitVec;
+use crate::abstractions::simd::{f32x4, f64x2};
+use crate::helpers::test::mk_const_sweep;
+use crate::helpers::test::HasRandom;
+
+/// Derives tests for a given intrinsics. Test that a given intrinsics and its model compute the same thing over random values (1000 by default).
+macro_rules! mk {
+    ($([$N:literal])?$(#[$mode:ident($fty:ty)])?$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
+        mk!(@test[$($N)?, val$(, $mode $fty)?]$name$({$(<$($c),*>),*})?($($x : $ty),*)$(-> $ret)?);
+    };
+    ($([$N:literal])?$(#[$mode:ident($fty:ty)])?$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : &$ty:ident),+)$(-> $ret:ty)?) => {
+        mk!(@test[$($N)?, ref$(, $mode $fty)?]$name$({$(<$($c),*>),*})?($($x : $ty),*)$(-> $ret)?);
+    };
+    (@test[$($N:literal)?, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
+        #[test]
+        fn $name() {
+            #[allow(unused)]
+            const N: usize = {
+                let n: usize = 1000;
+                $(let n: usize = $N;)?
+                    n
+            };
+            mk!(@[N, $pass$(, $mode $fty)?]$name$($(<$($c),*>)*)?($($x : $ty),*)$(-> $ret)?);
+        }
+    };
+    (@val $x:ident) => {
+        $x.into()
+    };
+    (@ref $x:ident) => {
+        &$x.into()
+    };
+    (@eq $model:expr, $upstream:expr) => {
+        $model == $upstream
+    };
+    (@eq $model:expr, $upstream:expr, $mode:ident $fty:ty) => {
+        super::mismatched_lanes::<$fty, _>($model, $upstream, mk!(@same $mode)).is_empty()
+    };
+    (@same float) => {
+        super::same_modulo_nan
+    };
+    (@same lanes) => {
+        super::same_bits
+    };
+    (@cmp[$inputs:expr] $model:expr, $upstream:expr) => {
+        assert_eq!($model, $upstream, "Failed with (shrunk) input values: {:?} (seed: {})", $inputs, crate::helpers::test::seed())
+    };
+    (@cmp[$inputs:expr] $model:expr, $upstream:expr, $mode:ident $fty:ty) => {
+        super::assert_lanes_eq::<$fty, _>($model, $upstream, mk!(@same $mode), $inputs)
+    };
+    (@[$N:ident, $pass:ident]$name:ident$(<$($c:tt),*>)?($($x:ident : $ty:ident),*) -> $ret:ty) => {
+        for _ in 0..$N {
+            $(let $x = std::cell::Cell::new($ty::random());)*
+                let fails = || {
+                    $(let $x = $x.get();)*
+                    let model: $ret = super::super::models::sse41::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*);
+                    let upstream: $ret = unsafe { upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*) };
+                    model != upstream
+                };
+                if super::shrink(&[$(&$x),*], fails) {
+                    $(let $x = $x.get();)*
+                    let inputs: &[&dyn std::fmt::Debug] = &[$(&$x),*];
+                    let model: $ret = super::super::models::sse41::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*);
+                    let upstream: $ret = unsafe { upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*) };
+                    mk!(@cmp[inputs] model, upstream);
+                }
+        }
+    };
+    (@[$N:ident, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident$(<$($c:tt),*>)?($($x:ident : $ty:ident),*)) => {
+        for _ in 0..$N {
+            $(let $x = std::cell::Cell::new($ty::random());)*
+                let fails = || {
+                    $(let $x = $x.get();)*
+                    !mk!(@eq super::super::models::sse41::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*), unsafe {
+                        BitVec::from(upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*)).into()
+                    }$(, $mode $fty)?)
+                };
+                if super::shrink(&[$(&$x),*], fails) {
+                    $(let $x = $x.get();)*
+                    let inputs: &[&dyn std::fmt::Debug] = &[$(&$x),*];
+                    mk!(@cmp[inputs] super::super::models::sse41::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*), unsafe {
+                        BitVec::from(upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*)).into()
+                    }$(, $mode $fty)?);
+                }
+        }
+    };
+    (@[$N:ident, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident<$($c1:tt),*>$(<$($c:tt),*>)*($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
+        let one = || {
+            mk!(@[$N, $pass$(, $mode $fty)?]$name<$($c1),*>($($x : $ty),*)$(-> $ret)?);
+        };
+        one();
+        mk!(@[$N, $pass$(, $mode $fty)?]$name$(<$($c),*>)*($($x : $ty),*)$(-> $ret)?);
+    }
+}
+mk!(_mm_blendv_epi8(a: __m128i, b: __m128i, mask: __m128i));
+mk_const_sweep!([100]_mm_blend_epi16{bits: 8}(a: __m128i, b: __m128i));
+mk!(_mm_blendv_pd(a: __m128d, b: __m128d, mask: __m128d));
+mk!(_mm_blendv_ps(a: __m128, b: __m128, mask: __m128));
+
+/// Only the sign bit of each mask lane is looked at, so `-0.0` and a negative
+/// NaN select from `b`, while `0.0` and a positive NaN select from `a`.
+#[test]
+fn _mm_blendv_ps_sign_bit() {
+    use super::super::models::sse41;
+    let a: __m128 = BitVec::from(f32x4::new(1.0, 2.0, 3.0, 4.0));
+    let b: __m128 = BitVec::from(f32x4::new(-1.0, -2.0, -3.0, -4.0));
+    let mask: __m128 = BitVec::from(f32x4::new(-0.0, 0.0, -f32::NAN, f32::NAN));
+    let expected: __m128 = BitVec::from(f32x4::new(-1.0, 2.0, -3.0, 4.0));
+    assert_eq!(sse41::_mm_blendv_ps(a, b, mask), expected);
+    let upstream =
+        unsafe { BitVec::from(upstream::_mm_blendv_ps(a.into(), b.into(), mask.into())) };
+    assert_eq!(upstream, expected);
+}
+mk!(_mm_max_epi8(a: __m128i, b: __m128i));
+mk!(_mm_max_epu16(a: __m128i, b: __m128i));
+mk!(_mm_max_epi32(a: __m128i, b: __m128i));
+mk!(_mm_max_epu32(a: __m128i, b: __m128i));
+mk!(_mm_min_epi8(a: __m128i, b: __m128i));
+mk!(_mm_min_epu16(a: __m128i, b: __m128i));
+mk!(_mm_min_epi32(a: __m128i, b: __m128i));
+mk!(_mm_min_epu32(a: __m128i, b: __m128i));
+
+/// Pairs up vectors made of a single repeated byte, picked on either side of
+/// the sign boundary, so that at every lane width a lane is zero, one, the
+/// largest positive value, the most negative value or all ones. A signed and an
+/// unsigned comparison disagree on most of these pairs.
+#[test]
+fn _mm_min_max_sign_boundaries() {
+    use super::super::models::sse41;
+    let bytes = [0x00u8, 0x01, 0x7f, 0x80, 0xff];
+    for x in bytes {
+        for y in bytes {
+            let a: __m128i = BitVec::from_slice(&[x; 16], 8);
+            let b: __m128i = BitVec::from_slice(&[y; 16], 8);
+            macro_rules! check {
+                ($($name:ident),*) => {
+                    $(assert_eq!(
+                        sse41::$name(a, b),
+                        unsafe { BitVec::from(upstream::$name(a.into(), b.into())) },
+                        "{}({x:#04x}, {y:#04x})",
+                        stringify!($name)
+                    );)*
+                };
+            }
+            check!(
+                _mm_max_epi8,
+                _mm_max_epu16,
+                _mm_max_epi32,
+                _mm_max_epu32,
+                _mm_min_epi8,
+                _mm_min_epu16,
+                _mm_min_epi32,
+                _mm_min_epu32
+            );
+        }
+    }
+}
+mk!(_mm_cvtepi8_epi16(a: __m128i));
+mk!(_mm_cvtepi8_epi32(a: __m128i));
+mk!(_mm_cvtepi8_epi64(a: __m128i));
+mk!(_mm_cvtepi16_epi32(a: __m128i));
+mk!(_mm_cvtepi16_epi64(a: __m128i));
+mk!(_mm_cvtepi32_epi64(a: __m128i));
+mk!(_mm_cvtepu8_epi16(a: __m128i));
+mk!(_mm_cvtepu8_epi32(a: __m128i));
+mk!(_mm_cvtepu8_epi64(a: __m128i));
+mk!(_mm_cvtepu16_epi32(a: __m128i));
+mk!(_mm_cvtepu16_epi64(a: __m128i));
+mk!(_mm_cvtepu32_epi64(a: __m128i));
+
+/// Negative lanes tell sign extension from zero extension: the `epi` variants
+/// fill the new high bits with ones, the `epu` ones with zeros.
+#[test]
+fn _mm_cvtep_negative_lanes() {
+    use super::super::models::sse41;
+    let a: __m128i = BitVec::from_slice(&[0x80u8; 16], 8);
+    assert_eq!(
+        sse41::_mm_cvtepi8_epi16(a),
+        BitVec::from_slice(&[-128i16; 8], 16)
+    );
+    assert_eq!(
+        sse41::_mm_cvtepu8_epi16(a),
+        BitVec::from_slice(&[128i16; 8], 16)
+    );
+    assert_eq!(
+        sse41::_mm_cvtepi16_epi64(a),
+        BitVec::from_slice(&[-0x7f80i64; 2], 64)
+    );
+    assert_eq!(
+        sse41::_mm_cvtepu16_epi64(a),
+        BitVec::from_slice(&[0x8080i64; 2], 64)
+    );
+    for a in [a, BitVec::from_slice(&[0xffu8; 16], 8)] {
+        macro_rules! check {
+            ($($name:ident),*) => {
+                $(assert_eq!(
+                    sse41::$name(a),
+                    unsafe { BitVec::from(upstream::$name(a.into())) },
+                    "{}({a:?})",
+                    stringify!($name)
+                );)*
+            };
+        }
+        check!(
+            _mm_cvtepi8_epi16,
+            _mm_cvtepi8_epi32,
+            _mm_cvtepi8_epi64,
+            _mm_cvtepi16_epi32,
+            _mm_cvtepi16_epi64,
+            _mm_cvtepi32_epi64,
+            _mm_cvtepu8_epi16,
+            _mm_cvtepu8_epi32,
+            _mm_cvtepu8_epi64,
+            _mm_cvtepu16_epi32,
+            _mm_cvtepu16_epi64,
+            _mm_cvtepu32_epi64
+        );
+    }
+}
+mk!(_mm_packus_epi32(a: __m128i, b: __m128i));
+
+/// Negative lanes saturate to zero, and lanes above `u16::MAX` to `0xFFFF`.
+#[test]
+fn _mm_packus_epi32_saturating() {
+    use super::super::models::sse41;
+    let a: __m128i = BitVec::from_slice(&[-1i32, i32::MIN, 0x10000, i32::MAX], 32);
+    let b: __m128i = BitVec::from_slice(&[0i32, 0xFFFF, 0x8000, -0x8000], 32);
+    let expected: __m128i =
+        BitVec::from_slice(&[0u16, 0, 0xFFFF, 0xFFFF, 0, 0xFFFF, 0x8000, 0], 16);
+    assert_eq!(sse41::_mm_packus_epi32(a, b), expected);
+    let upstream = unsafe { BitVec::from(upstream::_mm_packus_epi32(a.into(), b.into())) };
+    assert_eq!(upstream, expected);
+}
+mk!([100]#[float(f64)]_mm_dp_pd{<0>,<1>,<2>,<3>,<16>,<17>,<18>,<19>,<32>,<33>,<34>,<35>,<48>,<49>,<50>,<51>,<204>,<255>}(a: __m128d, b: __m128d));
+mk!([100]#[float(f32)]_mm_dp_ps{<0>,<1>,<15>,<16>,<31>,<49>,<51>,<85>,<127>,<128>,<170>,<204>,<240>,<241>,<255>}(a: __m128, b: __m128));
+
+/// The products are summed up pairwise: with the products `1`, `2^24`, `1`
+/// and `-2^24`, adding them from left to right would round `2^24 + 1` down
+/// twice and return `0`, where the hardware returns `1`.
+#[test]
+fn _mm_dp_ps_pairwise_sum() {
+    use super::super::models::sse41;
+    let big = (1u32 << 24) as f32;
+    let a: __m128 = BitVec::from(f32x4::new(1.0, big, 1.0, -big));
+    let b: __m128 = BitVec::from(f32x4::splat(1.0));
+    let expected: __m128 = BitVec::from(f32x4::splat(1.0));
+    assert_eq!(sse41::_mm_dp_ps::<0xff>(a, b), expected);
+    let upstream = unsafe { BitVec::from(upstream::_mm_dp_ps::<0xff>(a.into(), b.into())) };
+    assert_eq!(upstream, expected);
+}
+mk_const_sweep!([100]_mm_extract_epi8{bits: 4}(a: __m128i) -> i32);
+mk!([100]_mm_extract_epi32{<0>,<1>,<2>,<3>}(a: __m128i) -> i32);
+#[cfg(target_arch = "x86_64")]
+mk!([100]_mm_extract_epi64{<0>,<1>}(a: __m128i) -> i64);
+mk_const_sweep!([100]_mm_insert_epi8{bits: 4}(a: __m128i, i: i32));
+mk!([100]_mm_insert_epi32{<0>,<1>,<2>,<3>}(a: __m128i, i: i32));
+#[cfg(target_arch = "x86_64")]
+mk!([100]_mm_insert_epi64{<0>,<1>}(a: __m128i, i: i64));
+
+/// The 8-bit extract zero-extends the lane into the returned `i32`, even
+/// when the lane is negative.
+#[test]
+fn _mm_extract_epi8_zero_extends() {
+    use super::super::models::sse41;
+    let a: __m128i = BitVec::from_slice(
+        &[-1i8, i8::MIN, 0x7f, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        8,
+    );
+    assert_eq!(sse41::_mm_extract_epi8::<0>(a), 0xff);
+    assert_eq!(sse41::_mm_extract_epi8::<1>(a), 0x80);
+    assert_eq!(sse41::_mm_extract_epi8::<2>(a), 0x7f);
+    unsafe {
+        assert_eq!(upstream::_mm_extract_epi8::<0>(a.into()), 0xff);
+        assert_eq!(upstream::_mm_extract_epi8::<1>(a.into()), 0x80);
+    }
+}
+mk!(#[float(f64)]_mm_floor_pd(a: __m128d));
+mk!(#[float(f32)]_mm_floor_ps(a: __m128));
+mk!(#[float(f64)]_mm_floor_sd(a: __m128d, b: __m128d));
+mk!(#[float(f32)]_mm_floor_ss(a: __m128, b: __m128));
+mk!(#[float(f64)]_mm_ceil_pd(a: __m128d));
+mk!(#[float(f32)]_mm_ceil_ps(a: __m128));
+mk!(#[float(f64)]_mm_ceil_sd(a: __m128d, b: __m128d));
+mk!(#[float(f32)]_mm_ceil_ss(a: __m128, b: __m128));
+mk!([100]#[float(f64)]_mm_round_pd{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>}(a: __m128d));
+mk!([100]#[float(f32)]_mm_round_ps{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>}(a: __m128));
+mk!([100]#[float(f64)]_mm_round_sd{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>}(a: __m128d, b: __m128d));
+mk!([100]#[float(f32)]_mm_round_ss{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>}(a: __m128, b: __m128));
+
+/// Random floats are mostly too large to have a fractional part, so the
+/// rounding modes are also checked on halfway cases, signed zeros and
+/// infinities. Rounding keeps the sign of the input, e.g. `-0.5` rounds to
+/// `-0.0` towards zero.
+#[test]
+fn _mm_round_ps_edge_cases() {
+    use super::super::models::sse41;
+    let inputs = [
+        f32x4::new(-0.5, 0.5, 1.5, 2.5),
+        f32x4::new(-1.5, -2.5, 0.25, -0.75),
+        f32x4::new(0.0, -0.0, f32::INFINITY, f32::NEG_INFINITY),
+    ];
+    for a in inputs {
+        let a: __m128 = BitVec::from(a);
+        macro_rules! check {
+            ($($rounding:ident),*) => {
+                $(assert_eq!(
+                    sse41::_mm_round_ps::<{ sse41::$rounding }>(a),
+                    unsafe { BitVec::from(upstream::_mm_round_ps::<{ upstream::$rounding }>(a.into())) },
+                    "{}({a:?})",
+                    stringify!($rounding)
+                );
+                assert_eq!(
+                    sse41::_mm_round_ss::<{ sse41::$rounding }>(a, a),
+                    unsafe { BitVec::from(upstream::_mm_round_ss::<{ upstream::$rounding }>(a.into(), a.into())) },
+                    "{}({a:?})",
+                    stringify!($rounding)
+                );)*
+            };
+        }
+        check!(
+            _MM_FROUND_TO_NEAREST_INT,
+            _MM_FROUND_TO_NEG_INF,
+            _MM_FROUND_TO_POS_INF,
+            _MM_FROUND_TO_ZERO,
+            _MM_FROUND_CUR_DIRECTION
+        );
+    }
+    let a: __m128 = BitVec::from(f32x4::new(-0.5, 0.5, 1.5, 2.5));
+    let expected: __m128 = BitVec::from(f32x4::new(-0.0, 0.0, 2.0, 2.0));
+    assert_eq!(
+        sse41::_mm_round_ps::<{ sse41::_MM_FROUND_TO_NEAREST_INT }>(a),
+        expected
+    );
+    let expected: __m128 = BitVec::from(f32x4::new(-0.0, 1.0, 2.0, 3.0));
+    assert_eq!(sse41::_mm_ceil_ps(a), expected);
+}
+
+/// Same as `_mm_round_ps_edge_cases`, on double-precision lanes.
+#[test]
+fn _mm_round_pd_edge_cases() {
+    use super::super::models::sse41;
+    let inputs = [
+        f64x2::new(-0.5, 2.5),
+        f64x2::new(-1.5, 0.75),
+        f64x2::new(-0.0, f64::INFINITY),
+    ];
+    for a in inputs {
+        let a: __m128d = BitVec::from(a);
+        macro_rules! check {
+            ($($rounding:ident),*) => {
+                $(assert_eq!(
+                    sse41::_mm_round_pd::<{ sse41::$rounding }>(a),
+                    unsafe { BitVec::from(upstream::_mm_round_pd::<{ upstream::$rounding }>(a.into())) },
+                    "{}({a:?})",
+                    stringify!($rounding)
+                );
+                assert_eq!(
+                    sse41::_mm_round_sd::<{ sse41::$rounding }>(a, a),
+                    unsafe { BitVec::from(upstream::_mm_round_sd::<{ upstream::$rounding }>(a.into(), a.into())) },
+                    "{}({a:?})",
+                    stringify!($rounding)
+                );)*
+            };
+        }
+        check!(
+            _MM_FROUND_TO_NEAREST_INT,
+            _MM_FROUND_TO_NEG_INF,
+            _MM_FROUND_TO_POS_INF,
+            _MM_FROUND_TO_ZERO,
+            _MM_FROUND_CUR_DIRECTION
+        );
+    }
+}
+mk!(_mm_minpos_epu16(a: __m128i));
+
+/// With several lanes holding the minimum, the lowest index is returned.
+#[test]
+fn _mm_minpos_epu16_ties() {
+    use super::super::models::sse41;
+    for lanes in [
+        [9u16, 3, 7, 3, 3, 8, 9, 3],
+        [u16::MAX; 8],
+        [5, 5, 5, 5, 5, 5, 5, 0],
+        [0, 1, 0, 1, 0, 1, 0, 1],
+    ] {
+        let a: __m128i = BitVec::from_slice(&lanes, 16);
+        let min = *lanes.iter().min().unwrap();
+        let index = lanes.iter().position(|&x| x == min).unwrap() as u16;
+        let expected: __m128i = BitVec::from_slice(&[min, index, 0, 0, 0, 0, 0, 0], 16);
+        assert_eq!(sse41::_mm_minpos_epu16(a), expected);
+        let upstream = unsafe { BitVec::from(upstream::_mm_minpos_epu16(a.into())) };
+        assert_eq!(upstream, expected);
+    }
+}
+
+mk_const_sweep!([100]_mm_mpsadbw_epu8{bits: 3}(a: __m128i, b: __m128i));
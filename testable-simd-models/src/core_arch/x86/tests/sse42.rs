@@ -0,0 +1,226 @@
+use super::types::*;
+use super::upstream;
+use crate::abstractions::bitvec::BitVec;
+use crate::helpers::test::mk_const_sweep;
+use crate::helpers::test::HasRandom;
+
+/// Derives tests for a given intrinsics. Test that a given intrinsics and its model compute the same thing over random values (1000 by default).
+macro_rules! mk {
+    ($([$N:literal])?$(#[$mode:ident($fty:ty)])?$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
+        mk!(@test[$($N)?, val$(, $mode $fty)?]$name$({$(<$($c),*>),*})?($($x : $ty),*)$(-> $ret)?);
+    };
+    ($([$N:literal])?$(#[$mode:ident($fty:ty)])?$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : &$ty:ident),+)$(-> $ret:ty)?) => {
+        mk!(@test[$($N)?, ref$(, $mode $fty)?]$name$({$(<$($c),*>),*})?($($x : $ty),*)$(-> $ret)?);
+    };
+    (@test[$($N:literal)?, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
+        #[test]
+        fn $name() {
+            #[allow(unused)]
+            const N: usize = {
+                let n: usize = 1000;
+                $(let n: usize = $N;)?
+                    n
+            };
+            mk!(@[N, $pass$(, $mode $fty)?]$name$($(<$($c),*>)*)?($($x : $ty),*)$(-> $ret)?);
+        }
+    };
+    (@val $x:ident) => {
+        $x.into()
+    };
+    (@ref $x:ident) => {
+        &$x.into()
+    };
+    (@eq $model:expr, $upstream:expr) => {
+        $model == $upstream
+    };
+    (@eq $model:expr, $upstream:expr, $mode:ident $fty:ty) => {
+        super::mismatched_lanes::<$fty, _>($model, $upstream, mk!(@same $mode)).is_empty()
+    };
+    (@same float) => {
+        super::same_modulo_nan
+    };
+    (@same lanes) => {
+        super::same_bits
+    };
+    (@cmp[$inputs:expr] $model:expr, $upstream:expr) => {
+        assert_eq!($model, $upstream, "Failed with (shrunk) input values: {:?} (seed: {})", $inputs, crate::helpers::test::seed())
+    };
+    (@cmp[$inputs:expr] $model:expr, $upstream:expr, $mode:ident $fty:ty) => {
+        super::assert_lanes_eq::<$fty, _>($model, $upstream, mk!(@same $mode), $inputs)
+    };
+    (@[$N:ident, $pass:ident]$name:ident$(<$($c:tt),*>)?($($x:ident : $ty:ident),*) -> $ret:ty) => {
+        for _ in 0..$N {
+            $(let $x = std::cell::Cell::new($ty::random());)*
+                let fails = || {
+                    $(let $x = $x.get();)*
+                    let model: $ret = super::super::models::sse42::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*);
+                    let upstream: $ret = unsafe { upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*) };
+                    model != upstream
+                };
+                if super::shrink(&[$(&$x),*], fails) {
+                    $(let $x = $x.get();)*
+                    let inputs: &[&dyn std::fmt::Debug] = &[$(&$x),*];
+                    let model: $ret = super::super::models::sse42::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*);
+                    let upstream: $ret = unsafe { upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*) };
+                    mk!(@cmp[inputs] model, upstream);
+                }
+        }
+    };
+    (@[$N:ident, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident$(<$($c:tt),*>)?($($x:ident : $ty:ident),*)) => {
+        for _ in 0..$N {
+            $(let $x = std::cell::Cell::new($ty::random());)*
+                let fails = || {
+                    $(let $x = $x.get();)*
+                    !mk!(@eq super::super::models::sse42::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*), unsafe {
+                        BitVec::from(upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*)).into()
+                    }$(, $mode $fty)?)
+                };
+                if super::shrink(&[$(&$x),*], fails) {
+                    $(let $x = $x.get();)*
+                    let inputs: &[&dyn std::fmt::Debug] = &[$(&$x),*];
+                    mk!(@cmp[inputs] super::super::models::sse42::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*), unsafe {
+                        BitVec::from(upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*)).into()
+                    }$(, $mode $fty)?);
+                }
+        }
+    };
+    (@[$N:ident, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident<$($c1:tt),*>$(<$($c:tt),*>)*($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
+        let one = || {
+            mk!(@[$N, $pass$(, $mode $fty)?]$name<$($c1),*>($($x : $ty),*)$(-> $ret)?);
+        };
+        one();
+        mk!(@[$N, $pass$(, $mode $fty)?]$name$(<$($c),*>)*($($x : $ty),*)$(-> $ret)?);
+    }
+}
+mk_const_sweep!([20]_mm_cmpistrm{bits: 7}(a: __m128i, b: __m128i));
+mk_const_sweep!([20]_mm_cmpestrm{bits: 7}(a: __m128i, la: i32, b: __m128i, lb: i32));
+
+/// Builds a string operand out of `s`, padded with null bytes.
+fn string(s: &[u8]) -> __m128i {
+    let mut bytes = [0u8; 16];
+    bytes[..s.len()].copy_from_slice(s);
+    BitVec::from_slice(&bytes, 8)
+}
+
+/// One example per aggregation, on byte strings, returning the bit mask.
+#[test]
+fn _mm_cmpistrm_aggregations() {
+    use super::super::models::sse42::{self, *};
+    macro_rules! check {
+        ($imm8:expr, $a:literal, $b:literal, $expected:literal) => {{
+            let (a, b) = (string($a), string($b));
+            let expected: __m128i =
+                BitVec::from_slice(&[$expected as u16, 0, 0, 0, 0, 0, 0, 0], 16);
+            assert_eq!(sse42::_mm_cmpistrm::<{ $imm8 }>(a, b), expected);
+            let upstream =
+                unsafe { BitVec::from(upstream::_mm_cmpistrm::<{ $imm8 }>(a.into(), b.into())) };
+            assert_eq!(upstream, expected);
+        }};
+    }
+    // The vowels of `b`.
+    check!(_SIDD_CMP_EQUAL_ANY, b"aeiou", b"hello world", 0b1001_0010);
+    // The lowercase letters of `b`.
+    check!(_SIDD_CMP_RANGES, b"az", b"Hello1z", 0b101_1110);
+    // Past the end of both strings, the elements are taken to be equal.
+    check!(
+        _SIDD_CMP_EQUAL_EACH,
+        b"hello",
+        b"help!",
+        0b1111_1111_1110_0111
+    );
+    // Where `lo` starts in `b`; the trailing `l` is not a match.
+    check!(_SIDD_CMP_EQUAL_ORDERED, b"lo", b"hello lol", 0b100_1000);
+    // Negating the whole mask, or only the elements of `b`.
+    check!(
+        _SIDD_CMP_EQUAL_ANY | _SIDD_NEGATIVE_POLARITY,
+        b"aeiou",
+        b"hello world",
+        0b1111_1111_0110_1101
+    );
+    check!(
+        _SIDD_CMP_EQUAL_ANY | _SIDD_MASKED_NEGATIVE_POLARITY,
+        b"aeiou",
+        b"hello world",
+        0b111_0110_1101
+    );
+}
+
+/// Draws elements from a handful of bytes, null included, so that elements
+/// often compare equal and strings often end before the end of the vector.
+fn small_alphabet() -> __m128i {
+    let alphabet = [0u8, 1, b'a', b'b', 0x7f, 0x80, 0xff];
+    let bytes: Vec<u8> = (0..16)
+        .map(|_| alphabet[usize::random() % alphabet.len()])
+        .collect();
+    BitVec::from_slice(&bytes, 8)
+}
+
+/// Compares the models with the upstream intrinsics for every control value,
+/// on short strings over a small alphabet.
+#[test]
+fn _mm_cmpstrm_small_alphabet() {
+    use super::super::models::sse42;
+    for _ in 0..100 {
+        let (a, b) = (small_alphabet(), small_alphabet());
+        let (la, lb) = (i32::random() % 20, i32::random() % 20);
+        // Every control value `16 * hi + lo`, as `mk_const_sweep!` would give
+        // for 7 bits.
+        macro_rules! check {
+            ($($hi:literal)*) => {
+                $(check!(@row $hi 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15);)*
+            };
+            (@row $hi:literal $($lo:literal)*) => {
+                $({
+                    const IMM8: i32 = $hi * 16 + $lo;
+                    assert_eq!(
+                        sse42::_mm_cmpistrm::<IMM8>(a, b),
+                        unsafe { BitVec::from(upstream::_mm_cmpistrm::<IMM8>(a.into(), b.into())) },
+                        "_mm_cmpistrm::<{IMM8}>({a:?}, {b:?})"
+                    );
+                    assert_eq!(
+                        sse42::_mm_cmpestrm::<IMM8>(a, la, b, lb),
+                        unsafe { BitVec::from(upstream::_mm_cmpestrm::<IMM8>(a.into(), la, b.into(), lb)) },
+                        "_mm_cmpestrm::<{IMM8}>({a:?}, {la}, {b:?}, {lb})"
+                    );
+                })*
+            };
+        }
+        check!(0 1 2 3 4 5 6 7);
+    }
+}
+mk!(_mm_crc32_u8(crc: u32, v: u8) -> u32);
+mk!(_mm_crc32_u16(crc: u32, v: u16) -> u32);
+mk!(_mm_crc32_u32(crc: u32, v: u32) -> u32);
+#[cfg(target_arch = "x86_64")]
+mk!(_mm_crc32_u64(crc: u64, v: u64) -> u64);
+
+/// The standard CRC32-C check value, with the initial and final inversions
+/// that the instructions leave to the caller. The message is fed one byte,
+/// word, or double word at a time, which gives the same result as the bytes
+/// are taken in little-endian order.
+#[test]
+fn _mm_crc32_check_value() {
+    use super::super::models::sse42;
+    let message = b"123456789";
+    let crc = message
+        .iter()
+        .fold(!0, |crc, &v| sse42::_mm_crc32_u8(crc, v));
+    assert_eq!(!crc, 0xE306_9283);
+    let crc = message[..8].chunks(2).fold(!0, |crc, v| {
+        sse42::_mm_crc32_u16(crc, u16::from_le_bytes([v[0], v[1]]))
+    });
+    assert_eq!(!sse42::_mm_crc32_u8(crc, message[8]), 0xE306_9283);
+    let crc = message[..8].chunks(4).fold(!0, |crc, v| {
+        sse42::_mm_crc32_u32(crc, u32::from_le_bytes(v.try_into().unwrap()))
+    });
+    assert_eq!(!sse42::_mm_crc32_u8(crc, message[8]), 0xE306_9283);
+    let crc = sse42::_mm_crc32_u64(!0, u64::from_le_bytes(message[..8].try_into().unwrap()));
+    assert_eq!(!sse42::_mm_crc32_u8(crc as u32, message[8]), 0xE306_9283);
+    // The upper half of `crc` is ignored.
+    assert_eq!(
+        sse42::_mm_crc32_u64(0xFFFF_FFFF_0000_0000, 1),
+        sse42::_mm_crc32_u64(0, 1)
+    );
+    // An all-zero message leaves a zero CRC unchanged.
+    assert_eq!(sse42::_mm_crc32_u32(0, 0), 0);
+}
@@ -1,11 +1,19 @@
 use super::types::*;
 use super::upstream;
 use crate::abstractions::bitvec::BitVec;
+use crate::helpers::test::mk_const_sweep;
+use crate::helpers::test::mk_exhaustive;
 use crate::helpers::test::HasRandom;
 
 /// Derives tests for a given intrinsics. Test that a given intrinsics and its model compute the same thing over random values (1000 by default).
 macro_rules! mk {
-    ($([$N:literal])?$name:ident$({$(<$($c:literal),*>),*})?($($x:ident : $ty:ident),*)) => {
+    ($([$N:literal])?$(#[$mode:ident($fty:ty)])?$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
+        mk!(@test[$($N)?, val$(, $mode $fty)?]$name$({$(<$($c),*>),*})?($($x : $ty),*)$(-> $ret)?);
+    };
+    ($([$N:literal])?$(#[$mode:ident($fty:ty)])?$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : &$ty:ident),+)$(-> $ret:ty)?) => {
+        mk!(@test[$($N)?, ref$(, $mode $fty)?]$name$({$(<$($c),*>),*})?($($x : $ty),*)$(-> $ret)?);
+    };
+    (@test[$($N:literal)?, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident$({$(<$($c:tt),*>),*})?($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
         #[test]
         fn $name() {
             #[allow(unused)]
@@ -14,38 +22,415 @@ macro_rules! mk {
                 $(let n: usize = $N;)?
                     n
             };
-            mk!(@[N]$name$($(<$($c),*>)*)?($($x : $ty),*));
+            mk!(@[N, $pass$(, $mode $fty)?]$name$($(<$($c),*>)*)?($($x : $ty),*)$(-> $ret)?);
+        }
+    };
+    (@val $x:ident) => {
+        $x.into()
+    };
+    (@ref $x:ident) => {
+        &$x.into()
+    };
+    (@eq $model:expr, $upstream:expr) => {
+        $model == $upstream
+    };
+    (@eq $model:expr, $upstream:expr, $mode:ident $fty:ty) => {
+        super::mismatched_lanes::<$fty, _>($model, $upstream, mk!(@same $mode)).is_empty()
+    };
+    (@same float) => {
+        super::same_modulo_nan
+    };
+    (@same lanes) => {
+        super::same_bits
+    };
+    (@cmp[$inputs:expr] $model:expr, $upstream:expr) => {
+        assert_eq!($model, $upstream, "Failed with (shrunk) input values: {:?} (seed: {})", $inputs, crate::helpers::test::seed())
+    };
+    (@cmp[$inputs:expr] $model:expr, $upstream:expr, $mode:ident $fty:ty) => {
+        super::assert_lanes_eq::<$fty, _>($model, $upstream, mk!(@same $mode), $inputs)
+    };
+    (@[$N:ident, $pass:ident]$name:ident$(<$($c:tt),*>)?($($x:ident : $ty:ident),*) -> $ret:ty) => {
+        for _ in 0..$N {
+            $(let $x = std::cell::Cell::new($ty::random());)*
+                let fails = || {
+                    $(let $x = $x.get();)*
+                    let model: $ret = super::super::models::ssse3::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*);
+                    let upstream: $ret = unsafe { upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*) };
+                    model != upstream
+                };
+                if super::shrink(&[$(&$x),*], fails) {
+                    $(let $x = $x.get();)*
+                    let inputs: &[&dyn std::fmt::Debug] = &[$(&$x),*];
+                    let model: $ret = super::super::models::ssse3::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*);
+                    let upstream: $ret = unsafe { upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*) };
+                    mk!(@cmp[inputs] model, upstream);
+                }
         }
     };
-    (@[$N:ident]$name:ident$(<$($c:literal),*>)?($($x:ident : $ty:ident),*)) => {
+    (@[$N:ident, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident$(<$($c:tt),*>)?($($x:ident : $ty:ident),*)) => {
         for _ in 0..$N {
-            $(let $x = $ty::random();)*
-                assert_eq!(super::super::models::ssse3::$name$(::<$($c,)*>)?($($x.into(),)*), unsafe {
-                    BitVec::from(upstream::$name$(::<$($c,)*>)?($($x.into(),)*)).into()
-                });
+            $(let $x = std::cell::Cell::new($ty::random());)*
+                let fails = || {
+                    $(let $x = $x.get();)*
+                    !mk!(@eq super::super::models::ssse3::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*), unsafe {
+                        BitVec::from(upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*)).into()
+                    }$(, $mode $fty)?)
+                };
+                if super::shrink(&[$(&$x),*], fails) {
+                    $(let $x = $x.get();)*
+                    let inputs: &[&dyn std::fmt::Debug] = &[$(&$x),*];
+                    mk!(@cmp[inputs] super::super::models::ssse3::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*), unsafe {
+                        BitVec::from(upstream::$name$(::<$($c,)*>)?($(mk!(@$pass $x),)*)).into()
+                    }$(, $mode $fty)?);
+                }
         }
     };
-    (@[$N:ident]$name:ident<$($c1:literal),*>$(<$($c:literal),*>)*($($x:ident : $ty:ident),*)) => {
+    (@[$N:ident, $pass:ident$(, $mode:ident $fty:ty)?]$name:ident<$($c1:tt),*>$(<$($c:tt),*>)*($($x:ident : $ty:ident),*)$(-> $ret:ty)?) => {
         let one = || {
-            mk!(@[$N]$name<$($c1),*>($($x : $ty),*));
+            mk!(@[$N, $pass$(, $mode $fty)?]$name<$($c1),*>($($x : $ty),*)$(-> $ret)?);
         };
         one();
-        mk!(@[$N]$name$(<$($c),*>)*($($x : $ty),*));
+        mk!(@[$N, $pass$(, $mode $fty)?]$name$(<$($c),*>)*($($x : $ty),*)$(-> $ret)?);
     }
 }
 mk!(_mm_abs_epi8(a: __m128i));
+mk_exhaustive!(ssse3::_mm_abs_epi8(a: __m128i));
 mk!(_mm_abs_epi16(a: __m128i));
 mk!(_mm_abs_epi32(a: __m128i));
+
+/// The absolute value of the minimum of a signed type does not fit in it, and
+/// wraps back to the minimum.
+#[test]
+fn _mm_abs_epi_min() {
+    use super::super::models::ssse3;
+    let a = BitVec::from_slice(
+        &[
+            i8::MIN,
+            -1,
+            0,
+            1,
+            i8::MAX,
+            -i8::MAX,
+            i8::MIN,
+            5,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ],
+        8,
+    );
+    let r = BitVec::from_slice(
+        &[
+            i8::MIN,
+            1,
+            0,
+            1,
+            i8::MAX,
+            i8::MAX,
+            i8::MIN,
+            5,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ],
+        8,
+    );
+    assert_eq!(ssse3::_mm_abs_epi8(a), r);
+    assert_eq!(
+        ssse3::_mm_abs_epi8(a),
+        unsafe { upstream::_mm_abs_epi8(a.into()) }.into()
+    );
+    let a = BitVec::from_slice(&[i16::MIN, -1, i16::MAX, -i16::MAX, 0, 7, -7, i16::MIN], 16);
+    let r = BitVec::from_slice(&[i16::MIN, 1, i16::MAX, i16::MAX, 0, 7, 7, i16::MIN], 16);
+    assert_eq!(ssse3::_mm_abs_epi16(a), r);
+    assert_eq!(
+        ssse3::_mm_abs_epi16(a),
+        unsafe { upstream::_mm_abs_epi16(a.into()) }.into()
+    );
+    let a = BitVec::from_slice(&[i32::MIN, -1, i32::MAX, -i32::MAX], 32);
+    let r = BitVec::from_slice(&[i32::MIN, 1, i32::MAX, i32::MAX], 32);
+    assert_eq!(ssse3::_mm_abs_epi32(a), r);
+    assert_eq!(
+        ssse3::_mm_abs_epi32(a),
+        unsafe { upstream::_mm_abs_epi32(a.into()) }.into()
+    );
+}
+
 mk!(_mm_shuffle_epi8(a: __m128i, b: __m128i));
-mk!([100]_mm_alignr_epi8{<0>,<1>,<2>,<3>,<4>,<5>,<6>,<7>,<8>,<9>,<10>,<11>,<12>,<13>,<14>,<15>,<16>,<17>,<18>,<19>,<20>,<21>,<22>,<23>,<24>,<25>,<26>,<27>,<28>,<29>,<30>,<31>,<32>,<33>,<34>,<35>,<36>,<37>,<38>,<39>,<40>,<41>,<42>,<43>,<44>,<45>,<46>,<47>,<48>,<49>,<50>,<51>,<52>,<53>,<54>,<55>,<56>,<57>,<58>,<59>,<60>,<61>,<62>,<63>,<64>,<65>,<66>,<67>,<68>,<69>,<70>,<71>,<72>,<73>,<74>,<75>,<76>,<77>,<78>,<79>,<80>,<81>,<82>,<83>,<84>,<85>,<86>,<87>,<88>,<89>,<90>,<91>,<92>,<93>,<94>,<95>,<96>,<97>,<98>,<99>,<100>,<101>,<102>,<103>,<104>,<105>,<106>,<107>,<108>,<109>,<110>,<111>,<112>,<113>,<114>,<115>,<116>,<117>,<118>,<119>,<120>,<121>,<122>,<123>,<124>,<125>,<126>,<127>,<128>,<129>,<130>,<131>,<132>,<133>,<134>,<135>,<136>,<137>,<138>,<139>,<140>,<141>,<142>,<143>,<144>,<145>,<146>,<147>,<148>,<149>,<150>,<151>,<152>,<153>,<154>,<155>,<156>,<157>,<158>,<159>,<160>,<161>,<162>,<163>,<164>,<165>,<166>,<167>,<168>,<169>,<170>,<171>,<172>,<173>,<174>,<175>,<176>,<177>,<178>,<179>,<180>,<181>,<182>,<183>,<184>,<185>,<186>,<187>,<188>,<189>,<190>,<191>,<192>,<193>,<194>,<195>,<196>,<197>,<198>,<199>,<200>,<201>,<202>,<203>,<204>,<205>,<206>,<207>,<208>,<209>,<210>,<211>,<212>,<213>,<214>,<215>,<216>,<217>,<218>,<219>,<220>,<221>,<222>,<223>,<224>,<225>,<226>,<227>,<228>,<229>,<230>,<231>,<232>,<233>,<234>,<235>,<236>,<237>,<238>,<239>,<240>,<241>,<242>,<243>,<244>,<245>,<246>,<247>,<248>,<249>,<250>,<251>,<252>,<253>,<254>,<255>}(a: __m128i, b: __m128i));
+
+/// Indices with their high bit set give 0, and the other ones only use their
+/// low 4 bits.
+#[test]
+fn _mm_shuffle_epi8_high_bit() {
+    let a = BitVec::from_slice(&core::array::from_fn::<u8, 16, _>(|i| 0xa0 + i as u8), 8);
+    let b = BitVec::from_slice(
+        &[
+            0u8, 15, 16, 0x1f, 0x7f, 0x80, 0x8f, 0xff, 3, 0x13, 0x23, 0x90, 7, 0x47, 0xc7, 1,
+        ],
+        8,
+    );
+    let r = BitVec::from_slice(
+        &[
+            0xa0u8, 0xaf, 0xa0, 0xaf, 0xaf, 0, 0, 0, 0xa3, 0xa3, 0xa3, 0, 0xa7, 0xa7, 0, 0xa1,
+        ],
+        8,
+    );
+    let model = super::super::models::ssse3::_mm_shuffle_epi8(a, b);
+    assert_eq!(model, r);
+    assert_eq!(
+        model,
+        unsafe { upstream::_mm_shuffle_epi8(a.into(), b.into()) }.into()
+    );
+}
+mk_const_sweep!([100]_mm_alignr_epi8{bits: 8}(a: __m128i, b: __m128i));
 mk!(_mm_hadd_epi16(a: __m128i, b: __m128i));
 mk!(_mm_hadds_epi16(a: __m128i, b: __m128i));
 mk!(_mm_hadd_epi32(a: __m128i, b: __m128i));
 mk!(_mm_hsub_epi16(a: __m128i, b: __m128i));
 mk!(_mm_hsubs_epi16(a: __m128i, b: __m128i));
 mk!(_mm_hsub_epi32(a: __m128i, b: __m128i));
+
+/// The sums and differences of the pairs saturate in the `s` variants, and
+/// wrap around in the other ones.
+#[test]
+fn _mm_hadds_epi16_saturating() {
+    use super::super::models::ssse3;
+    let a = BitVec::from_slice(
+        &[i16::MAX, 1, i16::MIN, -1, i16::MAX, i16::MIN, 100, 200],
+        16,
+    );
+    let b = BitVec::from_slice(
+        &[
+            i16::MIN,
+            i16::MIN,
+            i16::MAX,
+            i16::MAX,
+            -1,
+            1,
+            0x4000,
+            0x4000,
+        ],
+        16,
+    );
+    let r = BitVec::from_slice(
+        &[i16::MAX, i16::MIN, -1, 300, i16::MIN, i16::MAX, 0, i16::MAX],
+        16,
+    );
+    assert_eq!(ssse3::_mm_hadds_epi16(a, b), r);
+    assert_eq!(
+        ssse3::_mm_hadds_epi16(a, b),
+        unsafe { upstream::_mm_hadds_epi16(a.into(), b.into()) }.into()
+    );
+    let r = BitVec::from_slice(&[i16::MIN, i16::MAX, -1, 300, 0, -2, 0, i16::MIN], 16);
+    assert_eq!(ssse3::_mm_hadd_epi16(a, b), r);
+    assert_eq!(
+        ssse3::_mm_hadd_epi16(a, b),
+        unsafe { upstream::_mm_hadd_epi16(a.into(), b.into()) }.into()
+    );
+}
+
+#[test]
+fn _mm_hsubs_epi16_saturating() {
+    use super::super::models::ssse3;
+    let a = BitVec::from_slice(&[i16::MIN, 1, i16::MAX, -1, 0, i16::MIN, -1, i16::MAX], 16);
+    let b = BitVec::from_slice(
+        &[
+            i16::MAX,
+            i16::MIN,
+            i16::MIN,
+            i16::MAX,
+            5,
+            3,
+            -0x4000,
+            0x4000,
+        ],
+        16,
+    );
+    let r = BitVec::from_slice(
+        &[
+            i16::MIN,
+            i16::MAX,
+            i16::MAX,
+            i16::MIN,
+            i16::MAX,
+            i16::MIN,
+            2,
+            i16::MIN,
+        ],
+        16,
+    );
+    assert_eq!(ssse3::_mm_hsubs_epi16(a, b), r);
+    assert_eq!(
+        ssse3::_mm_hsubs_epi16(a, b),
+        unsafe { upstream::_mm_hsubs_epi16(a.into(), b.into()) }.into()
+    );
+    let r = BitVec::from_slice(
+        &[i16::MAX, i16::MIN, i16::MIN, i16::MIN, -1, 1, 2, i16::MIN],
+        16,
+    );
+    assert_eq!(ssse3::_mm_hsub_epi16(a, b), r);
+    assert_eq!(
+        ssse3::_mm_hsub_epi16(a, b),
+        unsafe { upstream::_mm_hsub_epi16(a.into(), b.into()) }.into()
+    );
+}
 mk!(_mm_maddubs_epi16(a: __m128i, b: __m128i));
 mk!(_mm_mulhrs_epi16(a: __m128i, b: __m128i));
+
+/// The sum of the two products of each pair saturates, though each product
+/// fits in 16 bits.
+#[test]
+fn _mm_maddubs_epi16_saturating() {
+    let a = BitVec::from_slice(
+        &[
+            255u8, 255, 255, 255, 255, 0, 255, 1, 255, 255, 128, 128, 0, 0, 1, 2,
+        ],
+        8,
+    );
+    let b = BitVec::from_slice(
+        &[
+            127i8, 127, -128, -128, 127, 0, 127, 1, -128, 127, -128, -128, 0, 0, -1, -1,
+        ],
+        8,
+    );
+    let r = BitVec::from_slice(
+        &[i16::MAX, i16::MIN, 32385, 32386, -255, i16::MIN, 0, -3],
+        16,
+    );
+    let model = super::super::models::ssse3::_mm_maddubs_epi16(a, b);
+    assert_eq!(model, r);
+    assert_eq!(
+        model,
+        unsafe { upstream::_mm_maddubs_epi16(a.into(), b.into()) }.into()
+    );
+}
+
+/// Halves are rounded up, and `i16::MIN * i16::MIN` wraps back to `i16::MIN`.
+#[test]
+fn _mm_mulhrs_epi16_rounding() {
+    let a = BitVec::from_slice(&[i16::MIN, 1, -1, 1, 3, -3, i16::MAX, i16::MIN], 16);
+    let b = BitVec::from_slice(
+        &[
+            i16::MIN,
+            0x4000,
+            0x4000,
+            0x3fff,
+            0x4000,
+            0x4000,
+            i16::MAX,
+            i16::MAX,
+        ],
+        16,
+    );
+    let r = BitVec::from_slice(&[i16::MIN, 1, 0, 0, 2, -1, 32766, -32767], 16);
+    let model = super::super::models::ssse3::_mm_mulhrs_epi16(a, b);
+    assert_eq!(model, r);
+    assert_eq!(
+        model,
+        unsafe { upstream::_mm_mulhrs_epi16(a.into(), b.into()) }.into()
+    );
+}
 mk!(_mm_sign_epi8(a: __m128i, b: __m128i));
 mk!(_mm_sign_epi16(a: __m128i, b: __m128i));
 mk!(_mm_sign_epi32(a: __m128i, b: __m128i));
+
+/// Lanes are negated, kept or zeroed depending on the sign of `b`, and
+/// negating the minimum of a signed type wraps back to it.
+#[test]
+fn _mm_sign_epi_signs() {
+    use super::super::models::ssse3;
+    let a = BitVec::from_slice(
+        &[
+            5i8,
+            5,
+            5,
+            i8::MIN,
+            i8::MIN,
+            i8::MIN,
+            -7,
+            -7,
+            -7,
+            i8::MAX,
+            0,
+            1,
+            2,
+            3,
+            4,
+            5,
+        ],
+        8,
+    );
+    let b = BitVec::from_slice(
+        &[
+            -1i8,
+            0,
+            1,
+            -1,
+            0,
+            1,
+            i8::MIN,
+            0,
+            i8::MAX,
+            -2,
+            -1,
+            0,
+            3,
+            i8::MIN,
+            0,
+            1,
+        ],
+        8,
+    );
+    let r = BitVec::from_slice(
+        &[
+            -5i8,
+            0,
+            5,
+            i8::MIN,
+            0,
+            i8::MIN,
+            7,
+            0,
+            -7,
+            -i8::MAX,
+            0,
+            0,
+            2,
+            -3,
+            0,
+            5,
+        ],
+        8,
+    );
+    assert_eq!(ssse3::_mm_sign_epi8(a, b), r);
+    assert_eq!(
+        ssse3::_mm_sign_epi8(a, b),
+        unsafe { upstream::_mm_sign_epi8(a.into(), b.into()) }.into()
+    );
+    let a = BitVec::from_slice(&[5i16, 5, 5, i16::MIN, i16::MIN, i16::MIN, -7, -7], 16);
+    let b = BitVec::from_slice(&[i16::MIN, 0, i16::MAX, -1, 0, 1, -3, 0], 16);
+    let r = BitVec::from_slice(&[-5i16, 0, 5, i16::MIN, 0, i16::MIN, 7, 0], 16);
+    assert_eq!(ssse3::_mm_sign_epi16(a, b), r);
+    assert_eq!(
+        ssse3::_mm_sign_epi16(a, b),
+        unsafe { upstream::_mm_sign_epi16(a.into(), b.into()) }.into()
+    );
+    let a = BitVec::from_slice(&[i32::MIN, i32::MIN, 9, -9], 32);
+    let b = BitVec::from_slice(&[-1, 0, i32::MIN, 1], 32);
+    let r = BitVec::from_slice(&[i32::MIN, 0, -9, -9], 32);
+    assert_eq!(ssse3::_mm_sign_epi32(a, b), r);
+    assert_eq!(
+        ssse3::_mm_sign_epi32(a, b),
+        unsafe { upstream::_mm_sign_epi32(a.into(), b.into()) }.into()
+    );
+}
@@ -1,14 +1,38 @@
 #[cfg(test)]
 pub mod test {
-    use crate::abstractions::{bit::Bit, bitvec::BitVec, funarr::FunArray};
+    use crate::abstractions::bit::{Bit, MachineInteger};
+    use crate::abstractions::bitvec::BitVec;
+    use crate::abstractions::funarr::{random_biased_lane, FunArray};
+    use rand::distr::{Distribution, StandardUniform};
     use rand::prelude::*;
-    use std::sync::{LazyLock, Mutex};
+    use std::cell::RefCell;
+    use std::sync::LazyLock;
 
-    static RNG: LazyLock<Mutex<StdRng>> = LazyLock::new(|| {
-        let seed = rand::rng().random();
-        println!("\nRandomness seed set to: {:?}", seed);
-        Mutex::new(StdRng::from_seed(seed))
-    });
+    /// Environment variable from which the seed of the random inputs is read.
+    pub const SEED_VAR: &str = "SIMD_TEST_SEED";
+    /// Seed used when `SEED_VAR` is not set.
+    pub const DEFAULT_SEED: u64 = 0;
+
+    /// Returns the seed the random inputs are generated from.
+    pub fn seed() -> u64 {
+        static SEED: LazyLock<u64> = LazyLock::new(|| match std::env::var(SEED_VAR) {
+            Ok(seed) => seed
+                .parse()
+                .unwrap_or_else(|_| panic!("{SEED_VAR} should be a u64, got {seed:?}")),
+            Err(_) => DEFAULT_SEED,
+        });
+        *SEED
+    }
+
+    thread_local! {
+        // Every test runs on its own thread, so each test draws the same
+        // sequence of values for a given seed, independently of the others.
+        static RNG: RefCell<StdRng> = RefCell::new({
+            let seed = seed();
+            println!("\nRandomness seed set to: {seed} (replay with {SEED_VAR}={seed})");
+            StdRng::seed_from_u64(seed)
+        });
+    }
 
     /// Helper trait to generate random values
     pub trait HasRandom {
@@ -18,7 +42,7 @@ pub mod test {
         ($($ty:ty),*) => {
             $(impl HasRandom for $ty {
                 fn random() -> Self {
-                    RNG.lock().unwrap().random()
+                    RNG.with_borrow_mut(|rng| rng.random())
                 }
             })*
         };
@@ -67,6 +91,125 @@ pub mod test {
             FunArray::from_fn(|_| T::random())
         }
     }
+
+    /// Helper trait to generate random values that are biased towards the edge
+    /// cases of their type, drawn from the same seeded generator as `HasRandom`
+    /// through `crate::abstractions::funarr::random_biased_lane`.
+    pub trait HasRandomBiased: HasRandom {
+        fn random_biased() -> Self;
+    }
+    macro_rules! mk_has_random_biased {
+        ($($ty:ty),*) => {
+            $(impl HasRandomBiased for $ty {
+                fn random_biased() -> Self {
+                    RNG.with_borrow_mut(|rng| random_biased_lane(rng))
+                }
+            })*
+        };
+    }
+
+    mk_has_random_biased!(i8, i16, i32, i64, i128);
+    mk_has_random_biased!(u8, u16, u32, u64, u128);
+
+    /// Each lane is drawn independently, so a vector usually mixes edge values
+    /// with uniform ones.
+    impl<const N: u32, T: HasRandomBiased + MachineInteger + Copy> HasRandomBiased for FunArray<N, T>
+    where
+        StandardUniform: Distribution<T>,
+    {
+        fn random_biased() -> Self {
+            RNG.with_borrow_mut(|rng| FunArray::random_biased_with(rng))
+        }
+    }
+
+    /// Enumerates structured inputs for intrinsics on 8-bit lanes: the vectors
+    /// with every byte equal, for each of the 256 values, then those with a
+    /// single non-zero byte, for each position and each of the 255 non-zero
+    /// values. Every byte value thus shows up in every lane, both next to equal
+    /// lanes and next to zeros.
+    pub fn byte_patterns<const N: u32>() -> impl Iterator<Item = BitVec<N>> {
+        let splats = (0..=255u8).map(|byte| BitVec::from_slice(&[byte; 64][..], 8));
+        let singles = (0..N as usize / 8).flat_map(|position| {
+            (1..=255u8).map(move |byte| {
+                let mut bytes = [0u8; 64];
+                bytes[position] = byte;
+                BitVec::from_slice(&bytes[..], 8)
+            })
+        });
+        splats.chain(singles)
+    }
+
+    /// Derives a test for a unary intrinsic on 8-bit lanes, named after it with
+    /// an `_exhaustive` suffix, which compares the model of the given module
+    /// against upstream on every input of `byte_patterns` rather than on random
+    /// ones, e.g.
+    ///
+    /// `mk_exhaustive!(ssse3::_mm_abs_epi8(a: __m128i));`
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    macro_rules! mk_exhaustive {
+        ($module:ident::$name:ident($x:ident : $ty:ident)) => {
+            pastey::paste! {
+                #[test]
+                fn [<$name _exhaustive>]() {
+                    for $x in crate::helpers::test::byte_patterns() {
+                        let $x: $ty = $x;
+                        let model = super::super::models::$module::$name($x);
+                        let upstream = unsafe { upstream::$name($x.into()) };
+                        assert_eq!(
+                            model,
+                            crate::abstractions::bitvec::BitVec::from(upstream),
+                            "Failed with input value: {:?}",
+                            $x
+                        );
+                    }
+                }
+            }
+        };
+    }
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    pub(crate) use mk_exhaustive;
+
+    /// Derives a test for an intrinsic taking an immediate of the given width (the
+    /// one from its `static_assert_uimm_bits!`), which runs the `mk!` comparison for
+    /// every value of the immediate, e.g.
+    ///
+    /// `mk_const_sweep!([100]_mm256_shuffle_epi32{bits: 8}(a: __m256i));`
+    ///
+    /// is the same as `mk!([100]_mm256_shuffle_epi32{<0>,<1>,...,<255>}(a: __m256i));`.
+    /// It expands to the `mk!` invocation of the module it is used in.
+    macro_rules! mk_const_sweep {
+        ($([$N:literal])?$name:ident{bits: 8}$($rest:tt)*) => {
+            mk_const_sweep!(@rows[] [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15] [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15] $([$N])?$name$($rest)*);
+        };
+        ($([$N:literal])?$name:ident{bits: 7}$($rest:tt)*) => {
+            mk_const_sweep!(@rows[] [0 1 2 3 4 5 6 7] [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15] $([$N])?$name$($rest)*);
+        };
+        ($([$N:literal])?$name:ident{bits: 6}$($rest:tt)*) => {
+            mk_const_sweep!(@rows[] [0 1 2 3] [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15] $([$N])?$name$($rest)*);
+        };
+        ($([$N:literal])?$name:ident{bits: 5}$($rest:tt)*) => {
+            mk_const_sweep!(@rows[] [0 1] [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15] $([$N])?$name$($rest)*);
+        };
+        ($([$N:literal])?$name:ident{bits: 4}$($rest:tt)*) => {
+            mk_const_sweep!(@rows[] [0] [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15] $([$N])?$name$($rest)*);
+        };
+        ($([$N:literal])?$name:ident{bits: 3}$($rest:tt)*) => {
+            mk_const_sweep!(@rows[] [0] [0 1 2 3 4 5 6 7] $([$N])?$name$($rest)*);
+        };
+        ($([$N:literal])?$name:ident{bits: 2}$($rest:tt)*) => {
+            mk_const_sweep!(@rows[] [0] [0 1 2 3] $([$N])?$name$($rest)*);
+        };
+        ($([$N:literal])?$name:ident{bits: 1}$($rest:tt)*) => {
+            mk_const_sweep!(@rows[] [0] [0 1] $([$N])?$name$($rest)*);
+        };
+        (@rows[$($acc:tt)*] [] [$($lo:tt)*] $([$N:literal])?$name:ident$($rest:tt)*) => {
+            mk!($([$N])?$name{$(<$acc>),*}$($rest)*);
+        };
+        (@rows[$($acc:tt)*] [$hi:tt $($his:tt)*] [$($lo:tt)*] $($rest:tt)*) => {
+            mk_const_sweep!(@rows[$($acc)* $({$hi * 16 + $lo})*] [$($his)*] [$($lo)*] $($rest)*);
+        };
+    }
+    pub(crate) use mk_const_sweep;
 }
 
 #[cfg(test)]